@@ -11,6 +11,15 @@ use std::sync::Arc;
 
 pub trait AuthenticatedResponder: AlkaneResponder {
     fn deploy_auth_token(&self, units: u128) -> Result<AlkaneTransfer> {
+        self.deploy_auth_token_at(units, &mut StoragePointer::from_keyword("/auth"))
+    }
+    // deploys an auth token and records its id at `pointer` rather than
+    // "/auth", for contracts that hand out more than one
+    fn deploy_auth_token_at(
+        &self,
+        units: u128,
+        pointer: &mut StoragePointer,
+    ) -> Result<AlkaneTransfer> {
         let cellpack = Cellpack {
             target: AlkaneId {
                 block: 6,
@@ -20,8 +29,7 @@ pub trait AuthenticatedResponder: AlkaneResponder {
         };
         let sequence = self.sequence();
         let response = self.call(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
-        let mut ptr = StoragePointer::from_keyword("/auth");
-        ptr.set(Arc::new(<AlkaneId as Into<Vec<u8>>>::into(AlkaneId {
+        pointer.set(Arc::new(<AlkaneId as Into<Vec<u8>>>::into(AlkaneId {
             block: 2,
             tx: sequence,
         })));
@@ -36,7 +44,11 @@ pub trait AuthenticatedResponder: AlkaneResponder {
         Ok(pointer.as_ref().clone().try_into()?)
     }
     fn only_owner(&self) -> Result<()> {
-        let auth_token = self.auth_token()?;
+        self.only_owner_at(&StoragePointer::from_keyword("/auth"))
+    }
+    // checks the caller sent the auth token whose id is recorded at `pointer`
+    fn only_owner_at(&self, pointer: &StoragePointer) -> Result<()> {
+        let auth_token: AlkaneId = pointer.get().as_ref().clone().try_into()?;
         let cellpack = Cellpack {
             target: auth_token,
             inputs: vec![0x1],
@@ -0,0 +1,447 @@
+use crate::storage::StoragePointer;
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+// Typed collections over alkane storage. Each collection owns a keyword and
+// lays its entries out beneath it, so contracts no longer build keys by hand.
+// Values go through StorageCodec. An empty encoding reads back as unset, so
+// StorageValue::get of an empty string or vec returns None.
+
+pub trait StorageCodec: Sized {
+    fn encode(&self) -> Vec<u8>;
+    fn decode(v: &[u8]) -> Result<Self>;
+}
+
+macro_rules! impl_int_codec {
+    ($($t:ty),*) => {
+        $(
+            impl StorageCodec for $t {
+                fn encode(&self) -> Vec<u8> {
+                    self.to_le_bytes().to_vec()
+                }
+                fn decode(v: &[u8]) -> Result<Self> {
+                    Ok(<$t>::from_le_bytes(v.try_into().map_err(|_| {
+                        anyhow!(
+                            "expected {} bytes for {}, got {}",
+                            std::mem::size_of::<$t>(),
+                            stringify!($t),
+                            v.len()
+                        )
+                    })?))
+                }
+            }
+        )*
+    };
+}
+
+impl_int_codec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StorageCodec for bool {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self as u8]
+    }
+    fn decode(v: &[u8]) -> Result<Self> {
+        match v {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(anyhow!("invalid encoding for bool")),
+        }
+    }
+}
+
+impl StorageCodec for Vec<u8> {
+    fn encode(&self) -> Vec<u8> {
+        self.clone()
+    }
+    fn decode(v: &[u8]) -> Result<Self> {
+        Ok(v.to_vec())
+    }
+}
+
+impl StorageCodec for String {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+    fn decode(v: &[u8]) -> Result<Self> {
+        Ok(String::from_utf8(v.to_vec())?)
+    }
+}
+
+impl StorageCodec for AlkaneId {
+    fn encode(&self) -> Vec<u8> {
+        (*self).into()
+    }
+    fn decode(v: &[u8]) -> Result<Self> {
+        v.to_vec().try_into()
+    }
+}
+
+fn write(pointer: &StoragePointer, v: Vec<u8>) {
+    pointer.clone().set(Arc::new(v));
+}
+
+// a single value stored under a keyword
+pub struct StorageValue<T: StorageCodec> {
+    pointer: StoragePointer,
+    _value: PhantomData<T>,
+}
+
+impl<T: StorageCodec> StorageValue<T> {
+    pub fn new(keyword: &str) -> Self {
+        Self::from_pointer(StoragePointer::from_keyword(keyword))
+    }
+    pub fn from_pointer(pointer: StoragePointer) -> Self {
+        StorageValue {
+            pointer,
+            _value: PhantomData,
+        }
+    }
+    pub fn is_set(&self) -> bool {
+        !self.pointer.get().is_empty()
+    }
+    pub fn get(&self) -> Result<Option<T>> {
+        let v = self.pointer.get();
+        if v.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(T::decode(v.as_ref())?))
+        }
+    }
+    pub fn set(&self, v: &T) {
+        write(&self.pointer, v.encode());
+    }
+    pub fn clear(&self) {
+        write(&self.pointer, vec![]);
+    }
+}
+
+impl<T: StorageCodec + Default> StorageValue<T> {
+    pub fn get_or_default(&self) -> Result<T> {
+        Ok(self.get()?.unwrap_or_default())
+    }
+}
+
+// a list of values with its length kept alongside it
+pub struct StorageVec<T: StorageCodec> {
+    pointer: StoragePointer,
+    _value: PhantomData<T>,
+}
+
+impl<T: StorageCodec> StorageVec<T> {
+    pub fn new(keyword: &str) -> Self {
+        Self::from_pointer(StoragePointer::from_keyword(keyword))
+    }
+    pub fn from_pointer(pointer: StoragePointer) -> Self {
+        StorageVec {
+            pointer,
+            _value: PhantomData,
+        }
+    }
+    pub fn len(&self) -> u32 {
+        self.pointer.length()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, index: u32) -> Result<Option<T>> {
+        if index >= self.len() {
+            return Ok(None);
+        }
+        Ok(Some(T::decode(
+            self.pointer.select_index(index).get().as_ref(),
+        )?))
+    }
+    pub fn set(&self, index: u32, v: &T) -> Result<()> {
+        if index >= self.len() {
+            return Err(anyhow!(
+                "index {} out of bounds for length {}",
+                index,
+                self.len()
+            ));
+        }
+        write(&self.pointer.select_index(index), v.encode());
+        Ok(())
+    }
+    pub fn push(&self, v: &T) {
+        self.pointer.clone().append(Arc::new(v.encode()));
+    }
+    pub fn pop(&self) -> Result<Option<T>> {
+        let length = self.len();
+        if length == 0 {
+            return Ok(None);
+        }
+        let last = self.get(length - 1)?;
+        write(&self.pointer.select_index(length - 1), vec![]);
+        self.pointer.length_key().set_value::<u32>(length - 1);
+        Ok(last)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<T>> + '_ {
+        (0..self.len()).map(move |i| T::decode(self.pointer.select_index(i).get().as_ref()))
+    }
+}
+
+// a set of keys that can be enumerated. Each member's 1-based position in the
+// list is stored next to it, so removal swaps the last member into the gap
+pub struct StorageSet<K: StorageCodec> {
+    members: StorageVec<K>,
+    positions: StoragePointer,
+}
+
+impl<K: StorageCodec> StorageSet<K> {
+    pub fn new(keyword: &str) -> Self {
+        Self::from_pointer(StoragePointer::from_keyword(keyword))
+    }
+    pub fn from_pointer(pointer: StoragePointer) -> Self {
+        StorageSet {
+            members: StorageVec::from_pointer(pointer.keyword("/members")),
+            positions: pointer.keyword("/position/"),
+        }
+    }
+    fn position(&self, key: &K) -> StoragePointer {
+        self.positions.select(&key.encode())
+    }
+    pub fn len(&self) -> u32 {
+        self.members.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+    pub fn contains(&self, key: &K) -> bool {
+        self.position(key).get_value::<u32>() != 0
+    }
+    // returns false if the key was already a member
+    pub fn insert(&self, key: &K) -> bool {
+        if self.contains(key) {
+            return false;
+        }
+        self.members.push(key);
+        self.position(key).set_value::<u32>(self.members.len());
+        true
+    }
+    // returns false if the key was not a member
+    pub fn remove(&self, key: &K) -> Result<bool> {
+        let index = self.position(key).get_value::<u32>();
+        if index == 0 {
+            return Ok(false);
+        }
+        let last = self
+            .members
+            .pop()?
+            .ok_or_else(|| anyhow!("storage set positions out of sync with members"))?;
+        if index <= self.members.len() {
+            self.members.set(index - 1, &last)?;
+            self.position(&last).set_value::<u32>(index);
+        }
+        self.position(key).set_value::<u32>(0);
+        Ok(true)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<K>> + '_ {
+        self.members.iter()
+    }
+}
+
+// a map from keys to values, named apart from alkanes_support's StorageMap of
+// raw writes. Keys are kept in a StorageSet so the map has a length and can be
+// iterated in insertion order, up to removals
+pub struct StorageHashMap<K: StorageCodec, V: StorageCodec> {
+    keys: StorageSet<K>,
+    values: StoragePointer,
+    _value: PhantomData<V>,
+}
+
+impl<K: StorageCodec, V: StorageCodec> StorageHashMap<K, V> {
+    pub fn new(keyword: &str) -> Self {
+        Self::from_pointer(StoragePointer::from_keyword(keyword))
+    }
+    pub fn from_pointer(pointer: StoragePointer) -> Self {
+        StorageHashMap {
+            keys: StorageSet::from_pointer(pointer.keyword("/keys")),
+            values: pointer.keyword("/values/"),
+            _value: PhantomData,
+        }
+    }
+    fn value(&self, key: &K) -> StoragePointer {
+        self.values.select(&key.encode())
+    }
+    pub fn len(&self) -> u32 {
+        self.keys.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+    pub fn get(&self, key: &K) -> Result<Option<V>> {
+        if !self.contains_key(key) {
+            return Ok(None);
+        }
+        Ok(Some(V::decode(self.value(key).get().as_ref())?))
+    }
+    pub fn insert(&self, key: &K, v: &V) {
+        self.keys.insert(key);
+        write(&self.value(key), v.encode());
+    }
+    pub fn remove(&self, key: &K) -> Result<Option<V>> {
+        let previous = self.get(key)?;
+        if previous.is_some() {
+            self.keys.remove(key)?;
+            write(&self.value(key), vec![]);
+        }
+        Ok(previous)
+    }
+    pub fn keys(&self) -> impl Iterator<Item = Result<K>> + '_ {
+        self.keys.iter()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<(K, V)>> + '_ {
+        self.keys.iter().map(move |key| {
+            let key = key?;
+            let v = V::decode(self.value(&key).get().as_ref())?;
+            Ok((key, v))
+        })
+    }
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use alkanes_support::{
+        cellpack::Cellpack, parcel::AlkaneTransferParcel, response::CallResponse,
+    };
+
+    const ALKANE: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+    // runs `f` as a call to ALKANE, so its writes are committed to the mock
+    // host and later calls read them back from there
+    fn call(f: impl Fn() -> Result<()> + 'static) -> Result<()> {
+        mock::register_handler(ALKANE, move || {
+            f()?;
+            Ok(CallResponse::default())
+        });
+        mock::execute(
+            &Cellpack {
+                target: ALKANE,
+                inputs: vec![0],
+            },
+            AlkaneTransferParcel::default(),
+        )?;
+        Ok(())
+    }
+
+    fn members(set: &StorageSet<u32>) -> Result<Vec<u32>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_set_remove_swaps_last_member_in() -> Result<()> {
+        mock::reset();
+        call(|| {
+            let set = StorageSet::<u32>::new("/set");
+            for v in [1, 2, 3] {
+                assert!(set.insert(&v));
+            }
+            assert!(!set.insert(&2));
+            Ok(())
+        })?;
+        call(|| {
+            let set = StorageSet::<u32>::new("/set");
+            // the middle member is replaced by the last
+            assert!(set.remove(&2)?);
+            assert_eq!(members(&set)?, vec![1, 3]);
+            assert!(!set.contains(&2));
+            assert!(!set.remove(&2)?);
+            Ok(())
+        })?;
+        call(|| {
+            let set = StorageSet::<u32>::new("/set");
+            // the last member has nothing to swap in
+            assert!(set.remove(&3)?);
+            assert_eq!(members(&set)?, vec![1]);
+            // 1 still knows its position after both removals
+            assert!(set.remove(&1)?);
+            assert!(set.is_empty());
+            assert!(set.insert(&3));
+            assert_eq!(members(&set)?, vec![3]);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_vec_pop() -> Result<()> {
+        mock::reset();
+        call(|| {
+            let list = StorageVec::<u64>::new("/list");
+            list.push(&7);
+            list.push(&8);
+            Ok(())
+        })?;
+        call(|| {
+            let list = StorageVec::<u64>::new("/list");
+            assert_eq!(list.pop()?, Some(8));
+            assert_eq!(list.len(), 1);
+            assert_eq!(list.get(1)?, None);
+            assert!(list.set(1, &9).is_err());
+            assert_eq!(list.pop()?, Some(7));
+            assert_eq!(list.pop()?, None);
+            list.push(&9);
+            assert_eq!(list.iter().collect::<Result<Vec<_>>>()?, vec![9]);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_map_remove_and_reinsert() -> Result<()> {
+        mock::reset();
+        call(|| {
+            let map = StorageHashMap::<AlkaneId, u128>::new("/map");
+            map.insert(&AlkaneId { block: 2, tx: 5 }, &50);
+            map.insert(&AlkaneId { block: 2, tx: 6 }, &60);
+            Ok(())
+        })?;
+        call(|| {
+            let map = StorageHashMap::<AlkaneId, u128>::new("/map");
+            let removed = AlkaneId { block: 2, tx: 5 };
+            assert_eq!(map.remove(&removed)?, Some(50));
+            assert_eq!(map.remove(&removed)?, None);
+            assert_eq!(map.get(&removed)?, None);
+            assert!(!map.contains_key(&removed));
+            assert_eq!(map.len(), 1);
+            map.insert(&removed, &55);
+            assert_eq!(map.get(&removed)?, Some(55));
+            assert_eq!(
+                map.iter().collect::<Result<Vec<_>>>()?,
+                vec![(AlkaneId { block: 2, tx: 6 }, 60), (removed, 55)]
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_codec_rejects_wrong_lengths() -> Result<()> {
+        mock::reset();
+        assert_eq!(
+            u32::decode(&[1, 2]).unwrap_err().to_string(),
+            "expected 4 bytes for u32, got 2"
+        );
+        assert!(u128::decode(&[0; 17]).is_err());
+        assert!(bool::decode(&[2]).is_err());
+        assert!(AlkaneId::decode(&[0; 31]).is_err());
+        call(|| {
+            StorageValue::<Vec<u8>>::new("/value").set(&vec![1, 2, 3]);
+            Ok(())
+        })?;
+        call(|| {
+            // the same key read back at another width
+            assert!(StorageValue::<u64>::new("/value").get().is_err());
+            assert_eq!(
+                StorageValue::<Vec<u8>>::new("/value").get()?,
+                Some(vec![1, 2, 3])
+            );
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,229 @@
+// Safe wrappers over the host imports. With test-utils the same calls are
+// answered by the in-process mock host instead, since native pointers do not
+// fit the i32 import ABI
+#[cfg(not(feature = "test-utils"))]
+pub use self::wasm::*;
+#[cfg(feature = "test-utils")]
+pub use crate::mock::host::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallKind {
+    Call,
+    Delegatecall,
+    Staticcall,
+}
+
+#[cfg(not(feature = "test-utils"))]
+mod wasm {
+    use super::CallKind;
+    use crate::imports::{
+        __balance, __call, __delegatecall, __ecdsa_verify, __emit_event, __fuel, __hash160,
+        __height, __keccak256, __load_block, __load_context, __load_storage, __load_transaction,
+        __log, __request_block, __request_context, __request_storage, __request_transaction,
+        __returndatacopy, __ripemd160, __schnorr_verify, __sequence, __sha256, __staticcall,
+    };
+    use alkanes_support::{
+        cellpack::Cellpack, id::AlkaneId, parcel::AlkaneTransferParcel, response::CallResponse,
+        storage::StorageMap,
+    };
+    use anyhow::{anyhow, Result};
+    use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr, to_ptr};
+    use std::io::Cursor;
+
+    pub fn abort() {
+        unsafe {
+            crate::imports::abort(0, 0, 0, 0);
+        }
+    }
+
+    pub fn log(v: &[u8]) {
+        let mut data = to_arraybuffer_layout(v);
+        unsafe {
+            __log(to_passback_ptr(&mut data));
+        }
+    }
+
+    pub fn context() -> Vec<u8> {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; __request_context() as usize]);
+            __load_context(to_ptr(&mut buffer) + 4);
+            (&buffer[4..]).to_vec()
+        }
+    }
+
+    pub fn block() -> Vec<u8> {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; __request_block() as usize]);
+            __load_block(to_ptr(&mut buffer) + 4);
+            (&buffer[4..]).to_vec()
+        }
+    }
+
+    pub fn transaction() -> Vec<u8> {
+        unsafe {
+            let mut buffer: Vec<u8> =
+                to_arraybuffer_layout(vec![0; __request_transaction() as usize]);
+            __load_transaction(to_ptr(&mut buffer) + 4);
+            (&buffer[4..]).to_vec()
+        }
+    }
+
+    pub fn load_storage(k: &[u8]) -> Vec<u8> {
+        unsafe {
+            let mut key_bytes = to_arraybuffer_layout(k);
+            let key = to_passback_ptr(&mut key_bytes);
+            let buf_size = __request_storage(key) as usize;
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; buf_size]);
+            __load_storage(key, to_passback_ptr(&mut buffer));
+            (&buffer[4..]).to_vec()
+        }
+    }
+
+    pub fn balance(who: &AlkaneId, what: &AlkaneId) -> u128 {
+        unsafe {
+            let mut who_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>((*who).into());
+            let mut what_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>((*what).into());
+            let who_ptr = to_ptr(&mut who_bytes) + 4;
+            let what_ptr = to_ptr(&mut what_bytes) + 4;
+            let mut output: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(vec![0u8; 16]);
+            __balance(who_ptr, what_ptr, to_ptr(&mut output) + 4);
+            u128::from_le_bytes((&output[4..]).try_into().unwrap())
+        }
+    }
+
+    pub fn sequence() -> u128 {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 16]);
+            __sequence(to_ptr(&mut buffer) + 4);
+            u128::from_le_bytes((&buffer[4..]).try_into().unwrap())
+        }
+    }
+
+    pub fn fuel() -> u64 {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 8]);
+            __fuel(to_ptr(&mut buffer) + 4);
+            u64::from_le_bytes((&buffer[4..]).try_into().unwrap())
+        }
+    }
+
+    pub fn height() -> u64 {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 8]);
+            __height(to_ptr(&mut buffer) + 4);
+            u64::from_le_bytes((&buffer[4..]).try_into().unwrap())
+        }
+    }
+
+    pub fn emit_event(topics: &[u8], data: &[u8]) {
+        unsafe {
+            let mut topic_bytes = to_arraybuffer_layout(topics);
+            let mut data_bytes = to_arraybuffer_layout(data);
+            __emit_event(
+                to_passback_ptr(&mut topic_bytes),
+                to_passback_ptr(&mut data_bytes),
+            );
+        }
+    }
+
+    fn digest<const N: usize>(data: &[u8], hash: unsafe extern "C" fn(i32, i32)) -> [u8; N] {
+        let mut data = to_arraybuffer_layout(data);
+        let mut output: Vec<u8> = to_arraybuffer_layout(vec![0u8; N]);
+        unsafe {
+            hash(to_passback_ptr(&mut data), to_ptr(&mut output) + 4);
+        }
+        (&output[4..]).try_into().unwrap()
+    }
+
+    pub fn sha256(data: &[u8]) -> [u8; 32] {
+        digest(data, __sha256)
+    }
+
+    pub fn keccak256(data: &[u8]) -> [u8; 32] {
+        digest(data, __keccak256)
+    }
+
+    pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+        digest(data, __ripemd160)
+    }
+
+    pub fn hash160(data: &[u8]) -> [u8; 20] {
+        digest(data, __hash160)
+    }
+
+    fn verify(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        verify: unsafe extern "C" fn(i32, i32, i32) -> i32,
+    ) -> bool {
+        let mut pubkey = to_arraybuffer_layout(pubkey);
+        let mut message = to_arraybuffer_layout(message);
+        let mut signature = to_arraybuffer_layout(signature);
+        unsafe {
+            verify(
+                to_passback_ptr(&mut pubkey),
+                to_passback_ptr(&mut message),
+                to_passback_ptr(&mut signature),
+            ) == 1
+        }
+    }
+
+    pub fn schnorr_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify(pubkey, message, signature, __schnorr_verify)
+    }
+
+    pub fn ecdsa_verify(pubkey: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+        verify(pubkey, digest, signature, __ecdsa_verify)
+    }
+
+    pub fn extcall(
+        kind: CallKind,
+        cellpack: &Cellpack,
+        outgoing_alkanes: &AlkaneTransferParcel,
+        checkpoint: &StorageMap,
+        fuel: u64,
+    ) -> Result<CallResponse> {
+        let mut cellpack_buffer = to_arraybuffer_layout::<&[u8]>(&cellpack.serialize());
+        let mut outgoing_alkanes_buffer: Vec<u8> =
+            to_arraybuffer_layout::<&[u8]>(&outgoing_alkanes.serialize());
+        let mut storage_map_buffer = to_arraybuffer_layout::<&[u8]>(&checkpoint.serialize());
+        let (cellpack_ptr, outgoing_ptr, checkpoint_ptr) = (
+            to_passback_ptr(&mut cellpack_buffer),
+            to_passback_ptr(&mut outgoing_alkanes_buffer),
+            to_passback_ptr(&mut storage_map_buffer),
+        );
+        let _call_result = unsafe {
+            match kind {
+                CallKind::Call => __call(cellpack_ptr, outgoing_ptr, checkpoint_ptr, fuel),
+                CallKind::Delegatecall => {
+                    __delegatecall(cellpack_ptr, outgoing_ptr, checkpoint_ptr, fuel)
+                }
+                CallKind::Staticcall => {
+                    __staticcall(cellpack_ptr, outgoing_ptr, checkpoint_ptr, fuel)
+                }
+            }
+        };
+        if _call_result < 0 {
+            let call_result = _call_result.abs() as usize;
+            let mut returndata = to_arraybuffer_layout(&vec![0; call_result]);
+            unsafe {
+                __returndatacopy(to_passback_ptr(&mut returndata));
+            }
+            let response = CallResponse::parse(&mut Cursor::new((&returndata[4..]).to_vec()))?;
+            if response.data.len() <= 4 || &response.data[0..4] != &[0x08, 0xc3, 0x79, 0xa0] {
+                return Err(anyhow!("Extcall failed (no details available)"));
+            }
+            let error_message = String::from_utf8_lossy(&response.data[4..]).to_string();
+            Err(anyhow!("Extcall failed: {}", error_message))
+        } else {
+            let call_result = _call_result as usize;
+            let mut returndata = to_arraybuffer_layout(&vec![0; call_result]);
+            unsafe {
+                __returndatacopy(to_passback_ptr(&mut returndata));
+            }
+            let response = CallResponse::parse(&mut Cursor::new((&returndata[4..]).to_vec()))?;
+            Ok(response)
+        }
+    }
+}
@@ -1,8 +1,12 @@
 pub mod auth;
+pub mod collections;
 #[cfg(feature = "panic-hook")]
 pub mod compat;
+pub mod host;
 pub mod imports;
 pub mod message;
+#[cfg(feature = "test-utils")]
+pub mod mock;
 pub mod runtime;
 pub mod stdio;
 pub mod storage;
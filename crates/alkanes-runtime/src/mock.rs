@@ -0,0 +1,454 @@
+use crate::host::CallKind;
+use crate::message::MessageDispatch;
+use crate::runtime::{replace_cache, AlkaneResponder};
+use alkanes_support::{
+    cellpack::Cellpack,
+    context::Context,
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+    storage::StorageMap,
+};
+use anyhow::{anyhow, Result};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+// In-process host for running alkanes natively under cargo test. Alkanes are
+// registered against an id and calls between them are routed through the same
+// transfer and storage rules the indexer applies, without wasm in between.
+// The host and the runtime's storage cache are both thread local, so tests
+// running on separate threads do not see each other's state.
+
+pub type Handler = Rc<dyn Fn() -> Result<CallResponse>>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockEvent {
+    pub alkane: AlkaneId,
+    pub topics: Vec<u128>,
+    pub data: Vec<u8>,
+}
+
+// everything a failed call rolls back
+#[derive(Clone, Default)]
+struct MockState {
+    storage: BTreeMap<AlkaneId, StorageMap>,
+    balances: BTreeMap<(AlkaneId, AlkaneId), u128>,
+    events: Vec<MockEvent>,
+}
+
+#[derive(Default)]
+struct MockHost {
+    state: MockState,
+    alkanes: BTreeMap<AlkaneId, Handler>,
+    frames: Vec<Context>,
+    height: u64,
+    block: Vec<u8>,
+    transaction: Vec<u8>,
+    sequence: u128,
+    fuel: u64,
+    logs: Vec<String>,
+}
+
+thread_local! {
+    static HOST: RefCell<MockHost> = RefCell::new(MockHost::default());
+}
+
+fn with_host<T>(f: impl FnOnce(&mut MockHost) -> T) -> T {
+    HOST.with(|host| f(&mut host.borrow_mut()))
+}
+
+fn current() -> Option<Context> {
+    with_host(|host| host.frames.last().cloned())
+}
+
+pub fn reset() {
+    with_host(|host| *host = MockHost::default());
+    replace_cache(StorageMap::default());
+}
+
+// registers an alkane dispatching through its message enum, as declare_alkane
+// does in wasm
+pub fn register<T: AlkaneResponder + Default, M: MessageDispatch<T> + 'static>(id: AlkaneId) {
+    register_handler(id, || {
+        let mut inputs = current()
+            .ok_or_else(|| anyhow!("no call in progress"))?
+            .inputs;
+        if inputs.is_empty() {
+            return Err(anyhow!("No opcode provided"));
+        }
+        let opcode = inputs.remove(0);
+        M::from_opcode(opcode, inputs)?.dispatch(&T::default())
+    });
+}
+
+// registers an alkane that implements AlkaneResponder::execute directly
+pub fn register_responder<T: AlkaneResponder + Default>(id: AlkaneId) {
+    register_handler(id, || T::default().execute());
+}
+
+pub fn register_handler(id: AlkaneId, handler: impl Fn() -> Result<CallResponse> + 'static) {
+    with_host(|host| host.alkanes.insert(id, Rc::new(handler)));
+}
+
+pub fn set_height(v: u64) {
+    with_host(|host| host.height = v);
+}
+
+pub fn set_block(v: Vec<u8>) {
+    with_host(|host| host.block = v);
+}
+
+pub fn set_transaction(v: Vec<u8>) {
+    with_host(|host| host.transaction = v);
+}
+
+pub fn set_sequence(v: u128) {
+    with_host(|host| host.sequence = v);
+}
+
+pub fn set_fuel(v: u64) {
+    with_host(|host| host.fuel = v);
+}
+
+pub fn set_balance(who: AlkaneId, what: AlkaneId, amount: u128) {
+    with_host(|host| host.state.balances.insert((who, what), amount));
+}
+
+pub fn balance_of(who: AlkaneId, what: AlkaneId) -> u128 {
+    with_host(|host| {
+        host.state
+            .balances
+            .get(&(who, what))
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
+pub fn set_storage(id: AlkaneId, k: &[u8], v: &[u8]) {
+    with_host(|host| host.state.storage.entry(id).or_default().set(k, v));
+}
+
+pub fn storage(id: AlkaneId) -> StorageMap {
+    with_host(|host| host.state.storage.get(&id).cloned().unwrap_or_default())
+}
+
+pub fn events() -> Vec<MockEvent> {
+    with_host(|host| host.state.events.clone())
+}
+
+pub fn logs() -> Vec<String> {
+    with_host(|host| host.logs.clone())
+}
+
+// calls an alkane from outside, the way a protostone would. The zero id
+// stands in for the transaction's outputs: the incoming alkanes are credited
+// to it and sent on to the target, and whatever the target returns, or all of
+// it if the call fails, ends up back there
+pub fn execute(
+    cellpack: &Cellpack,
+    incoming_alkanes: AlkaneTransferParcel,
+) -> Result<CallResponse> {
+    let outputs = AlkaneId::default();
+    for v in &incoming_alkanes.0 {
+        set_balance(
+            outputs,
+            v.id,
+            balance_of(outputs, v.id).saturating_add(v.value),
+        );
+    }
+    run(
+        CallKind::Call,
+        outputs,
+        cellpack,
+        &incoming_alkanes,
+        incoming_alkanes.clone(),
+    )
+}
+
+fn transfer(from: AlkaneId, to: AlkaneId, transfer: &AlkaneTransfer) -> Result<()> {
+    with_host(|host| {
+        let balances = &mut host.state.balances;
+        let balance = balances
+            .get(&(from, transfer.id))
+            .cloned()
+            .unwrap_or_default();
+        // an alkane can always send its own token, minting what it lacks
+        let remaining = match balance.checked_sub(transfer.value) {
+            Some(v) => v,
+            None if transfer.id == from => 0,
+            None => {
+                return Err(anyhow!(
+                    "{}:{} holds {} of {}:{} but sent {}",
+                    from.block,
+                    from.tx,
+                    balance,
+                    transfer.id.block,
+                    transfer.id.tx,
+                    transfer.value
+                ))
+            }
+        };
+        balances.insert((from, transfer.id), remaining);
+        let credited = balances.entry((to, transfer.id)).or_default();
+        *credited = credited
+            .checked_add(transfer.value)
+            .ok_or_else(|| anyhow!("balance overflow"))?;
+        Ok(())
+    })
+}
+
+// runs one call frame. `outgoing` is moved from the caller to the callee
+// before it runs, while `incoming_alkanes` is what the callee is told it
+// received
+fn run(
+    kind: CallKind,
+    caller: AlkaneId,
+    cellpack: &Cellpack,
+    outgoing: &AlkaneTransferParcel,
+    incoming_alkanes: AlkaneTransferParcel,
+) -> Result<CallResponse> {
+    let handler =
+        with_host(|host| host.alkanes.get(&cellpack.target).cloned()).ok_or_else(|| {
+            anyhow!(
+                "no alkane registered at {}:{}",
+                cellpack.target.block,
+                cellpack.target.tx
+            )
+        })?;
+    let myself = match kind {
+        CallKind::Delegatecall => caller,
+        _ => cellpack.target,
+    };
+    let snapshot = with_host(|host| host.state.clone());
+    let result = (|| {
+        for v in &outgoing.0 {
+            transfer(caller, myself, v)?;
+        }
+        with_host(|host| {
+            host.frames.push(Context {
+                myself,
+                caller,
+                vout: 0,
+                incoming_alkanes,
+                inputs: cellpack.inputs.clone(),
+            })
+        });
+        let outer = replace_cache(StorageMap::default());
+        let result = handler();
+        let writes = replace_cache(outer);
+        with_host(|host| host.frames.pop());
+        let response = result?;
+        with_host(|host| {
+            host.state
+                .storage
+                .entry(myself)
+                .or_default()
+                .0
+                .extend(writes.0)
+        });
+        for v in &response.alkanes.0 {
+            transfer(myself, caller, v)?;
+        }
+        Ok(response)
+    })();
+    // a staticcall sees its own writes while it runs but keeps none of them
+    if result.is_err() || kind == CallKind::Staticcall {
+        with_host(|host| host.state = snapshot);
+    }
+    result
+}
+
+// the host imports as seen from inside a mocked call
+pub mod host {
+    use super::{current, run, with_host, MockEvent};
+    use crate::host::CallKind;
+    use alkanes_support::{
+        cellpack::Cellpack, event::decode_topics, id::AlkaneId, parcel::AlkaneTransferParcel,
+        response::CallResponse, storage::StorageMap,
+    };
+    use anyhow::{anyhow, Result};
+
+    // failures surface as the Err the call returns
+    pub fn abort() {}
+
+    pub fn log(v: &[u8]) {
+        let line = String::from_utf8_lossy(v).to_string();
+        print!("{}", line);
+        with_host(|host| host.logs.push(line));
+    }
+
+    pub fn context() -> Vec<u8> {
+        current().map(|v| v.serialize()).unwrap_or_default()
+    }
+
+    pub fn block() -> Vec<u8> {
+        with_host(|host| host.block.clone())
+    }
+
+    pub fn transaction() -> Vec<u8> {
+        with_host(|host| host.transaction.clone())
+    }
+
+    pub fn load_storage(k: &[u8]) -> Vec<u8> {
+        let myself = current().map(|v| v.myself).unwrap_or_default();
+        with_host(|host| {
+            host.state
+                .storage
+                .get(&myself)
+                .and_then(|v| v.get(k).cloned())
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn balance(who: &AlkaneId, what: &AlkaneId) -> u128 {
+        super::balance_of(*who, *what)
+    }
+
+    pub fn sequence() -> u128 {
+        with_host(|host| host.sequence)
+    }
+
+    pub fn fuel() -> u64 {
+        with_host(|host| host.fuel)
+    }
+
+    pub fn height() -> u64 {
+        with_host(|host| host.height)
+    }
+
+    pub fn emit_event(topics: &[u8], data: &[u8]) {
+        let alkane = current().map(|v| v.myself).unwrap_or_default();
+        let topics = decode_topics(topics).expect("invalid event topics");
+        with_host(|host| {
+            host.state.events.push(MockEvent {
+                alkane,
+                topics,
+                data: data.to_vec(),
+            })
+        });
+    }
+
+    pub use alkanes_support::crypto::{
+        ecdsa_verify, hash160, keccak256, ripemd160, schnorr_verify, sha256,
+    };
+
+    pub fn extcall(
+        kind: CallKind,
+        cellpack: &Cellpack,
+        outgoing_alkanes: &AlkaneTransferParcel,
+        checkpoint: &StorageMap,
+        _fuel: u64,
+    ) -> Result<CallResponse> {
+        let caller = current()
+            .ok_or_else(|| anyhow!("extcall outside of a call"))?
+            .myself;
+        // the caller's writes so far are committed before the callee runs
+        with_host(|host| {
+            host.state
+                .storage
+                .entry(caller)
+                .or_default()
+                .0
+                .extend(checkpoint.0.clone())
+        });
+        run(
+            kind,
+            caller,
+            cellpack,
+            outgoing_alkanes,
+            outgoing_alkanes.clone(),
+        )
+        .map_err(|e| anyhow!("Extcall failed: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::StoragePointer;
+    use metashrew_support::index_pointer::KeyValuePointer;
+
+    const COUNTER: AlkaneId = AlkaneId { block: 2, tx: 1 };
+    const FORWARDER: AlkaneId = AlkaneId { block: 2, tx: 2 };
+
+    #[derive(Default)]
+    struct Counter(());
+
+    impl AlkaneResponder for Counter {
+        fn execute(&self) -> Result<CallResponse> {
+            let context = self.context()?;
+            let mut count = StoragePointer::from_keyword("/count");
+            count.set_value::<u128>(count.get_value::<u128>() + 1);
+            let mut response = CallResponse::default();
+            match context.inputs[0] {
+                // mint 10 of itself to the caller
+                0 => response.alkanes.0.push(AlkaneTransfer {
+                    id: context.myself,
+                    value: 10,
+                }),
+                _ => return Err(anyhow!("reverted")),
+            }
+            Ok(response)
+        }
+    }
+
+    #[derive(Default)]
+    struct Forwarder(());
+
+    impl AlkaneResponder for Forwarder {
+        fn execute(&self) -> Result<CallResponse> {
+            let context = self.context()?;
+            StoragePointer::from_keyword("/calls").set_value::<u128>(1);
+            self.call(
+                &Cellpack {
+                    target: COUNTER,
+                    inputs: context.inputs.clone(),
+                },
+                &AlkaneTransferParcel::default(),
+                self.fuel(),
+            )
+        }
+    }
+
+    fn setup() {
+        reset();
+        register_responder::<Counter>(COUNTER);
+        register_responder::<Forwarder>(FORWARDER);
+    }
+
+    fn forward(opcode: u128) -> Result<CallResponse> {
+        execute(
+            &Cellpack {
+                target: FORWARDER,
+                inputs: vec![opcode],
+            },
+            AlkaneTransferParcel::default(),
+        )
+    }
+
+    #[test]
+    fn test_nested_call_moves_alkanes_and_storage() -> Result<()> {
+        setup();
+        let response = forward(0)?;
+        assert_eq!(response.alkanes.0[0].id, COUNTER);
+        assert_eq!(response.alkanes.0[0].value, 10);
+        assert_eq!(balance_of(AlkaneId::default(), COUNTER), 10);
+        assert_eq!(balance_of(FORWARDER, COUNTER), 0);
+        assert_eq!(
+            storage(COUNTER).get("/count"),
+            Some(&1u128.to_le_bytes().to_vec())
+        );
+        assert!(storage(FORWARDER).get("/calls").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_call_rolls_back() -> Result<()> {
+        setup();
+        assert!(forward(1).is_err());
+        assert!(storage(COUNTER).get("/count").is_none());
+        assert!(storage(FORWARDER).get("/calls").is_none());
+        Ok(())
+    }
+}
@@ -1,16 +1,10 @@
-#[allow(unused_imports)]
-use crate::imports::{
-    __balance, __call, __delegatecall, __fuel, __height, __load_block, __load_context,
-    __load_storage, __load_transaction, __log, __request_block, __request_context,
-    __request_storage, __request_transaction, __returndatacopy, __sequence, __staticcall,
-    abort, /*, __load_output, __request_output */
-};
+use crate::host::{self, CallKind};
 #[allow(unused_imports)]
 use crate::{
     println,
     stdio::{stdout, Write},
 };
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 #[allow(unused_imports)]
 use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr, to_ptr};
 use std::io::Cursor;
@@ -22,6 +16,7 @@ use crate::compat::panic_hook;
 use alkanes_support::{
     cellpack::Cellpack,
     context::Context,
+    event::encode_topics,
     id::AlkaneId,
     parcel::{AlkaneTransfer, AlkaneTransferParcel},
     response::{CallResponse, ExtendedCallResponse},
@@ -31,33 +26,51 @@ use alkanes_support::{
 use std::panic;
 
 fn _abort() {
-    unsafe {
-        abort(0, 0, 0, 0);
-    }
+    host::abort();
 }
 
+// the storage writes of the running call. Under test-utils many calls run in
+// one process, one test per thread, so each thread keeps a cache of its own
+#[cfg(not(feature = "test-utils"))]
 static mut _CACHE: Option<StorageMap> = None;
 
-pub fn initialize_cache() {
-    unsafe {
-        if _CACHE.is_none() {
-            _CACHE = Some(StorageMap::default());
-        }
-    }
+#[cfg(feature = "test-utils")]
+thread_local! {
+    static _CACHE: std::cell::RefCell<Option<StorageMap>> = std::cell::RefCell::new(None);
 }
 
+#[cfg(not(feature = "test-utils"))]
 #[allow(static_mut_refs)]
+fn with_cache_slot<T>(f: impl FnOnce(&mut Option<StorageMap>) -> T) -> T {
+    unsafe { f(&mut _CACHE) }
+}
+
+#[cfg(feature = "test-utils")]
+fn with_cache_slot<T>(f: impl FnOnce(&mut Option<StorageMap>) -> T) -> T {
+    _CACHE.with(|cache| f(&mut cache.borrow_mut()))
+}
+
+fn with_cache<T>(f: impl FnOnce(&mut StorageMap) -> T) -> T {
+    with_cache_slot(|cache| f(cache.get_or_insert_with(StorageMap::default)))
+}
+
+pub fn initialize_cache() {
+    with_cache(|_| ());
+}
+
 pub fn get_cache() -> StorageMap {
-    unsafe {
-        initialize_cache();
-        _CACHE.as_ref().unwrap().clone()
-    }
+    with_cache(|cache| cache.clone())
+}
+
+// swaps out the storage writes of the running call, so the mock host can give
+// each nested call a cache of its own and restore the caller's afterwards
+#[cfg(feature = "test-utils")]
+pub(crate) fn replace_cache(v: StorageMap) -> StorageMap {
+    with_cache(|cache| std::mem::replace(cache, v))
 }
 
-#[allow(static_mut_refs)]
 pub fn handle_success(response: CallResponse) -> ExtendedCallResponse {
     let mut extended: ExtendedCallResponse = response.into();
-    initialize_cache();
     extended.storage = get_cache();
     extended
 }
@@ -82,106 +95,51 @@ pub fn response_to_i32(response: ExtendedCallResponse) -> i32 {
 }
 
 pub trait Extcall {
-    fn __call(cellpack: i32, outgoing_alkanes: i32, checkpoint: i32, fuel: u64) -> i32;
-    #[allow(static_mut_refs)]
+    const KIND: CallKind;
     fn call(
         cellpack: &Cellpack,
         outgoing_alkanes: &AlkaneTransferParcel,
         fuel: u64,
     ) -> Result<CallResponse> {
-        let mut cellpack_buffer = to_arraybuffer_layout::<&[u8]>(&cellpack.serialize());
-        let mut outgoing_alkanes_buffer: Vec<u8> =
-            to_arraybuffer_layout::<&[u8]>(&outgoing_alkanes.serialize());
-        let mut storage_map_buffer =
-            to_arraybuffer_layout::<&[u8]>(&unsafe { _CACHE.as_ref().unwrap().serialize() });
-        let _call_result = Self::__call(
-            to_passback_ptr(&mut cellpack_buffer),
-            to_passback_ptr(&mut outgoing_alkanes_buffer),
-            to_passback_ptr(&mut storage_map_buffer),
-            fuel,
-        );
-        if _call_result < 0 {
-            let call_result = _call_result.abs() as usize;
-            let mut returndata = to_arraybuffer_layout(&vec![0; call_result]);
-            unsafe {
-                __returndatacopy(to_passback_ptr(&mut returndata));
-            }
-            let response = CallResponse::parse(&mut Cursor::new((&returndata[4..]).to_vec()))?;
-            if response.data.len() <= 4 || &response.data[0..4] != &[0x08, 0xc3, 0x79, 0xa0] {
-                return Err(anyhow!("Extcall failed (no details available)"));
-            }
-            let error_message = String::from_utf8_lossy(&response.data[4..]).to_string();
-            return Err(anyhow!("Extcall failed: {}", error_message));
-        } else {
-            let call_result = _call_result as usize;
-            let mut returndata = to_arraybuffer_layout(&vec![0; call_result]);
-            unsafe {
-                __returndatacopy(to_passback_ptr(&mut returndata));
-            }
-            let response = CallResponse::parse(&mut Cursor::new((&returndata[4..]).to_vec()))?;
-            Ok(response)
-        }
+        host::extcall(Self::KIND, cellpack, outgoing_alkanes, &get_cache(), fuel)
     }
 }
 
 pub struct Call(());
 
 impl Extcall for Call {
-    fn __call(cellpack: i32, outgoing_alkanes: i32, checkpoint: i32, fuel: u64) -> i32 {
-        unsafe { __call(cellpack, outgoing_alkanes, checkpoint, fuel) }
-    }
+    const KIND: CallKind = CallKind::Call;
 }
 
 pub struct Delegatecall(());
 
 impl Extcall for Delegatecall {
-    fn __call(cellpack: i32, outgoing_alkanes: i32, checkpoint: i32, fuel: u64) -> i32 {
-        unsafe { __delegatecall(cellpack, outgoing_alkanes, checkpoint, fuel) }
-    }
+    const KIND: CallKind = CallKind::Delegatecall;
 }
 
 pub struct Staticcall(());
 
 impl Extcall for Staticcall {
-    fn __call(cellpack: i32, outgoing_alkanes: i32, checkpoint: i32, fuel: u64) -> i32 {
-        unsafe { __staticcall(cellpack, outgoing_alkanes, checkpoint, fuel) }
-    }
+    const KIND: CallKind = CallKind::Staticcall;
 }
 
 pub trait AlkaneResponder: 'static {
     fn context(&self) -> Result<Context> {
-        unsafe {
-            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; __request_context() as usize]);
-            __load_context(to_ptr(&mut buffer) + 4);
-            let res = Context::parse(&mut Cursor::<Vec<u8>>::new((&buffer[4..]).to_vec()));
-            res
-        }
+        Context::parse(&mut Cursor::<Vec<u8>>::new(host::context()))
     }
     fn block(&self) -> Vec<u8> {
-        unsafe {
-            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; __request_block() as usize]);
-            __load_block(to_ptr(&mut buffer) + 4);
-            (&buffer[4..]).to_vec()
-        }
+        host::block()
     }
-    #[allow(static_mut_refs)]
     fn initialize(&self) -> &Self {
-        unsafe {
-            if _CACHE.is_none() {
-                initialize_cache();
-                #[cfg(feature = "panic-hook")]
-                panic::set_hook(Box::new(panic_hook));
-            }
-            self
+        if with_cache_slot(|cache| cache.is_none()) {
+            initialize_cache();
+            #[cfg(feature = "panic-hook")]
+            panic::set_hook(Box::new(panic_hook));
         }
+        self
     }
     fn transaction(&self) -> Vec<u8> {
-        unsafe {
-            let mut buffer: Vec<u8> =
-                to_arraybuffer_layout(vec![0; __request_transaction() as usize]);
-            __load_transaction(to_ptr(&mut buffer) + 4);
-            (&buffer[4..]).to_vec()
-        }
+        host::transaction()
     }
     /*
     fn output(&self, v: &OutPoint) -> Result<Vec<u8>> {
@@ -201,65 +159,27 @@ pub trait AlkaneResponder: 'static {
         }
     }
     */
-    #[allow(static_mut_refs)]
     fn load(&self, k: Vec<u8>) -> Vec<u8> {
-        unsafe {
-            initialize_cache();
-            if _CACHE.as_ref().unwrap().0.contains_key(&k) {
-                _CACHE
-                    .as_ref()
-                    .unwrap()
-                    .get(&k)
-                    .map(|v| v.clone())
-                    .unwrap_or_else(|| Vec::<u8>::new())
-            } else {
-                let mut key_bytes = to_arraybuffer_layout(&k);
-                let key = to_passback_ptr(&mut key_bytes);
-                let buf_size = __request_storage(key) as usize;
-                let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; buf_size]);
-                __load_storage(key, to_passback_ptr(&mut buffer));
-                (&buffer[4..]).to_vec()
-            }
-        }
+        with_cache(|cache| cache.get(&k).cloned()).unwrap_or_else(|| host::load_storage(&k))
     }
-    #[allow(static_mut_refs)]
     fn store(&self, k: Vec<u8>, v: Vec<u8>) {
-        unsafe {
-            initialize_cache();
-            _CACHE.as_mut().unwrap().set(&k, &v);
-        }
+        with_cache(|cache| cache.set(&k, &v));
     }
     fn balance(&self, who: &AlkaneId, what: &AlkaneId) -> u128 {
-        unsafe {
-            let mut who_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(who.clone().into());
-            let mut what_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(what.clone().into());
-            let who_ptr = to_ptr(&mut who_bytes) + 4;
-            let what_ptr = to_ptr(&mut what_bytes) + 4;
-            let mut output: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(vec![0u8; 16]);
-            __balance(who_ptr, what_ptr, to_ptr(&mut output) + 4);
-            u128::from_le_bytes((&output[4..]).try_into().unwrap())
-        }
+        host::balance(who, what)
     }
     fn sequence(&self) -> u128 {
-        unsafe {
-            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 16]);
-            __sequence(to_ptr(&mut buffer) + 4);
-            u128::from_le_bytes((&buffer[4..]).try_into().unwrap())
-        }
+        host::sequence()
     }
     fn fuel(&self) -> u64 {
-        unsafe {
-            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 8]);
-            __fuel(to_ptr(&mut buffer) + 4);
-            u64::from_le_bytes((&buffer[4..]).try_into().unwrap())
-        }
+        host::fuel()
     }
     fn height(&self) -> u64 {
-        unsafe {
-            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 8]);
-            __height(to_ptr(&mut buffer) + 4);
-            u64::from_le_bytes((&buffer[4..]).try_into().unwrap())
-        }
+        host::height()
+    }
+    // emits an event indexed under this alkane and each of its topics
+    fn emit(&self, topics: &[u128], data: &[u8]) {
+        host::emit_event(&encode_topics(topics), data);
     }
     fn extcall<T: Extcall>(
         &self,
@@ -307,8 +227,7 @@ pub trait AlkaneResponder: 'static {
         let extended: ExtendedCallResponse = match self.initialize().execute() {
             Ok(v) => {
                 let mut response: ExtendedCallResponse = v.into();
-                initialize_cache();
-                response.storage = unsafe { _CACHE.as_ref().unwrap().clone() };
+                response.storage = get_cache();
                 response
                     .alkanes
                     .0
@@ -0,0 +1,258 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::{
+    auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch,
+    storage::StoragePointer,
+};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_support::{
+    cellpack::Cellpack,
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+};
+use anyhow::{anyhow, Result};
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+// opcodes understood by alkanes-std-amm-pool
+const POOL_OPCODE_INIT: u128 = 0;
+const POOL_OPCODE_SET_FEE: u128 = 20;
+
+#[derive(Default)]
+pub struct AMMFactory(());
+
+impl AuthenticatedResponder for AMMFactory {}
+
+#[derive(MessageDispatch)]
+enum AMMFactoryMessage {
+    #[opcode(0)]
+    Initialize { pool_factory_id: u128 },
+
+    #[opcode(1)]
+    CreateNewPool {
+        alkane_a: AlkaneId,
+        alkane_b: AlkaneId,
+        amount_a: u128,
+        amount_b: u128,
+    },
+
+    #[opcode(2)]
+    #[returns(Vec<u8>)]
+    FindExistingPoolId {
+        alkane_a: AlkaneId,
+        alkane_b: AlkaneId,
+    },
+
+    #[opcode(3)]
+    #[returns(Vec<u8>)]
+    GetAllPools,
+
+    #[opcode(4)]
+    #[returns(u128)]
+    GetNumPools,
+
+    #[opcode(21)]
+    SetPoolFee { pool: AlkaneId, fee_per_1000: u128 },
+}
+
+fn sort_alkanes(a: AlkaneId, b: AlkaneId) -> (AlkaneId, AlkaneId) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+impl AMMFactory {
+    fn pool_factory_id(&self) -> u128 {
+        StoragePointer::from_keyword("/pool_factory_id").get_value::<u128>()
+    }
+    fn pool_pointer(&self, alkane_a: &AlkaneId, alkane_b: &AlkaneId) -> StoragePointer {
+        StoragePointer::from_keyword("/pools/")
+            .select(&alkane_a.clone().into())
+            .keyword("/")
+            .select(&alkane_b.clone().into())
+    }
+    fn all_pools_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/all_pools")
+    }
+    // A deploy is rolled back along with its call when the call fails, so
+    // with every other opcode refusing to run first, a factory only exists
+    // once Initialize has run in the call that deployed it. Its auth token
+    // can only ever go to the deployer
+    fn require_initialized(&self) -> Result<()> {
+        if StoragePointer::from_keyword("/initialized").get().len() == 0 {
+            return Err(anyhow!("amm-factory: not initialized"));
+        }
+        Ok(())
+    }
+
+    fn initialize(&self, pool_factory_id: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut pointer = StoragePointer::from_keyword("/initialized");
+        if pointer.get().len() != 0 {
+            return Err(anyhow!("already initialized"));
+        }
+        pointer.set_value::<u8>(0x01);
+        StoragePointer::from_keyword("/pool_factory_id").set_value::<u128>(pool_factory_id);
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.alkanes.0.push(self.deploy_auth_token(1)?);
+        Ok(response)
+    }
+
+    fn create_new_pool(
+        &self,
+        alkane_a: AlkaneId,
+        alkane_b: AlkaneId,
+        amount_a: u128,
+        amount_b: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.require_initialized()?;
+        if alkane_a == alkane_b {
+            return Err(anyhow!("amm-factory: pool assets must be distinct"));
+        }
+        let (sorted_a, sorted_b) = sort_alkanes(alkane_a.clone(), alkane_b.clone());
+        let mut pool_pointer = self.pool_pointer(&sorted_a, &sorted_b);
+        if pool_pointer.get().len() != 0 {
+            return Err(anyhow!(
+                "amm-factory: pool already exists for {:?} and {:?}",
+                sorted_a,
+                sorted_b
+            ));
+        }
+        // take exactly the requested amounts out of the incoming alkanes and
+        // hand everything else back to the caller
+        let mut remaining = [amount_a, amount_b];
+        let mut rest = AlkaneTransferParcel::default();
+        for transfer in &context.incoming_alkanes.0 {
+            let mut value = transfer.value;
+            for (i, id) in [&alkane_a, &alkane_b].iter().enumerate() {
+                if transfer.id == **id {
+                    let taken = std::cmp::min(value, remaining[i]);
+                    remaining[i] -= taken;
+                    value -= taken;
+                }
+            }
+            if value != 0 {
+                rest.0.push(AlkaneTransfer {
+                    id: transfer.id.clone(),
+                    value,
+                });
+            }
+        }
+        if remaining != [0, 0] {
+            return Err(anyhow!(
+                "amm-factory: incoming alkanes do not cover the requested pool deposit"
+            ));
+        }
+        let pool_id = AlkaneId::new(2, self.sequence());
+        let cellpack = Cellpack {
+            target: AlkaneId::new(6, self.pool_factory_id()),
+            inputs: vec![
+                POOL_OPCODE_INIT,
+                alkane_a.block,
+                alkane_a.tx,
+                alkane_b.block,
+                alkane_b.tx,
+            ],
+        };
+        let pool_response = self.call(
+            &cellpack,
+            &AlkaneTransferParcel(vec![
+                AlkaneTransfer {
+                    id: alkane_a,
+                    value: amount_a,
+                },
+                AlkaneTransfer {
+                    id: alkane_b,
+                    value: amount_b,
+                },
+            ]),
+            self.fuel(),
+        )?;
+        pool_pointer.set(Arc::new(pool_id.clone().into()));
+        self.all_pools_pointer()
+            .append(Arc::new(pool_id.clone().into()));
+        let mut response = CallResponse::forward(&rest);
+        response.alkanes.0.extend(pool_response.alkanes.0);
+        response.data = pool_id.into();
+        Ok(response)
+    }
+
+    fn find_existing_pool_id(&self, alkane_a: AlkaneId, alkane_b: AlkaneId) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.require_initialized()?;
+        let (sorted_a, sorted_b) = sort_alkanes(alkane_a, alkane_b);
+        let pool = self.pool_pointer(&sorted_a, &sorted_b).get();
+        if pool.len() == 0 {
+            return Err(anyhow!(
+                "amm-factory: no pool exists for {:?} and {:?}",
+                sorted_a,
+                sorted_b
+            ));
+        }
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = pool.as_ref().clone();
+        Ok(response)
+    }
+
+    fn get_all_pools(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.require_initialized()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let pools = self.all_pools_pointer().get_list();
+        response.data = (pools.len() as u128).to_le_bytes().to_vec();
+        for pool in pools {
+            response.data.extend(pool.as_ref());
+        }
+        Ok(response)
+    }
+
+    fn get_num_pools(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.require_initialized()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = (self.all_pools_pointer().length() as u128)
+            .to_le_bytes()
+            .to_vec();
+        Ok(response)
+    }
+
+    fn set_pool_fee(&self, pool: AlkaneId, fee_per_1000: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.require_initialized()?;
+        self.only_owner()?;
+        self.call(
+            &Cellpack {
+                target: pool,
+                inputs: vec![POOL_OPCODE_SET_FEE, fee_per_1000],
+            },
+            &AlkaneTransferParcel::default(),
+            self.fuel(),
+        )?;
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+}
+
+impl AlkaneResponder for AMMFactory {
+    fn execute(&self) -> Result<CallResponse> {
+        // The opcode extraction and dispatch logic is now handled by the declare_alkane macro
+        // This method is still required by the AlkaneResponder trait, but we can just return an error
+        // indicating that it should not be called directly
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for AMMFactory {
+        type Message = AMMFactoryMessage;
+    }
+}
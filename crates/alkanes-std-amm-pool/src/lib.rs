@@ -0,0 +1,369 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::{declare_alkane, message::MessageDispatch, storage::StoragePointer};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_std_factory_support::MintableToken;
+use alkanes_support::{
+    context::Context,
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+    utils::overflow_error,
+};
+use anyhow::{anyhow, Result};
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use ruint::aliases::U256;
+use std::sync::Arc;
+
+// LP tokens permanently locked on the first deposit so the pool can never be
+// drained back to an empty total supply
+pub const MINIMUM_LIQUIDITY: u128 = 1000;
+// swap fee in parts per thousand, Uniswap v2 default
+pub const DEFAULT_FEE_PER_1000: u128 = 3;
+
+#[derive(Default)]
+pub struct AMMPool(());
+
+impl MintableToken for AMMPool {}
+
+#[derive(MessageDispatch)]
+enum AMMPoolMessage {
+    #[opcode(0)]
+    InitPool {
+        alkane_a: AlkaneId,
+        alkane_b: AlkaneId,
+    },
+
+    #[opcode(1)]
+    AddLiquidity,
+
+    #[opcode(2)]
+    Burn,
+
+    #[opcode(3)]
+    Swap { amount_out_min: u128 },
+
+    #[opcode(20)]
+    SetFee { fee_per_1000: u128 },
+
+    #[opcode(97)]
+    #[returns(Vec<u8>)]
+    GetReserves,
+
+    #[opcode(98)]
+    #[returns(Vec<u8>)]
+    GetPoolDetails,
+
+    #[opcode(99)]
+    #[returns(String)]
+    GetName,
+
+    #[opcode(100)]
+    #[returns(String)]
+    GetSymbol,
+
+    #[opcode(101)]
+    #[returns(u128)]
+    GetTotalSupply,
+}
+
+fn to_u128(v: U256) -> Result<u128> {
+    u128::try_from(v).map_err(|_| anyhow!("amm-pool: value exceeds u128"))
+}
+
+fn sqrt(y: U256) -> U256 {
+    if y > U256::from(3u8) {
+        let mut z = y;
+        let mut x = y / U256::from(2u8) + U256::from(1u8);
+        while x < z {
+            z = x;
+            x = (y / x + x) / U256::from(2u8);
+        }
+        z
+    } else if y != U256::ZERO {
+        U256::from(1u8)
+    } else {
+        U256::ZERO
+    }
+}
+
+impl AMMPool {
+    fn alkanes_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/alkanes")
+    }
+    fn alkanes_for_self(&self) -> Result<(AlkaneId, AlkaneId)> {
+        let ptr = self.alkanes_pointer();
+        Ok((
+            ptr.select_index(0).get().as_ref().clone().try_into()?,
+            ptr.select_index(1).get().as_ref().clone().try_into()?,
+        ))
+    }
+    fn factory_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/factory")
+    }
+    fn factory(&self) -> Result<AlkaneId> {
+        Ok(self.factory_pointer().get().as_ref().clone().try_into()?)
+    }
+    fn fee_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/fee")
+    }
+    fn fee(&self) -> u128 {
+        self.fee_pointer().get_value::<u128>()
+    }
+    // splits the incoming alkanes into the amounts of the pool's two assets and
+    // everything else, which is handed back untouched
+    fn split_incoming(
+        &self,
+        context: &Context,
+        ids: &[AlkaneId],
+    ) -> (Vec<u128>, AlkaneTransferParcel) {
+        let mut amounts = vec![0u128; ids.len()];
+        let mut rest = AlkaneTransferParcel::default();
+        for transfer in &context.incoming_alkanes.0 {
+            match ids.iter().position(|id| *id == transfer.id) {
+                Some(i) => amounts[i] = amounts[i].saturating_add(transfer.value),
+                None => rest.0.push(transfer.clone()),
+            }
+        }
+        (amounts, rest)
+    }
+    // reserves as they were before this call, since incoming alkanes are
+    // already credited to the pool by the time we execute
+    fn previous_reserves(&self, context: &Context) -> Result<(u128, u128)> {
+        let (a, b) = self.alkanes_for_self()?;
+        let (incoming, _) = self.split_incoming(context, &[a.clone(), b.clone()]);
+        Ok((
+            overflow_error(
+                self.balance(&context.myself, &a)
+                    .checked_sub(incoming[0]),
+            )?,
+            overflow_error(
+                self.balance(&context.myself, &b)
+                    .checked_sub(incoming[1]),
+            )?,
+        ))
+    }
+    fn reserves(&self) -> Result<(u128, u128)> {
+        let context = self.context()?;
+        let (a, b) = self.alkanes_for_self()?;
+        Ok((
+            self.balance(&context.myself, &a),
+            self.balance(&context.myself, &b),
+        ))
+    }
+
+    fn init_pool(&self, alkane_a: AlkaneId, alkane_b: AlkaneId) -> Result<CallResponse> {
+        let context = self.context()?;
+        if alkane_a == alkane_b {
+            return Err(anyhow!("amm-pool: pool assets must be distinct"));
+        }
+        self.observe_initialization()?;
+        let (alkane_a, alkane_b) = if alkane_a < alkane_b {
+            (alkane_a, alkane_b)
+        } else {
+            (alkane_b, alkane_a)
+        };
+        let ptr = self.alkanes_pointer();
+        ptr.append(Arc::new(alkane_a.clone().into()));
+        ptr.append(Arc::new(alkane_b.clone().into()));
+        self.factory_pointer()
+            .set(Arc::new(context.caller.clone().into()));
+        self.fee_pointer().set_value::<u128>(DEFAULT_FEE_PER_1000);
+        self.set_name_and_symbol_str(
+            format!(
+                "{}:{}/{}:{} LP",
+                alkane_a.block, alkane_a.tx, alkane_b.block, alkane_b.tx
+            ),
+            String::from("LP"),
+        );
+        self.add_liquidity()
+    }
+
+    fn add_liquidity(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let (a, b) = self.alkanes_for_self()?;
+        let (amounts, rest) = self.split_incoming(&context, &[a, b]);
+        if amounts[0] == 0 || amounts[1] == 0 {
+            return Err(anyhow!(
+                "amm-pool: liquidity must be provided in both pool assets"
+            ));
+        }
+        let (reserve_a, reserve_b) = self.previous_reserves(&context)?;
+        let total_supply = self.total_supply();
+        let liquidity = if total_supply == 0 {
+            let root = to_u128(sqrt(U256::from(amounts[0]) * U256::from(amounts[1])))?;
+            // the locked share still counts towards the supply, it is simply
+            // never paid out
+            self.increase_total_supply(MINIMUM_LIQUIDITY)?;
+            overflow_error(root.checked_sub(MINIMUM_LIQUIDITY))?
+        } else {
+            let supply = U256::from(total_supply);
+            std::cmp::min(
+                to_u128(U256::from(amounts[0]) * supply / U256::from(reserve_a))?,
+                to_u128(U256::from(amounts[1]) * supply / U256::from(reserve_b))?,
+            )
+        };
+        if liquidity == 0 {
+            return Err(anyhow!("amm-pool: insufficient liquidity minted"));
+        }
+        let mut response = CallResponse::forward(&rest);
+        response
+            .alkanes
+            .0
+            .push(<Self as MintableToken>::mint(self, &context, liquidity)?);
+        Ok(response)
+    }
+
+    fn burn(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let (a, b) = self.alkanes_for_self()?;
+        let (amounts, rest) = self.split_incoming(&context, &[context.myself.clone()]);
+        let liquidity = amounts[0];
+        if liquidity == 0 {
+            return Err(anyhow!("amm-pool: no LP tokens supplied to burn"));
+        }
+        // pool assets sent along with the LP tokens are refunded through
+        // `rest`, so they must not count towards the share paid out
+        let (reserve_a, reserve_b) = self.previous_reserves(&context)?;
+        let total_supply = U256::from(self.total_supply());
+        let amount_a = to_u128(U256::from(liquidity) * U256::from(reserve_a) / total_supply)?;
+        let amount_b = to_u128(U256::from(liquidity) * U256::from(reserve_b) / total_supply)?;
+        if amount_a == 0 || amount_b == 0 {
+            return Err(anyhow!("amm-pool: insufficient liquidity burned"));
+        }
+        self.set_total_supply(overflow_error(self.total_supply().checked_sub(liquidity))?);
+        let mut response = CallResponse::forward(&rest);
+        response.alkanes.0.push(AlkaneTransfer {
+            id: a,
+            value: amount_a,
+        });
+        response.alkanes.0.push(AlkaneTransfer {
+            id: b,
+            value: amount_b,
+        });
+        Ok(response)
+    }
+
+    fn swap(&self, amount_out_min: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let (a, b) = self.alkanes_for_self()?;
+        let (amounts, rest) = self.split_incoming(&context, &[a.clone(), b.clone()]);
+        let (reserve_a, reserve_b) = self.previous_reserves(&context)?;
+        let (amount_in, reserve_in, reserve_out, id_out) = match (amounts[0], amounts[1]) {
+            (0, 0) => return Err(anyhow!("amm-pool: no pool asset supplied to swap")),
+            (amount, 0) => (amount, reserve_a, reserve_b, b),
+            (0, amount) => (amount, reserve_b, reserve_a, a),
+            _ => {
+                return Err(anyhow!(
+                    "amm-pool: supply only one of the pool assets to swap"
+                ))
+            }
+        };
+        let amount_out = self.get_amount_out(amount_in, reserve_in, reserve_out)?;
+        if amount_out < amount_out_min {
+            return Err(anyhow!(
+                "amm-pool: output {} is below the minimum of {}",
+                amount_out,
+                amount_out_min
+            ));
+        }
+        let mut response = CallResponse::forward(&rest);
+        response.alkanes.0.push(AlkaneTransfer {
+            id: id_out,
+            value: amount_out,
+        });
+        Ok(response)
+    }
+
+    fn get_amount_out(&self, amount_in: u128, reserve_in: u128, reserve_out: u128) -> Result<u128> {
+        if reserve_in == 0 || reserve_out == 0 {
+            return Err(anyhow!("amm-pool: pool has no liquidity"));
+        }
+        let amount_in_with_fee =
+            U256::from(amount_in) * U256::from(overflow_error(1000u128.checked_sub(self.fee()))?);
+        let numerator = amount_in_with_fee * U256::from(reserve_out);
+        let denominator = U256::from(reserve_in) * U256::from(1000u128) + amount_in_with_fee;
+        let amount_out = to_u128(numerator / denominator)?;
+        if amount_out == 0 || amount_out >= reserve_out {
+            return Err(anyhow!("amm-pool: insufficient output amount"));
+        }
+        Ok(amount_out)
+    }
+
+    fn set_fee(&self, fee_per_1000: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        if context.caller != self.factory()? {
+            return Err(anyhow!("amm-pool: only the factory can set the fee"));
+        }
+        if fee_per_1000 >= 1000 {
+            return Err(anyhow!("amm-pool: fee must be below 1000 parts per thousand"));
+        }
+        self.fee_pointer().set_value::<u128>(fee_per_1000);
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn get_reserves(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let (reserve_a, reserve_b) = self.reserves()?;
+        response.data = reserve_a.to_le_bytes().to_vec();
+        response.data.extend(&reserve_b.to_le_bytes());
+        Ok(response)
+    }
+
+    fn get_pool_details(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let (a, b) = self.alkanes_for_self()?;
+        let (reserve_a, reserve_b) = self.reserves()?;
+        response.data = a.into();
+        response.data.extend(<AlkaneId as Into<Vec<u8>>>::into(b));
+        response.data.extend(&reserve_a.to_le_bytes());
+        response.data.extend(&reserve_b.to_le_bytes());
+        response.data.extend(&self.total_supply().to_le_bytes());
+        response.data.extend(&self.fee().to_le_bytes());
+        Ok(response)
+    }
+
+    fn get_name(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = self.name().into_bytes().to_vec();
+        Ok(response)
+    }
+
+    fn get_symbol(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = self.symbol().into_bytes().to_vec();
+        Ok(response)
+    }
+
+    fn get_total_supply(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = self.total_supply().to_le_bytes().to_vec();
+        Ok(response)
+    }
+}
+
+impl AlkaneResponder for AMMPool {
+    fn execute(&self) -> Result<CallResponse> {
+        // The opcode extraction and dispatch logic is now handled by the declare_alkane macro
+        // This method is still required by the AlkaneResponder trait, but we can just return an error
+        // indicating that it should not be called directly
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for AMMPool {
+        type Message = AMMPoolMessage;
+    }
+}
@@ -0,0 +1,281 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::storage::StoragePointer;
+use alkanes_runtime::{declare_alkane, message::MessageDispatch};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_std_factory_support::MintableToken;
+use alkanes_support::response::CallResponse;
+use alkanes_support::utils::overflow_error;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::Transaction;
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_decode;
+use std::io::Cursor;
+
+// A fair-mint token: anyone calling Mint receives value_per_mint units until
+// the cap is reached. The mint can be confined to a window of block heights
+// and each transaction can be limited in how many mints it makes. For every
+// optional setting, 0 means unset.
+
+#[derive(Default)]
+pub struct FreeMint(());
+
+impl MintableToken for FreeMint {}
+
+#[derive(MessageDispatch)]
+enum FreeMintMessage {
+    #[opcode(0)]
+    Initialize {
+        name: u128,
+        symbol: u128,
+        // the most units that will ever exist, premine included
+        cap: u128,
+        value_per_mint: u128,
+        premine: u128,
+        start_height: u128,
+        end_height: u128,
+        mints_per_transaction: u128,
+    },
+
+    #[opcode(77)]
+    Mint,
+
+    #[opcode(99)]
+    #[returns(String)]
+    GetName,
+
+    #[opcode(100)]
+    #[returns(String)]
+    GetSymbol,
+
+    #[opcode(101)]
+    #[returns(u128)]
+    GetTotalSupply,
+
+    #[opcode(102)]
+    #[returns(u128)]
+    GetCap,
+
+    #[opcode(103)]
+    #[returns(u128)]
+    GetValuePerMint,
+
+    #[opcode(104)]
+    #[returns(u128)]
+    GetMinted,
+}
+
+impl FreeMint {
+    fn cap_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/cap")
+    }
+
+    fn value_per_mint_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/value-per-mint")
+    }
+
+    fn start_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/start-height")
+    }
+
+    fn end_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/end-height")
+    }
+
+    fn mints_per_transaction_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/mints-per-transaction")
+    }
+
+    // the number of mints made so far
+    fn minted_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/minted")
+    }
+
+    fn transaction_mints_pointer(&self) -> Result<StoragePointer> {
+        let tx = consensus_decode::<Transaction>(&mut Cursor::new(self.transaction()))?;
+        Ok(StoragePointer::from_keyword("/transaction-mints/")
+            .select(&tx.compute_txid().as_byte_array().to_vec()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn initialize(
+        &self,
+        name: u128,
+        symbol: u128,
+        cap: u128,
+        value_per_mint: u128,
+        premine: u128,
+        start_height: u128,
+        end_height: u128,
+        mints_per_transaction: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.observe_initialization()?;
+        if value_per_mint == 0 {
+            return Err(anyhow!("value_per_mint must be nonzero"));
+        }
+        if premine > cap {
+            return Err(anyhow!("premine exceeds the cap"));
+        }
+        if end_height != 0 && end_height < start_height {
+            return Err(anyhow!("mint window ends before it starts"));
+        }
+        <Self as MintableToken>::set_name_and_symbol(self, name, symbol);
+        self.cap_pointer().set_value::<u128>(cap);
+        self.value_per_mint_pointer()
+            .set_value::<u128>(value_per_mint);
+        self.start_height_pointer()
+            .set_value::<u64>(start_height.try_into()?);
+        self.end_height_pointer()
+            .set_value::<u64>(end_height.try_into()?);
+        self.mints_per_transaction_pointer()
+            .set_value::<u128>(mints_per_transaction);
+        if premine != 0 {
+            response
+                .alkanes
+                .0
+                .push(<Self as MintableToken>::mint(self, &context, premine)?);
+        }
+
+        Ok(response)
+    }
+
+    fn observe_mint_window(&self) -> Result<()> {
+        let height = self.height();
+        let start_height = self.start_height_pointer().get_value::<u64>();
+        let end_height = self.end_height_pointer().get_value::<u64>();
+        if height < start_height {
+            return Err(anyhow!("mint opens at block {}", start_height));
+        }
+        if end_height != 0 && height > end_height {
+            return Err(anyhow!("mint closed at block {}", end_height));
+        }
+        Ok(())
+    }
+
+    fn observe_transaction_limit(&self) -> Result<()> {
+        let limit = self.mints_per_transaction_pointer().get_value::<u128>();
+        if limit == 0 {
+            return Ok(());
+        }
+        let mut pointer = self.transaction_mints_pointer()?;
+        let mints = pointer.get_value::<u128>();
+        if mints >= limit {
+            return Err(anyhow!(
+                "transaction already made {} mints, the most allowed",
+                limit
+            ));
+        }
+        pointer.set_value::<u128>(mints + 1);
+        Ok(())
+    }
+
+    fn mint(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.observe_mint_window()?;
+        self.observe_transaction_limit()?;
+        let value = self.value_per_mint_pointer().get_value::<u128>();
+        if overflow_error(self.total_supply().checked_add(value))?
+            > self.cap_pointer().get_value::<u128>()
+        {
+            return Err(anyhow!("mint would exceed the cap"));
+        }
+        let mut minted = self.minted_pointer();
+        minted.set_value::<u128>(minted.get_value::<u128>() + 1);
+        response
+            .alkanes
+            .0
+            .push(<Self as MintableToken>::mint(self, &context, value)?);
+
+        Ok(response)
+    }
+
+    fn get_name(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.name().into_bytes();
+
+        Ok(response)
+    }
+
+    fn get_symbol(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.symbol().into_bytes();
+
+        Ok(response)
+    }
+
+    fn get_total_supply(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.total_supply().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    fn get_cap(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .cap_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    fn get_value_per_mint(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .value_per_mint_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    fn get_minted(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .minted_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+}
+
+impl AlkaneResponder for FreeMint {
+    fn execute(&self) -> Result<CallResponse> {
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for FreeMint {
+        type Message = FreeMintMessage;
+    }
+}
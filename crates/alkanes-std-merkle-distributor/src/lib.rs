@@ -6,11 +6,7 @@ use alkanes_runtime::{
     stdio::{stdout, Write},
 };
 use alkanes_support::{
-    id::AlkaneId,
-    parcel::AlkaneTransfer,
-    response::CallResponse,
-    utils::{shift_bytes32_or_err, shift_or_err},
-    witness::find_witness_payload,
+    id::AlkaneId, parcel::AlkaneTransfer, response::CallResponse, witness::find_witness_payload,
 };
 use anyhow::{anyhow, Result};
 use bitcoin::Transaction;
@@ -35,6 +31,47 @@ enum MerkleDistributorMessage {
 
     #[opcode(1)]
     Claim,
+
+    #[opcode(2)]
+    #[returns(u128)]
+    IsClaimed { index: u128 },
+}
+
+// The leaf layout is fixed for the whole tree when the distributor is
+// initialized, since nothing in a leaf can tell the two apart. Legacy leaves
+// are 40 bytes: a 20 byte script, a u32 index and a u128 amount. Version 1
+// leaves carry a u32 script length ahead of the script, so P2TR and P2WSH
+// outputs can be paid
+pub const LEAF_VERSION_LEGACY: u128 = 0;
+pub const LEAF_VERSION_1: u128 = 1;
+
+const LEGACY_SCRIPT_SIZE: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaf {
+    pub script: Vec<u8>,
+    pub index: u32,
+    pub amount: u128,
+}
+
+// splits a leaf off the front of a claim payload, returning it with the exact
+// bytes it was hashed from
+pub fn consume_leaf(cursor: &mut Cursor<Vec<u8>>, version: u128) -> Result<(Leaf, Vec<u8>)> {
+    let start = cursor.position() as usize;
+    let script = match version {
+        LEAF_VERSION_1 => {
+            let length = consume_sized_int::<u32>(cursor)? as usize;
+            consume_exact(cursor, length)?
+        }
+        _ => consume_exact(cursor, LEGACY_SCRIPT_SIZE)?,
+    };
+    let leaf = Leaf {
+        script,
+        index: consume_sized_int::<u32>(cursor)?,
+        amount: consume_sized_int::<u128>(cursor)?,
+    };
+    let end = cursor.position() as usize;
+    Ok((leaf, cursor.get_ref()[start..end].to_vec()))
 }
 
 pub fn overflow_error(v: Option<u128>) -> Result<u128> {
@@ -65,29 +102,25 @@ impl MerkleDistributor {
                 Cursor::<Vec<u8>>::new(find_witness_payload(&tx, 0).ok_or("").map_err(|_| {
                     anyhow!("merkle-distributor: witness envelope at index 0 does not contain data")
                 })?);
-            let leaf = consume_exact(&mut cursor, 40)?;
-            let leaf_hash = Sha256::hash(&leaf);
+            let (leaf, leaf_bytes) = consume_leaf(&mut cursor, self.leaf_version())?;
+            let leaf_hash = Sha256::hash(&leaf_bytes);
             let proof = consume_to_end(&mut cursor)?;
-            let mut leaf_cursor = Cursor::new(leaf.clone());
-            let p2sh = consume_exact(&mut leaf_cursor, 20)?;
-            let index = consume_sized_int::<u32>(&mut leaf_cursor)? as usize;
-            let amount = consume_sized_int::<u128>(&mut leaf_cursor)?;
+            if self.claimed(leaf.index) {
+                return Err(anyhow!("index {} has already been claimed", leaf.index));
+            }
             if MerkleProof::<Sha256>::try_from(proof)?.verify(
                 self.root()?,
-                &[index],
+                &[leaf.index as usize],
                 &[leaf_hash],
                 self.length(),
             ) {
-                if tx.output[(vout as usize) as usize]
-                    .script_pubkey
-                    .clone()
-                    .into_bytes()
-                    .to_vec()
-                    != p2sh
-                {
+                // vout is the protostone's shadow vout, the claim is paid to
+                // wherever its pointer sends it
+                if tx.output[pointer as usize].script_pubkey.as_bytes() != leaf.script.as_slice() {
                     Err(anyhow!("spendable output created does not match proof"))
                 } else {
-                    Ok(amount)
+                    self.set_claimed(leaf.index);
+                    Ok(leaf.amount)
                 }
             } else {
                 Err(anyhow!("proof verification failure"))
@@ -113,6 +146,14 @@ impl MerkleDistributor {
         self.root_pointer().set(Arc::new(v))
     }
 
+    pub fn leaf_version_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/leaf-version")
+    }
+
+    pub fn leaf_version(&self) -> u128 {
+        self.leaf_version_pointer().get_value::<u128>()
+    }
+
     pub fn length(&self) -> usize {
         self.length_pointer().get_value::<usize>()
     }
@@ -125,6 +166,21 @@ impl MerkleDistributor {
             .map_err(|_| anyhow!("root bytes in storage are not of length 32"))
     }
 
+    // claims are tracked as a bitmap of 128 indices per storage word
+    pub fn claimed_pointer(&self, index: u32) -> StoragePointer {
+        StoragePointer::from_keyword("/claimed/").select(&(index / 128).to_le_bytes().to_vec())
+    }
+
+    pub fn claimed(&self, index: u32) -> bool {
+        self.claimed_pointer(index).get_value::<u128>() & (1u128 << (index % 128)) != 0
+    }
+
+    pub fn set_claimed(&self, index: u32) {
+        let mut pointer = self.claimed_pointer(index);
+        let word = pointer.get_value::<u128>();
+        pointer.set_value::<u128>(word | (1u128 << (index % 128)));
+    }
+
     pub fn alkane_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/alkane")
     }
@@ -137,9 +193,18 @@ impl MerkleDistributor {
         self.alkane_pointer().set(Arc::<Vec<u8>>::new(v.into()));
     }
 
+    // the root is root_bytes followed by the next input, each as 16 little
+    // endian bytes. An optional input after the root picks the leaf layout
     fn initialize(&self, length: u128, root_bytes: u128) -> Result<CallResponse> {
         let context = self.context()?;
-        let mut inputs = context.inputs.clone();
+        let mut rest = context.inputs.iter().skip(3).cloned();
+        let root_high = rest
+            .next()
+            .ok_or_else(|| anyhow!("root needs a second input"))?;
+        let leaf_version = rest.next().unwrap_or(LEAF_VERSION_LEGACY);
+        if leaf_version != LEAF_VERSION_LEGACY && leaf_version != LEAF_VERSION_1 {
+            return Err(anyhow!("unknown leaf version {}", leaf_version));
+        }
 
         let mut pointer = StoragePointer::from_keyword("/initialized");
         if pointer.get().len() == 0 {
@@ -149,9 +214,11 @@ impl MerkleDistributor {
             }
             self.set_alkane(context.incoming_alkanes.0[0].id.clone());
 
-            // Extract the remaining parameters from inputs
             self.set_length(length.try_into().unwrap());
-            self.set_root(shift_bytes32_or_err(&mut inputs)?);
+            let mut root = root_bytes.to_le_bytes().to_vec();
+            root.extend(&root_high.to_le_bytes());
+            self.set_root(root);
+            self.leaf_version_pointer().set_value::<u128>(leaf_version);
 
             Ok(CallResponse::default())
         } else {
@@ -170,6 +237,16 @@ impl MerkleDistributor {
 
         Ok(response)
     }
+
+    fn is_claimed(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let index: u32 = index
+            .try_into()
+            .map_err(|_| anyhow!("claim index exceeds u32"))?;
+        response.data = (self.claimed(index) as u128).to_le_bytes().to_vec();
+        Ok(response)
+    }
 }
 
 impl AlkaneResponder for MerkleDistributor {
@@ -0,0 +1,406 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::storage::StoragePointer;
+use alkanes_runtime::{auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_std_factory_support::MintableToken;
+use alkanes_support::{
+    cellpack::Cellpack,
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+    witness::find_witness_payload,
+};
+use anyhow::{anyhow, Result};
+use bitcoin::Transaction;
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_decode;
+use std::sync::Arc;
+
+// A collection of orbitals. Each Mint clones the template orbital and
+// initializes the clone as the next item of the collection, which then
+// answers its name, symbol and data from here. The holder of the auth token
+// minted at initialization sets per-item metadata and withdraws mint
+// proceeds. The total supply is the number of items minted.
+//
+// The template must be an orbital, or anything else that takes opcode 1
+// (InitializeItem) with the item's index. Initialize cannot probe for that
+// without initializing the template itself, so it only checks the template
+// can be cloned. Against any other template every Mint reverts, payment
+// included.
+
+#[derive(Default)]
+pub struct OrbitalCollection(());
+
+impl MintableToken for OrbitalCollection {}
+
+impl AuthenticatedResponder for OrbitalCollection {}
+
+#[derive(MessageDispatch)]
+enum OrbitalCollectionMessage {
+    // a price_amount of 0 makes minting free. The template is an orbital,
+    // see above
+    #[opcode(0)]
+    Initialize {
+        name: u128,
+        symbol: u128,
+        max_supply: u128,
+        template_block: u128,
+        template_tx: u128,
+        price_block: u128,
+        price_tx: u128,
+        price_amount: u128,
+        auth_token_units: u128,
+    },
+
+    // metadata is read from the envelope in the calling transaction
+    #[opcode(1)]
+    SetItemData { index: u128 },
+
+    #[opcode(2)]
+    Withdraw,
+
+    #[opcode(77)]
+    Mint,
+
+    #[opcode(99)]
+    #[returns(String)]
+    GetName,
+
+    #[opcode(100)]
+    #[returns(String)]
+    GetSymbol,
+
+    #[opcode(101)]
+    #[returns(u128)]
+    GetTotalSupply,
+
+    #[opcode(102)]
+    #[returns(u128)]
+    GetMaxSupply,
+
+    #[opcode(103)]
+    #[returns(Vec<u8>)]
+    GetItem { index: u128 },
+
+    // a limit of 0 returns every item from offset on
+    #[opcode(104)]
+    #[returns(Vec<u8>)]
+    GetItems { offset: u128, limit: u128 },
+
+    #[opcode(105)]
+    #[returns(Vec<u8>)]
+    GetItemData { index: u128 },
+
+    #[opcode(106)]
+    #[returns(Vec<u8>)]
+    GetMintPrice,
+
+    #[opcode(107)]
+    #[returns(Vec<u8>)]
+    GetTemplate,
+}
+
+impl OrbitalCollection {
+    fn max_supply_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/max-supply")
+    }
+
+    fn template_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/template")
+    }
+
+    fn template(&self) -> Result<AlkaneId> {
+        Ok(self.template_pointer().get().as_ref().clone().try_into()?)
+    }
+
+    fn price_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/price")
+    }
+
+    fn price(&self) -> Result<AlkaneTransfer> {
+        let pointer = self.price_pointer();
+        Ok(AlkaneTransfer {
+            id: pointer.keyword("/id").get().as_ref().clone().try_into()?,
+            value: pointer.keyword("/amount").get_value::<u128>(),
+        })
+    }
+
+    // item ids in mint order
+    fn items_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/items")
+    }
+
+    fn item(&self, index: u128) -> Result<AlkaneId> {
+        if index >= self.total_supply() {
+            return Err(anyhow!("no item {} has been minted", index));
+        }
+        Ok(self
+            .items_pointer()
+            .select_index(index.try_into()?)
+            .get()
+            .as_ref()
+            .clone()
+            .try_into()?)
+    }
+
+    fn item_data_pointer(&self, index: u128) -> StoragePointer {
+        StoragePointer::from_keyword("/item-data/").select(&index.to_le_bytes().to_vec())
+    }
+
+    // the factory cellpack target that clones the template
+    fn clone_target(template: &AlkaneId) -> Result<AlkaneId> {
+        match template.block {
+            2 => Ok(AlkaneId::new(5, template.tx)),
+            4 => Ok(AlkaneId::new(6, template.tx)),
+            _ => Err(anyhow!("template {:?} cannot be cloned", template)),
+        }
+    }
+
+    // what came in, less the units of `id` taken by the collection
+    fn forward_less(
+        incoming: &AlkaneTransferParcel,
+        id: &AlkaneId,
+        mut value: u128,
+    ) -> Result<AlkaneTransferParcel> {
+        let mut result = AlkaneTransferParcel::default();
+        for transfer in incoming.0.iter() {
+            if &transfer.id == id {
+                let taken = std::cmp::min(transfer.value, value);
+                value -= taken;
+                if transfer.value > taken {
+                    result.pay(AlkaneTransfer {
+                        id: transfer.id,
+                        value: transfer.value - taken,
+                    });
+                }
+            } else {
+                result.pay(*transfer);
+            }
+        }
+        if value != 0 {
+            return Err(anyhow!("mint price not paid, {} of {:?} short", value, id));
+        }
+        Ok(result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn initialize(
+        &self,
+        name: u128,
+        symbol: u128,
+        max_supply: u128,
+        template_block: u128,
+        template_tx: u128,
+        price_block: u128,
+        price_tx: u128,
+        price_amount: u128,
+        auth_token_units: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.observe_initialization()?;
+        let template = AlkaneId::new(template_block, template_tx);
+        Self::clone_target(&template)?;
+        <Self as MintableToken>::set_name_and_symbol(self, name, symbol);
+        self.max_supply_pointer().set_value::<u128>(max_supply);
+        self.template_pointer().set(Arc::new(template.into()));
+        let price = self.price_pointer();
+        price
+            .keyword("/id")
+            .set(Arc::new(AlkaneId::new(price_block, price_tx).into()));
+        price.keyword("/amount").set_value::<u128>(price_amount);
+        response
+            .alkanes
+            .0
+            .push(self.deploy_auth_token(auth_token_units)?);
+
+        Ok(response)
+    }
+
+    fn set_item_data(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        if index >= self.max_supply_pointer().get_value::<u128>() {
+            return Err(anyhow!("index {} is past the max supply", index));
+        }
+        let tx = consensus_decode::<Transaction>(&mut std::io::Cursor::new(self.transaction()))?;
+        self.item_data_pointer(index)
+            .set(Arc::new(find_witness_payload(&tx, 0).ok_or_else(|| {
+                anyhow!("orbital-collection: witness envelope at index 0 does not contain data")
+            })?));
+
+        Ok(response)
+    }
+
+    fn withdraw(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        let price = self.price()?;
+        let incoming = context
+            .incoming_alkanes
+            .0
+            .iter()
+            .filter(|v| v.id == price.id)
+            .fold(0u128, |r, v| r + v.value);
+        let value = self
+            .balance(&context.myself, &price.id)
+            .saturating_sub(incoming);
+        if value != 0 {
+            response.alkanes.pay(AlkaneTransfer {
+                id: price.id,
+                value,
+            });
+        }
+
+        Ok(response)
+    }
+
+    fn mint(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let price = self.price()?;
+        let mut response = CallResponse::default();
+        response.alkanes = if price.value == 0 {
+            context.incoming_alkanes.clone()
+        } else {
+            Self::forward_less(&context.incoming_alkanes, &price.id, price.value)?
+        };
+
+        let index = self.total_supply();
+        if index >= self.max_supply_pointer().get_value::<u128>() {
+            return Err(anyhow!("collection is fully minted"));
+        }
+        let sequence = self.sequence();
+        let minted = self.call(
+            &Cellpack {
+                target: Self::clone_target(&self.template()?)?,
+                inputs: vec![0x1, index],
+            },
+            &AlkaneTransferParcel::default(),
+            self.fuel(),
+        )?;
+        let item = AlkaneId::new(2, sequence);
+        self.items_pointer().append(Arc::new(item.into()));
+        self.increase_total_supply(1)?;
+        response.alkanes.0.extend(minted.alkanes.0);
+
+        Ok(response)
+    }
+
+    fn get_name(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.name().into_bytes();
+
+        Ok(response)
+    }
+
+    fn get_symbol(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.symbol().into_bytes();
+
+        Ok(response)
+    }
+
+    fn get_total_supply(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.total_supply().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    fn get_max_supply(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .max_supply_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    fn get_item(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.item(index)?.into();
+
+        Ok(response)
+    }
+
+    fn get_items(&self, offset: u128, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let total = self.total_supply();
+        let end = match limit {
+            0 => total,
+            v => std::cmp::min(total, offset.saturating_add(v)),
+        };
+        for index in offset..end {
+            let item: Vec<u8> = self.item(index)?.into();
+            response.data.extend(item);
+        }
+
+        Ok(response)
+    }
+
+    fn get_item_data(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.item_data_pointer(index).get().as_ref().clone();
+
+        Ok(response)
+    }
+
+    fn get_mint_price(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let price: Vec<u128> = self.price()?.into();
+        response.data = price.into_iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        Ok(response)
+    }
+
+    fn get_template(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.template_pointer().get().as_ref().clone();
+
+        Ok(response)
+    }
+}
+
+impl AlkaneResponder for OrbitalCollection {
+    fn execute(&self) -> Result<CallResponse> {
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for OrbitalCollection {
+        type Message = OrbitalCollectionMessage;
+    }
+}
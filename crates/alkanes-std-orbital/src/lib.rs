@@ -6,11 +6,17 @@ use alkanes_runtime::{
     stdio::{stdout, Write},
 };
 use alkanes_runtime::{runtime::AlkaneResponder, storage::StoragePointer, token::Token};
-use alkanes_support::{parcel::AlkaneTransfer, response::CallResponse};
+use alkanes_support::{
+    cellpack::Cellpack,
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+};
 use anyhow::{anyhow, Result};
 use hex_lit::hex;
 use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
 use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct Orbital(());
@@ -20,6 +26,10 @@ enum OrbitalMessage {
     #[opcode(0)]
     Initialize,
 
+    // called by a collection on the clone it just created
+    #[opcode(1)]
+    InitializeItem { index: u128 },
+
     #[opcode(99)]
     #[returns(String)]
     GetName,
@@ -32,6 +42,14 @@ enum OrbitalMessage {
     #[returns(u128)]
     GetTotalSupply,
 
+    #[opcode(102)]
+    #[returns(u128)]
+    GetIndex,
+
+    #[opcode(103)]
+    #[returns(Vec<u8>)]
+    GetCollection,
+
     #[opcode(1000)]
     #[returns(Vec<u8>)]
     GetData,
@@ -69,6 +87,67 @@ impl Orbital {
         }
     }
 
+    pub fn collection_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/collection")
+    }
+
+    // the collection this orbital was minted by, if any
+    pub fn collection(&self) -> Result<Option<AlkaneId>> {
+        let collection = self.collection_pointer().get();
+        if collection.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(collection.as_ref().clone().try_into()?))
+        }
+    }
+
+    pub fn index_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/index")
+    }
+
+    fn query_collection(&self, collection: &AlkaneId, inputs: Vec<u128>) -> Result<Vec<u8>> {
+        Ok(self
+            .staticcall(
+                &Cellpack {
+                    target: collection.clone(),
+                    inputs,
+                },
+                &AlkaneTransferParcel::default(),
+                self.fuel(),
+            )?
+            .data)
+    }
+
+    fn item_name(&self) -> Result<String> {
+        match self.collection()? {
+            Some(collection) => Ok(format!(
+                "{} #{}",
+                String::from_utf8(self.query_collection(&collection, vec![99])?)?,
+                self.index_pointer().get_value::<u128>()
+            )),
+            None => Ok(self.name()),
+        }
+    }
+
+    fn item_symbol(&self) -> Result<String> {
+        match self.collection()? {
+            Some(collection) => Ok(String::from_utf8(
+                self.query_collection(&collection, vec![100])?,
+            )?),
+            None => Ok(self.symbol()),
+        }
+    }
+
+    fn item_data(&self) -> Result<Vec<u8>> {
+        match self.collection()? {
+            Some(collection) => self.query_collection(
+                &collection,
+                vec![105, self.index_pointer().get_value::<u128>()],
+            ),
+            None => Ok(self.data()),
+        }
+    }
+
     pub fn data(&self) -> Vec<u8> {
         // in this reference implementation, we return a 1x1 PNG
         // NFT data can be anything, however
@@ -89,11 +168,28 @@ impl Orbital {
         Ok(response)
     }
 
+    fn initialize_item(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.observe_initialization()?;
+        self.collection_pointer()
+            .set(Arc::new(context.caller.clone().into()));
+        self.index_pointer().set_value::<u128>(index);
+        self.set_total_supply(1);
+        response.alkanes.0.push(AlkaneTransfer {
+            id: context.myself.clone(),
+            value: 1u128,
+        });
+
+        Ok(response)
+    }
+
     fn get_name(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.name().into_bytes().to_vec();
+        response.data = self.item_name()?.into_bytes().to_vec();
 
         Ok(response)
     }
@@ -102,7 +198,7 @@ impl Orbital {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.symbol().into_bytes().to_vec();
+        response.data = self.item_symbol()?.into_bytes().to_vec();
 
         Ok(response)
     }
@@ -120,7 +216,29 @@ impl Orbital {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.data();
+        response.data = self.item_data()?;
+
+        Ok(response)
+    }
+
+    fn get_index(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .index_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    fn get_collection(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.collection_pointer().get().as_ref().clone();
 
         Ok(response)
     }
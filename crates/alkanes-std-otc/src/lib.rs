@@ -0,0 +1,241 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::storage::StoragePointer;
+use alkanes_runtime::{auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_support::{parcel::AlkaneTransfer, response::CallResponse};
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::Transaction;
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_decode;
+use std::io::Cursor;
+use std::sync::Arc;
+
+// An over-the-counter escrow settling alkanes against BTC. A seller lists the
+// alkanes sent with List, asking a number of sats to be paid to a
+// scriptPubKey, and receives an auth token for the listing. Anyone can Buy a
+// listing in a transaction that pays the ask to that script; the alkanes go
+// wherever the Buy protostone points. The holder of the listing's auth token
+// can Cancel it while it is open and take the alkanes back.
+
+#[derive(Default)]
+pub struct Otc(());
+
+impl AuthenticatedResponder for Otc {}
+
+pub const LISTING_OPEN: u8 = 0;
+pub const LISTING_SOLD: u8 = 1;
+pub const LISTING_CANCELLED: u8 = 2;
+
+#[derive(MessageDispatch)]
+enum OtcMessage {
+    // the payout script is packed little-endian into words, the last one
+    // padded past script_length
+    #[opcode(1)]
+    #[returns(u128)]
+    List {
+        ask_sats: u128,
+        script_length: u128,
+        script: Vec<u128>,
+    },
+
+    #[opcode(2)]
+    Buy { listing: u128 },
+
+    #[opcode(3)]
+    Cancel { listing: u128 },
+
+    #[opcode(100)]
+    #[returns(Vec<u8>)]
+    GetListing { listing: u128 },
+
+    #[opcode(101)]
+    #[returns(u128)]
+    GetListingCount,
+}
+
+impl Otc {
+    fn count_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/count")
+    }
+
+    fn listing_pointer(&self, listing: u128) -> StoragePointer {
+        StoragePointer::from_keyword("/listings/").select(&listing.to_le_bytes().to_vec())
+    }
+
+    fn listing(&self, listing: u128) -> Result<StoragePointer> {
+        if listing >= self.count_pointer().get_value::<u128>() {
+            return Err(anyhow!("no listing {}", listing));
+        }
+        Ok(self.listing_pointer(listing))
+    }
+
+    fn open_listing(&self, listing: u128) -> Result<StoragePointer> {
+        let pointer = self.listing(listing)?;
+        match pointer.keyword("/status").get_value::<u8>() {
+            LISTING_OPEN => Ok(pointer),
+            LISTING_SOLD => Err(anyhow!("listing {} was sold", listing)),
+            _ => Err(anyhow!("listing {} was cancelled", listing)),
+        }
+    }
+
+    fn deposit(&self, listing: &StoragePointer) -> Result<AlkaneTransfer> {
+        Ok(AlkaneTransfer {
+            id: listing
+                .keyword("/alkane")
+                .get()
+                .as_ref()
+                .clone()
+                .try_into()?,
+            value: listing.keyword("/amount").get_value::<u128>(),
+        })
+    }
+
+    fn close(&self, listing: &StoragePointer, status: u8) {
+        listing.keyword("/status").set_value::<u8>(status);
+    }
+
+    // outputs already counted as payment for a listing, so one output cannot
+    // settle two listings asking the same script
+    fn spent_output_pointer(&self, tx: &Transaction, vout: usize) -> StoragePointer {
+        StoragePointer::from_keyword("/paid/")
+            .select(&tx.compute_txid().as_byte_array().to_vec())
+            .select(&(vout as u32).to_le_bytes().to_vec())
+    }
+
+    fn list(&self, ask_sats: u128, script_length: u128, script: Vec<u128>) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::default();
+
+        let deposit = match context.incoming_alkanes.0.as_slice() {
+            [deposit] if deposit.value != 0 => *deposit,
+            _ => return Err(anyhow!("send exactly one alkane to list")),
+        };
+        let ask: u64 = ask_sats.try_into()?;
+        if ask == 0 {
+            return Err(anyhow!("ask must be nonzero"));
+        }
+        let mut script: Vec<u8> = script.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let script_length: usize = script_length.try_into()?;
+        if script_length == 0 || script_length > script.len() {
+            return Err(anyhow!("script_length does not fit the script words"));
+        }
+        script.truncate(script_length);
+
+        let mut count = self.count_pointer();
+        let id = count.get_value::<u128>();
+        count.set_value::<u128>(id + 1);
+        let listing = self.listing_pointer(id);
+        listing.keyword("/alkane").set(Arc::new(deposit.id.into()));
+        listing.keyword("/amount").set_value::<u128>(deposit.value);
+        listing.keyword("/ask").set_value::<u64>(ask);
+        listing.keyword("/script").set(Arc::new(script));
+        self.close(&listing, LISTING_OPEN);
+        // the listing's own auth token, which controls it
+        response
+            .alkanes
+            .pay(self.deploy_auth_token_at(1, &mut listing.keyword("/auth"))?);
+        response.data = id.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    fn buy(&self, listing: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let pointer = self.open_listing(listing)?;
+        let ask = pointer.keyword("/ask").get_value::<u64>();
+        let script = pointer.keyword("/script").get();
+        let tx = consensus_decode::<Transaction>(&mut Cursor::new(self.transaction()))?;
+        let vout = (0..tx.output.len())
+            .find(|&vout| {
+                let output = &tx.output[vout];
+                output.script_pubkey.as_bytes() == script.as_slice()
+                    && output.value.to_sat() >= ask
+                    && self.spent_output_pointer(&tx, vout).get().len() == 0
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "transaction does not pay the {} sat ask of listing {}",
+                    ask,
+                    listing
+                )
+            })?;
+        self.spent_output_pointer(&tx, vout).set_value::<u8>(0x01);
+        self.close(&pointer, LISTING_SOLD);
+        response.alkanes.pay(self.deposit(&pointer)?);
+
+        Ok(response)
+    }
+
+    fn cancel(&self, listing: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let pointer = self.open_listing(listing)?;
+        self.only_owner_at(&pointer.keyword("/auth"))?;
+        self.close(&pointer, LISTING_CANCELLED);
+        response.alkanes.pay(self.deposit(&pointer)?);
+
+        Ok(response)
+    }
+
+    // the listed alkane id and amount, the ask in sats and the status as
+    // little-endian u128s, then the auth token id and the payout script
+    fn get_listing(&self, listing: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let pointer = self.listing(listing)?;
+        let deposit: Vec<u128> = self.deposit(&pointer)?.into();
+        response.data = deposit
+            .into_iter()
+            .chain([
+                pointer.keyword("/ask").get_value::<u64>() as u128,
+                pointer.keyword("/status").get_value::<u8>() as u128,
+            ])
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        response
+            .data
+            .extend(pointer.keyword("/auth").get().as_ref());
+        response
+            .data
+            .extend(pointer.keyword("/script").get().as_ref());
+
+        Ok(response)
+    }
+
+    fn get_listing_count(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .count_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+}
+
+impl AlkaneResponder for Otc {
+    fn execute(&self) -> Result<CallResponse> {
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for Otc {
+        type Message = OtcMessage;
+    }
+}
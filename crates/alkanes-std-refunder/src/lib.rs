@@ -0,0 +1,163 @@
+use alkanes_runtime::collections::StorageSet;
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::storage::StoragePointer;
+use alkanes_runtime::{auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch};
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_support::{
+    id::AlkaneId,
+    parcel::{AlkaneTransfer, AlkaneTransferParcel},
+    response::CallResponse,
+};
+use anyhow::{anyhow, Result};
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+
+// Catches alkanes sent to it by mistake. Refund hands whatever came in back
+// out, to the output the calling protostone points at. Escrow keeps it
+// instead, and the holder of the auth token minted at initialization can
+// later Recover it to an output of their choosing.
+
+#[derive(Default)]
+pub struct Refunder(());
+
+impl AuthenticatedResponder for Refunder {}
+
+#[derive(MessageDispatch)]
+enum RefunderMessage {
+    #[opcode(0)]
+    Initialize { auth_token_units: u128 },
+
+    #[opcode(50)]
+    Refund,
+
+    #[opcode(51)]
+    Escrow,
+
+    // an amount of 0 recovers the whole escrowed balance
+    #[opcode(52)]
+    Recover { block: u128, tx: u128, amount: u128 },
+
+    #[opcode(1000)]
+    #[returns(Vec<u8>)]
+    GetEscrowed,
+}
+
+impl Refunder {
+    // every alkane ever escrowed, each listed once
+    fn escrowed_set(&self) -> StorageSet<AlkaneId> {
+        StorageSet::new("/escrowed")
+    }
+
+    fn escrowed(&self) -> Result<AlkaneTransferParcel> {
+        let context = self.context()?;
+        Ok(AlkaneTransferParcel(
+            self.escrowed_set()
+                .iter()
+                .map(|id| {
+                    let id = id?;
+                    Ok(AlkaneTransfer {
+                        id,
+                        value: self.balance(&context.myself, &id),
+                    })
+                })
+                .collect::<Result<Vec<AlkaneTransfer>>>()?
+                .into_iter()
+                .filter(|v| v.value != 0)
+                .collect(),
+        ))
+    }
+
+    fn initialize(&self, auth_token_units: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.observe_initialization()?;
+        response
+            .alkanes
+            .0
+            .push(self.deploy_auth_token(auth_token_units)?);
+
+        Ok(response)
+    }
+
+    fn refund(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn escrow(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let escrowed = self.escrowed_set();
+        for transfer in context.incoming_alkanes.0.iter() {
+            escrowed.insert(&transfer.id);
+        }
+        Ok(CallResponse::default())
+    }
+
+    fn recover(&self, block: u128, tx: u128, amount: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        let id = AlkaneId { block, tx };
+        if !self.escrowed_set().contains(&id) {
+            return Err(anyhow!("{:?} was never escrowed", id));
+        }
+        // alkanes that came in with this call, the auth token among them,
+        // are already being forwarded and are not part of the escrow
+        let incoming = context
+            .incoming_alkanes
+            .0
+            .iter()
+            .filter(|v| v.id == id)
+            .fold(0u128, |r, v| r + v.value);
+        let escrowed = self.balance(&context.myself, &id).saturating_sub(incoming);
+        let value = match amount {
+            0 => escrowed,
+            v if v > escrowed => {
+                return Err(anyhow!("only {} of {:?} is escrowed", escrowed, id));
+            }
+            v => v,
+        };
+        response.alkanes.pay(AlkaneTransfer { id, value });
+
+        Ok(response)
+    }
+
+    fn get_escrowed(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.escrowed()?.serialize();
+
+        Ok(response)
+    }
+
+    fn observe_initialization(&self) -> Result<()> {
+        let mut pointer = StoragePointer::from_keyword("/initialized");
+        if pointer.get().len() == 0 {
+            pointer.set_value::<u8>(0x01);
+            Ok(())
+        } else {
+            Err(anyhow!("already initialized"))
+        }
+    }
+}
+
+impl AlkaneResponder for Refunder {
+    fn execute(&self) -> Result<CallResponse> {
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for Refunder {
+        type Message = RefunderMessage;
+    }
+}
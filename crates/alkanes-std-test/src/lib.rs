@@ -1,4 +1,4 @@
-use alkanes_runtime::{declare_alkane, message::MessageDispatch, runtime::AlkaneResponder};
+use alkanes_runtime::{declare_alkane, host, message::MessageDispatch, runtime::AlkaneResponder};
 use alkanes_support::{
     cellpack::Cellpack,
     parcel::{AlkaneTransfer, AlkaneTransferParcel},
@@ -13,6 +13,9 @@ use {
     std::fmt::Write,
 };
 
+// topic attached to every event this alkane emits
+const EVENT_TOPIC: u128 = 0x7e57;
+
 #[derive(Default)]
 pub struct LoggerAlkane(());
 
@@ -43,12 +46,30 @@ enum LoggerAlkaneMessage {
     #[opcode(20)]
     TestInfiniteLoop,
 
+    #[opcode(30)]
+    EmitEvent,
+
+    #[opcode(31)]
+    EmitEventAndRevert,
+
     #[opcode(50)]
     GetTransaction,
 
     #[opcode(78)]
     HashLoop,
 
+    #[opcode(79)]
+    #[returns(Vec<u8>)]
+    HashPrecompiles,
+
+    #[opcode(80)]
+    #[returns(Vec<u8>)]
+    VerifySchnorr {
+        pubkey: Vec<u128>,
+        message: Vec<u128>,
+        signature: Vec<u128>,
+    },
+
     #[opcode(99)]
     #[returns(Vec<u8>)]
     ReturnDefaultData,
@@ -105,6 +126,17 @@ impl LoggerAlkane {
         Ok(response)
     }
 
+    fn emit_event(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.emit(&[EVENT_TOPIC, context.myself.tx], &[0x01, 0x02, 0x03]);
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn emit_event_and_revert(&self) -> Result<CallResponse> {
+        self.emit(&[EVENT_TOPIC], &[0xff]);
+        Err(anyhow!("reverting after emitting an event"))
+    }
+
     fn get_transaction(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -132,6 +164,42 @@ impl LoggerAlkane {
         Ok(response)
     }
 
+    // the digests of b"alkanes" from each hashing precompile, concatenated
+    fn hash_precompiles(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let data = b"alkanes";
+        response.data = [
+            host::sha256(data).to_vec(),
+            host::keccak256(data).to_vec(),
+            host::ripemd160(data).to_vec(),
+            host::hash160(data).to_vec(),
+        ]
+        .concat();
+
+        Ok(response)
+    }
+
+    // arguments arrive as little-endian u128 words
+    fn verify_schnorr(
+        &self,
+        pubkey: Vec<u128>,
+        message: Vec<u128>,
+        signature: Vec<u128>,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let to_bytes =
+            |words: Vec<u128>| -> Vec<u8> { words.iter().flat_map(|v| v.to_le_bytes()).collect() };
+        let verified =
+            host::schnorr_verify(&to_bytes(pubkey), &to_bytes(message), &to_bytes(signature));
+        response.data = vec![verified as u8];
+
+        Ok(response)
+    }
+
     fn test_infinite_loop(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -0,0 +1,310 @@
+use alkanes_runtime::runtime::AlkaneResponder;
+use alkanes_runtime::storage::StoragePointer;
+use alkanes_runtime::{auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch};
+use alkanes_std_factory_support::MintableToken;
+#[allow(unused_imports)]
+use alkanes_runtime::{
+    println,
+    stdio::{stdout, Write},
+};
+use alkanes_support::{
+    id::AlkaneId, parcel::AlkaneTransfer, response::CallResponse, utils::overflow_error,
+};
+use anyhow::{anyhow, Result};
+use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+// Locks the alkane sent to Initialize and releases it to the beneficiary
+// over a schedule measured in block heights: nothing before the cliff, then
+// linearly from start to end. Setting cliff and end to the same height makes
+// a plain timelock. The beneficiary is whoever holds the claim token minted
+// at initialization, and the holder of the admin auth token can revoke a
+// revocable grant, taking back whatever has not vested yet.
+
+#[derive(Default)]
+pub struct Vesting(());
+
+impl AuthenticatedResponder for Vesting {}
+
+impl MintableToken for Vesting {}
+
+#[derive(MessageDispatch)]
+enum VestingMessage {
+    #[opcode(0)]
+    Initialize {
+        start_height: u128,
+        cliff_height: u128,
+        end_height: u128,
+        revocable: u128,
+    },
+
+    #[opcode(1)]
+    Claim,
+
+    #[opcode(2)]
+    Revoke,
+
+    #[opcode(100)]
+    #[returns(u128)]
+    GetVested,
+
+    #[opcode(101)]
+    #[returns(u128)]
+    GetClaimed,
+
+    #[opcode(102)]
+    #[returns(u128)]
+    GetRemaining,
+
+    #[opcode(103)]
+    #[returns(u128)]
+    GetClaimable,
+
+    #[opcode(104)]
+    #[returns(Vec<u8>)]
+    GetSchedule,
+}
+
+impl Vesting {
+    fn token_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/token")
+    }
+
+    fn token(&self) -> Result<AlkaneId> {
+        Ok(self.token_pointer().get().as_ref().clone().try_into()?)
+    }
+
+    fn claim_token_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/claim-token")
+    }
+
+    fn total_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/total")
+    }
+
+    fn claimed_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/claimed")
+    }
+
+    fn start_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/start-height")
+    }
+
+    fn cliff_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/cliff-height")
+    }
+
+    fn end_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/end-height")
+    }
+
+    fn revocable_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/revocable")
+    }
+
+    // the height vesting stopped at, empty while the grant stands
+    fn revoked_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/revoked")
+    }
+
+    // the amount vested by `height`, never past the height of a revocation
+    fn vested_at(&self, height: u64) -> Result<u128> {
+        let revoked = self.revoked_pointer();
+        let height = if revoked.get().len() == 0 {
+            height
+        } else {
+            std::cmp::min(height, revoked.get_value::<u64>())
+        };
+        let total = self.total_pointer().get_value::<u128>();
+        let start = self.start_height_pointer().get_value::<u64>();
+        let end = self.end_height_pointer().get_value::<u64>();
+        if height < self.cliff_height_pointer().get_value::<u64>() || height < start {
+            Ok(0)
+        } else if height >= end {
+            Ok(total)
+        } else {
+            Ok(overflow_error(total.checked_mul((height - start) as u128))?
+                / ((end - start) as u128))
+        }
+    }
+
+    fn vested(&self) -> Result<u128> {
+        self.vested_at(self.height())
+    }
+
+    fn claimable(&self) -> Result<u128> {
+        Ok(self.vested()? - self.claimed_pointer().get_value::<u128>())
+    }
+
+    // what the grant can still pay out, claimable now or later
+    fn remaining(&self) -> Result<u128> {
+        let ceiling = if self.revoked_pointer().get().len() == 0 {
+            self.total_pointer().get_value::<u128>()
+        } else {
+            self.vested()?
+        };
+        Ok(ceiling - self.claimed_pointer().get_value::<u128>())
+    }
+
+    fn initialize(
+        &self,
+        start_height: u128,
+        cliff_height: u128,
+        end_height: u128,
+        revocable: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::default();
+
+        self.observe_initialization()?;
+        let deposit = match context.incoming_alkanes.0.as_slice() {
+            [deposit] if deposit.value != 0 => *deposit,
+            _ => return Err(anyhow!("send exactly one alkane to vest")),
+        };
+        if cliff_height < start_height || end_height < cliff_height {
+            return Err(anyhow!(
+                "schedule must run start <= cliff <= end, got {} {} {}",
+                start_height,
+                cliff_height,
+                end_height
+            ));
+        }
+        self.token_pointer().set(Arc::new(deposit.id.into()));
+        self.total_pointer().set_value::<u128>(deposit.value);
+        self.start_height_pointer()
+            .set_value::<u64>(start_height.try_into()?);
+        self.cliff_height_pointer()
+            .set_value::<u64>(cliff_height.try_into()?);
+        self.end_height_pointer()
+            .set_value::<u64>(end_height.try_into()?);
+        self.revocable_pointer()
+            .set_value::<u8>((revocable != 0) as u8);
+        response.alkanes.pay(self.deploy_auth_token(1)?);
+        // the beneficiary's claim token, recorded apart from the admin's
+        response
+            .alkanes
+            .pay(self.deploy_auth_token_at(1, &mut self.claim_token_pointer())?);
+
+        Ok(response)
+    }
+
+    fn claim(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner_at(&self.claim_token_pointer())?;
+        let value = self.claimable()?;
+        if value == 0 {
+            return Err(anyhow!("nothing has vested since the last claim"));
+        }
+        let mut claimed = self.claimed_pointer();
+        claimed.set_value::<u128>(claimed.get_value::<u128>() + value);
+        response.alkanes.pay(AlkaneTransfer {
+            id: self.token()?,
+            value,
+        });
+
+        Ok(response)
+    }
+
+    fn revoke(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        if self.revocable_pointer().get_value::<u8>() == 0 {
+            return Err(anyhow!("grant is not revocable"));
+        }
+        let mut revoked = self.revoked_pointer();
+        if revoked.get().len() != 0 {
+            return Err(anyhow!("grant was already revoked"));
+        }
+        let unvested = self.total_pointer().get_value::<u128>() - self.vested()?;
+        revoked.set_value::<u64>(self.height());
+        if unvested != 0 {
+            response.alkanes.pay(AlkaneTransfer {
+                id: self.token()?,
+                value: unvested,
+            });
+        }
+
+        Ok(response)
+    }
+
+    fn get_vested(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.vested()?.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    fn get_claimed(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .claimed_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    fn get_remaining(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.remaining()?.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    fn get_claimable(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.claimable()?.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    // token id, total, start, cliff and end heights, then the revocation
+    // height or 0, each as a little-endian u128
+    fn get_schedule(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let token: Vec<u128> = self.token()?.into();
+        response.data = token
+            .into_iter()
+            .chain([
+                self.total_pointer().get_value::<u128>(),
+                self.start_height_pointer().get_value::<u64>() as u128,
+                self.cliff_height_pointer().get_value::<u64>() as u128,
+                self.end_height_pointer().get_value::<u64>() as u128,
+                self.revoked_pointer().get_value::<u64>() as u128,
+            ])
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+
+        Ok(response)
+    }
+}
+
+impl AlkaneResponder for Vesting {
+    fn execute(&self) -> Result<CallResponse> {
+        Err(anyhow!(
+            "This method should not be called directly. Use the declare_alkane macro instead."
+        ))
+    }
+}
+
+declare_alkane! {
+    impl AlkaneResponder for Vesting {
+        type Message = VestingMessage;
+    }
+}
@@ -0,0 +1,63 @@
+use bitcoin::hashes::{hash160, ripemd160, sha256, Hash};
+use bitcoin::secp256k1::{ecdsa, schnorr, Message, PublicKey, Secp256k1, XOnlyPublicKey};
+use tiny_keccak::{Hasher, Keccak};
+
+// Native implementations behind the crypto host functions. The indexer and
+// the runtime's mock host both call these, so a contract sees the same result
+// under test as it does on chain. Malformed keys or signatures fail
+// verification rather than erroring.
+
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    sha256::Hash::hash(data).to_byte_array()
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+    ripemd160::Hash::hash(data).to_byte_array()
+}
+
+// ripemd160(sha256(data)), as used for bitcoin public key hashes
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    hash160::Hash::hash(data).to_byte_array()
+}
+
+// BIP340 verification of a 64-byte signature over a 32-byte message with a
+// 32-byte x-only public key
+pub fn schnorr_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Ok(pubkey), Ok(message), Ok(signature)) = (
+        XOnlyPublicKey::from_slice(pubkey),
+        Message::from_digest_slice(message),
+        schnorr::Signature::from_slice(signature),
+    ) else {
+        return false;
+    };
+    Secp256k1::verification_only()
+        .verify_schnorr(&signature, &message, &pubkey)
+        .is_ok()
+}
+
+// secp256k1 ECDSA verification of a 32-byte digest. The public key may be
+// compressed or uncompressed and the signature DER or compact. DER is tried
+// first, since a DER signature with short r and s can itself be 64 bytes long.
+// High-S signatures are rejected, as bitcoin's standardness rules do
+pub fn ecdsa_verify(pubkey: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+    let signature = ecdsa::Signature::from_der(signature)
+        .or_else(|_| ecdsa::Signature::from_compact(signature));
+    let (Ok(pubkey), Ok(digest), Ok(signature)) = (
+        PublicKey::from_slice(pubkey),
+        Message::from_digest_slice(digest),
+        signature,
+    ) else {
+        return false;
+    };
+    Secp256k1::verification_only()
+        .verify_ecdsa(&digest, &signature, &pubkey)
+        .is_ok()
+}
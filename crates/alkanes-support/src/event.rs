@@ -0,0 +1,86 @@
+use crate::id::AlkaneId;
+use crate::proto;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::{OutPoint, Txid};
+use protobuf::MessageField;
+
+// an event may carry at most this many indexed topics
+pub const MAX_EVENT_TOPICS: usize = 4;
+
+// an event emitted by an alkane through __emit_event, along with where it was
+// emitted from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlkaneEvent {
+    pub alkane: AlkaneId,
+    pub topics: Vec<u128>,
+    pub data: Vec<u8>,
+    pub height: u64,
+    pub outpoint: OutPoint,
+}
+
+impl AlkaneEvent {
+    pub fn has_topic(&self, topic: u128) -> bool {
+        self.topics.contains(&topic)
+    }
+}
+
+// topics cross the host boundary as packed little-endian u128 values
+pub fn encode_topics(topics: &[u128]) -> Vec<u8> {
+    topics.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+pub fn decode_topics(v: &[u8]) -> Result<Vec<u128>> {
+    if v.len() % 16 != 0 {
+        return Err(anyhow!("event topics must be a multiple of 16 bytes"));
+    }
+    let topics = v
+        .chunks(16)
+        .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+        .collect::<Vec<u128>>();
+    if topics.len() > MAX_EVENT_TOPICS {
+        return Err(anyhow!(
+            "events carry at most {} topics, got {}",
+            MAX_EVENT_TOPICS,
+            topics.len()
+        ));
+    }
+    Ok(topics)
+}
+
+impl Into<proto::alkanes::AlkaneEvent> for AlkaneEvent {
+    fn into(self) -> proto::alkanes::AlkaneEvent {
+        let mut result = proto::alkanes::AlkaneEvent::new();
+        result.alkane = MessageField::some(self.alkane.into());
+        result.topics = self.topics.into_iter().map(|v| v.into()).collect();
+        result.data = self.data;
+        result.height = self.height;
+        let mut outpoint = proto::alkanes::Outpoint::new();
+        outpoint.txid = self.outpoint.txid.as_byte_array().to_vec();
+        outpoint.vout = self.outpoint.vout;
+        result.outpoint = MessageField::some(outpoint);
+        result
+    }
+}
+
+impl TryFrom<proto::alkanes::AlkaneEvent> for AlkaneEvent {
+    type Error = anyhow::Error;
+    fn try_from(v: proto::alkanes::AlkaneEvent) -> Result<AlkaneEvent> {
+        let outpoint = v.outpoint.into_option().unwrap_or_default();
+        Ok(AlkaneEvent {
+            alkane: v.alkane.into_option().map(|v| v.into()).unwrap_or_default(),
+            topics: v.topics.into_iter().map(|v| v.into()).collect(),
+            data: v.data,
+            height: v.height,
+            outpoint: OutPoint {
+                txid: Txid::from_slice(&outpoint.txid)?,
+                vout: outpoint.vout,
+            },
+        })
+    }
+}
+
+// the topic an EventsRequest filters on, if any
+pub fn requested_topic(v: &proto::alkanes::EventsRequest) -> Option<u128> {
+    v.topic.clone().into_option().map(|v| v.into())
+}
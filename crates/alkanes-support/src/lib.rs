@@ -1,7 +1,9 @@
 pub mod cellpack;
 pub mod constants;
 pub mod context;
+pub mod crypto;
 pub mod envelope;
+pub mod event;
 pub mod gz;
 pub mod id;
 pub mod parcel;
@@ -593,6 +593,8 @@ pub struct MessageContextParcel {
     pub pointer: u32,
     // @@protoc_insertion_point(field:alkanes.MessageContextParcel.refund_pointer)
     pub refund_pointer: u32,
+    // @@protoc_insertion_point(field:alkanes.MessageContextParcel.overrides)
+    pub overrides: ::std::vec::Vec<AlkaneOverride>,
     // special fields
     // @@protoc_insertion_point(special_field:alkanes.MessageContextParcel.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -610,7 +612,7 @@ impl MessageContextParcel {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(10);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "alkanes",
@@ -657,6 +659,11 @@ impl MessageContextParcel {
             |m: &MessageContextParcel| { &m.refund_pointer },
             |m: &mut MessageContextParcel| { &mut m.refund_pointer },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "overrides",
+            |m: &MessageContextParcel| { &m.overrides },
+            |m: &mut MessageContextParcel| { &mut m.overrides },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MessageContextParcel>(
             "MessageContextParcel",
             fields,
@@ -702,6 +709,9 @@ impl ::protobuf::Message for MessageContextParcel {
                 72 => {
                     self.refund_pointer = is.read_uint32()?;
                 },
+                82 => {
+                    self.overrides.push(is.read_message()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -742,6 +752,10 @@ impl ::protobuf::Message for MessageContextParcel {
         if self.refund_pointer != 0 {
             my_size += ::protobuf::rt::uint32_size(9, self.refund_pointer);
         }
+        for value in &self.overrides {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -775,6 +789,9 @@ impl ::protobuf::Message for MessageContextParcel {
         if self.refund_pointer != 0 {
             os.write_uint32(9, self.refund_pointer)?;
         }
+        for v in &self.overrides {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -801,6 +818,7 @@ impl ::protobuf::Message for MessageContextParcel {
         self.vout = 0;
         self.pointer = 0;
         self.refund_pointer = 0;
+        self.overrides.clear();
         self.special_fields.clear();
     }
 
@@ -815,6 +833,7 @@ impl ::protobuf::Message for MessageContextParcel {
             vout: 0,
             pointer: 0,
             refund_pointer: 0,
+            overrides: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -838,6 +857,185 @@ impl ::protobuf::reflect::ProtobufValue for MessageContextParcel {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:alkanes.AlkaneOverride)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneOverride {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneOverride.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneOverride.storage)
+    pub storage: ::std::vec::Vec<KeyValuePair>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneOverride.bytecode)
+    pub bytecode: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneOverride.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneOverride.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneOverride {
+    fn default() -> &'a AlkaneOverride {
+        <AlkaneOverride as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneOverride {
+    pub fn new() -> AlkaneOverride {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneOverride| { &m.id },
+            |m: &mut AlkaneOverride| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "storage",
+            |m: &AlkaneOverride| { &m.storage },
+            |m: &mut AlkaneOverride| { &mut m.storage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytecode",
+            |m: &AlkaneOverride| { &m.bytecode },
+            |m: &mut AlkaneOverride| { &mut m.bytecode },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &AlkaneOverride| { &m.balances },
+            |m: &mut AlkaneOverride| { &mut m.balances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneOverride>(
+            "AlkaneOverride",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneOverride {
+    const NAME: &'static str = "AlkaneOverride";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    self.storage.push(is.read_message()?);
+                },
+                26 => {
+                    self.bytecode = is.read_bytes()?;
+                },
+                34 => {
+                    self.balances.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.storage {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.bytecode.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.bytecode);
+        }
+        for value in &self.balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.storage {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if !self.bytecode.is_empty() {
+            os.write_bytes(3, &self.bytecode)?;
+        }
+        for v in &self.balances {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneOverride {
+        AlkaneOverride::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.storage.clear();
+        self.bytecode.clear();
+        self.balances.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneOverride {
+        static instance: AlkaneOverride = AlkaneOverride {
+            id: ::protobuf::MessageField::none(),
+            storage: ::std::vec::Vec::new(),
+            bytecode: ::std::vec::Vec::new(),
+            balances: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneOverride {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneOverride").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneOverride {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneOverride {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:alkanes.KeyValuePair)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct KeyValuePair {
@@ -1618,53 +1816,60 @@ impl ::protobuf::reflect::ProtobufValue for AlkanesEnterContext {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesExitContext)
+// @@protoc_insertion_point(message:alkanes.StorageSlotDiff)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesExitContext {
+pub struct StorageSlotDiff {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.status)
-    pub status: ::protobuf::EnumOrUnknown<AlkanesTraceStatusFlag>,
-    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.response)
-    pub response: ::protobuf::MessageField<ExtendedCallResponse>,
+    // @@protoc_insertion_point(field:alkanes.StorageSlotDiff.key)
+    pub key: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageSlotDiff.old_value)
+    pub old_value: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageSlotDiff.new_value)
+    pub new_value: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesExitContext.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.StorageSlotDiff.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesExitContext {
-    fn default() -> &'a AlkanesExitContext {
-        <AlkanesExitContext as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StorageSlotDiff {
+    fn default() -> &'a StorageSlotDiff {
+        <StorageSlotDiff as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesExitContext {
-    pub fn new() -> AlkanesExitContext {
+impl StorageSlotDiff {
+    pub fn new() -> StorageSlotDiff {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "status",
-            |m: &AlkanesExitContext| { &m.status },
-            |m: &mut AlkanesExitContext| { &mut m.status },
+            "key",
+            |m: &StorageSlotDiff| { &m.key },
+            |m: &mut StorageSlotDiff| { &mut m.key },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
-            "response",
-            |m: &AlkanesExitContext| { &m.response },
-            |m: &mut AlkanesExitContext| { &mut m.response },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "old_value",
+            |m: &StorageSlotDiff| { &m.old_value },
+            |m: &mut StorageSlotDiff| { &mut m.old_value },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesExitContext>(
-            "AlkanesExitContext",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_value",
+            |m: &StorageSlotDiff| { &m.new_value },
+            |m: &mut StorageSlotDiff| { &mut m.new_value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StorageSlotDiff>(
+            "StorageSlotDiff",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesExitContext {
-    const NAME: &'static str = "AlkanesExitContext";
+impl ::protobuf::Message for StorageSlotDiff {
+    const NAME: &'static str = "StorageSlotDiff";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1673,11 +1878,14 @@ impl ::protobuf::Message for AlkanesExitContext {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.status = is.read_enum_or_unknown()?;
+                10 => {
+                    self.key = is.read_bytes()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.response)?;
+                    self.old_value = is.read_bytes()?;
+                },
+                26 => {
+                    self.new_value = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1691,12 +1899,14 @@ impl ::protobuf::Message for AlkanesExitContext {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
-            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
-        if let Some(v) = self.response.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.old_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.old_value);
+        }
+        if !self.new_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.new_value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1704,11 +1914,14 @@ impl ::protobuf::Message for AlkanesExitContext {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
-            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
         }
-        if let Some(v) = self.response.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if !self.old_value.is_empty() {
+            os.write_bytes(2, &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            os.write_bytes(3, &self.new_value)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1722,83 +1935,99 @@ impl ::protobuf::Message for AlkanesExitContext {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesExitContext {
-        AlkanesExitContext::new()
+    fn new() -> StorageSlotDiff {
+        StorageSlotDiff::new()
     }
 
     fn clear(&mut self) {
-        self.status = ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS);
-        self.response.clear();
+        self.key.clear();
+        self.old_value.clear();
+        self.new_value.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesExitContext {
-        static instance: AlkanesExitContext = AlkanesExitContext {
-            status: ::protobuf::EnumOrUnknown::from_i32(0),
-            response: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static StorageSlotDiff {
+        static instance: StorageSlotDiff = StorageSlotDiff {
+            key: ::std::vec::Vec::new(),
+            old_value: ::std::vec::Vec::new(),
+            new_value: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesExitContext {
+impl ::protobuf::MessageFull for StorageSlotDiff {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesExitContext").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StorageSlotDiff").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesExitContext {
+impl ::std::fmt::Display for StorageSlotDiff {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesExitContext {
+impl ::protobuf::reflect::ProtobufValue for StorageSlotDiff {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesCreate)
+// @@protoc_insertion_point(message:alkanes.AlkanesExitContext)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesCreate {
+pub struct AlkanesExitContext {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesCreate.new_alkane)
-    pub new_alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.status)
+    pub status: ::protobuf::EnumOrUnknown<AlkanesTraceStatusFlag>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.response)
+    pub response: ::protobuf::MessageField<ExtendedCallResponse>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.storage_diff)
+    pub storage_diff: ::std::vec::Vec<StorageSlotDiff>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesCreate.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesExitContext.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesCreate {
-    fn default() -> &'a AlkanesCreate {
-        <AlkanesCreate as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AlkanesExitContext {
+    fn default() -> &'a AlkanesExitContext {
+        <AlkanesExitContext as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesCreate {
-    pub fn new() -> AlkanesCreate {
+impl AlkanesExitContext {
+    pub fn new() -> AlkanesExitContext {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "new_alkane",
-            |m: &AlkanesCreate| { &m.new_alkane },
-            |m: &mut AlkanesCreate| { &mut m.new_alkane },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &AlkanesExitContext| { &m.status },
+            |m: &mut AlkanesExitContext| { &mut m.status },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesCreate>(
-            "AlkanesCreate",
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
+            "response",
+            |m: &AlkanesExitContext| { &m.response },
+            |m: &mut AlkanesExitContext| { &mut m.response },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "storage_diff",
+            |m: &AlkanesExitContext| { &m.storage_diff },
+            |m: &mut AlkanesExitContext| { &mut m.storage_diff },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesExitContext>(
+            "AlkanesExitContext",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesCreate {
-    const NAME: &'static str = "AlkanesCreate";
+impl ::protobuf::Message for AlkanesExitContext {
+    const NAME: &'static str = "AlkanesExitContext";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1807,11 +2036,157 @@ impl ::protobuf::Message for AlkanesCreate {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.new_alkane)?;
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
                 },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.response)?;
+                },
+                26 => {
+                    self.storage_diff.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if let Some(v) = self.response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.storage_diff {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if let Some(v) = self.response.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.storage_diff {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesExitContext {
+        AlkanesExitContext::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS);
+        self.response.clear();
+        self.storage_diff.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesExitContext {
+        static instance: AlkanesExitContext = AlkanesExitContext {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            response: ::protobuf::MessageField::none(),
+            storage_diff: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesExitContext {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesExitContext").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesExitContext {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesExitContext {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesCreate)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesCreate {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesCreate.new_alkane)
+    pub new_alkane: ::protobuf::MessageField<AlkaneId>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesCreate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesCreate {
+    fn default() -> &'a AlkanesCreate {
+        <AlkanesCreate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesCreate {
+    pub fn new() -> AlkanesCreate {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "new_alkane",
+            |m: &AlkanesCreate| { &m.new_alkane },
+            |m: &mut AlkanesCreate| { &mut m.new_alkane },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesCreate>(
+            "AlkanesCreate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesCreate {
+    const NAME: &'static str = "AlkanesCreate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.new_alkane)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
             };
         }
@@ -2936,46 +3311,53 @@ impl ::protobuf::reflect::ProtobufValue for MultiSimulateResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneInventoryRequest)
+// @@protoc_insertion_point(message:alkanes.EstimateFuelRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneInventoryRequest {
+pub struct EstimateFuelRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.id)
-    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.parcel)
+    pub parcel: ::protobuf::MessageField<MessageContextParcel>,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.block_size)
+    pub block_size: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.EstimateFuelRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkaneInventoryRequest {
-    fn default() -> &'a AlkaneInventoryRequest {
-        <AlkaneInventoryRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a EstimateFuelRequest {
+    fn default() -> &'a EstimateFuelRequest {
+        <EstimateFuelRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneInventoryRequest {
-    pub fn new() -> AlkaneInventoryRequest {
+impl EstimateFuelRequest {
+    pub fn new() -> EstimateFuelRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "id",
-            |m: &AlkaneInventoryRequest| { &m.id },
-            |m: &mut AlkaneInventoryRequest| { &mut m.id },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MessageContextParcel>(
+            "parcel",
+            |m: &EstimateFuelRequest| { &m.parcel },
+            |m: &mut EstimateFuelRequest| { &mut m.parcel },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryRequest>(
-            "AlkaneInventoryRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "block_size",
+            |m: &EstimateFuelRequest| { &m.block_size },
+            |m: &mut EstimateFuelRequest| { &mut m.block_size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EstimateFuelRequest>(
+            "EstimateFuelRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneInventoryRequest {
-    const NAME: &'static str = "AlkaneInventoryRequest";
+impl ::protobuf::Message for EstimateFuelRequest {
+    const NAME: &'static str = "EstimateFuelRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2985,7 +3367,10 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.parcel)?;
+                },
+                16 => {
+                    self.block_size = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2999,19 +3384,25 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.id.as_ref() {
+        if let Some(v) = self.parcel.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.block_size != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.block_size);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.id.as_ref() {
+        if let Some(v) = self.parcel.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
+        if self.block_size != 0 {
+            os.write_uint64(2, self.block_size)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3024,81 +3415,125 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
         &mut self.special_fields
     }
 
-    fn new() -> AlkaneInventoryRequest {
-        AlkaneInventoryRequest::new()
+    fn new() -> EstimateFuelRequest {
+        EstimateFuelRequest::new()
     }
 
     fn clear(&mut self) {
-        self.id.clear();
+        self.parcel.clear();
+        self.block_size = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkaneInventoryRequest {
-        static instance: AlkaneInventoryRequest = AlkaneInventoryRequest {
-            id: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static EstimateFuelRequest {
+        static instance: EstimateFuelRequest = EstimateFuelRequest {
+            parcel: ::protobuf::MessageField::none(),
+            block_size: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkaneInventoryRequest {
+impl ::protobuf::MessageFull for EstimateFuelRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EstimateFuelRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkaneInventoryRequest {
+impl ::std::fmt::Display for EstimateFuelRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryRequest {
+impl ::protobuf::reflect::ProtobufValue for EstimateFuelRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneInventoryResponse)
+// @@protoc_insertion_point(message:alkanes.EstimateFuelResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneInventoryResponse {
+pub struct EstimateFuelResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryResponse.alkanes)
-    pub alkanes: ::std::vec::Vec<AlkaneTransfer>,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.gas_used)
+    pub gas_used: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.vsize)
+    pub vsize: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.fuel)
+    pub fuel: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.success)
+    pub success: bool,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.required_vsize)
+    pub required_vsize: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.padding)
+    pub padding: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.EstimateFuelResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkaneInventoryResponse {
-    fn default() -> &'a AlkaneInventoryResponse {
-        <AlkaneInventoryResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a EstimateFuelResponse {
+    fn default() -> &'a EstimateFuelResponse {
+        <EstimateFuelResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneInventoryResponse {
-    pub fn new() -> AlkaneInventoryResponse {
+impl EstimateFuelResponse {
+    pub fn new() -> EstimateFuelResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "alkanes",
-            |m: &AlkaneInventoryResponse| { &m.alkanes },
-            |m: &mut AlkaneInventoryResponse| { &mut m.alkanes },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_used",
+            |m: &EstimateFuelResponse| { &m.gas_used },
+            |m: &mut EstimateFuelResponse| { &mut m.gas_used },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryResponse>(
-            "AlkaneInventoryResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vsize",
+            |m: &EstimateFuelResponse| { &m.vsize },
+            |m: &mut EstimateFuelResponse| { &mut m.vsize },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "fuel",
+            |m: &EstimateFuelResponse| { &m.fuel },
+            |m: &mut EstimateFuelResponse| { &mut m.fuel },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &EstimateFuelResponse| { &m.success },
+            |m: &mut EstimateFuelResponse| { &mut m.success },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &EstimateFuelResponse| { &m.error },
+            |m: &mut EstimateFuelResponse| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "required_vsize",
+            |m: &EstimateFuelResponse| { &m.required_vsize },
+            |m: &mut EstimateFuelResponse| { &mut m.required_vsize },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "padding",
+            |m: &EstimateFuelResponse| { &m.padding },
+            |m: &mut EstimateFuelResponse| { &mut m.padding },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EstimateFuelResponse>(
+            "EstimateFuelResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneInventoryResponse {
-    const NAME: &'static str = "AlkaneInventoryResponse";
+impl ::protobuf::Message for EstimateFuelResponse {
+    const NAME: &'static str = "EstimateFuelResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3107,8 +3542,26 @@ impl ::protobuf::Message for AlkaneInventoryResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.alkanes.push(is.read_message()?);
+                8 => {
+                    self.gas_used = is.read_uint64()?;
+                },
+                16 => {
+                    self.vsize = is.read_uint64()?;
+                },
+                24 => {
+                    self.fuel = is.read_uint64()?;
+                },
+                32 => {
+                    self.success = is.read_bool()?;
+                },
+                42 => {
+                    self.error = is.read_string()?;
+                },
+                48 => {
+                    self.required_vsize = is.read_uint64()?;
+                },
+                56 => {
+                    self.padding = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3122,22 +3575,315 @@ impl ::protobuf::Message for AlkaneInventoryResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.alkanes {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
+        if self.gas_used != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.gas_used);
+        }
+        if self.vsize != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.vsize);
+        }
+        if self.fuel != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.fuel);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error);
+        }
+        if self.required_vsize != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.required_vsize);
+        }
+        if self.padding != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.padding);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.alkanes {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        os.write_unknown_fields(self.special_fields.unknown_fields())?;
-        ::std::result::Result::Ok(())
-    }
+        if self.gas_used != 0 {
+            os.write_uint64(1, self.gas_used)?;
+        }
+        if self.vsize != 0 {
+            os.write_uint64(2, self.vsize)?;
+        }
+        if self.fuel != 0 {
+            os.write_uint64(3, self.fuel)?;
+        }
+        if self.success != false {
+            os.write_bool(4, self.success)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(5, &self.error)?;
+        }
+        if self.required_vsize != 0 {
+            os.write_uint64(6, self.required_vsize)?;
+        }
+        if self.padding != 0 {
+            os.write_uint64(7, self.padding)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EstimateFuelResponse {
+        EstimateFuelResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.gas_used = 0;
+        self.vsize = 0;
+        self.fuel = 0;
+        self.success = false;
+        self.error.clear();
+        self.required_vsize = 0;
+        self.padding = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EstimateFuelResponse {
+        static instance: EstimateFuelResponse = EstimateFuelResponse {
+            gas_used: 0,
+            vsize: 0,
+            fuel: 0,
+            success: false,
+            error: ::std::string::String::new(),
+            required_vsize: 0,
+            padding: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EstimateFuelResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EstimateFuelResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EstimateFuelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EstimateFuelResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneInventoryRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneInventoryRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneInventoryRequest {
+    fn default() -> &'a AlkaneInventoryRequest {
+        <AlkaneInventoryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneInventoryRequest {
+    pub fn new() -> AlkaneInventoryRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneInventoryRequest| { &m.id },
+            |m: &mut AlkaneInventoryRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryRequest>(
+            "AlkaneInventoryRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneInventoryRequest {
+    const NAME: &'static str = "AlkaneInventoryRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneInventoryRequest {
+        AlkaneInventoryRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneInventoryRequest {
+        static instance: AlkaneInventoryRequest = AlkaneInventoryRequest {
+            id: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneInventoryRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneInventoryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneInventoryResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneInventoryResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryResponse.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneInventoryResponse {
+    fn default() -> &'a AlkaneInventoryResponse {
+        <AlkaneInventoryResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneInventoryResponse {
+    pub fn new() -> AlkaneInventoryResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &AlkaneInventoryResponse| { &m.alkanes },
+            |m: &mut AlkaneInventoryResponse| { &mut m.alkanes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryResponse>(
+            "AlkaneInventoryResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneInventoryResponse {
+    const NAME: &'static str = "AlkaneInventoryResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.alkanes.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
 
     fn special_fields(&self) -> &::protobuf::SpecialFields {
         &self.special_fields
@@ -3832,46 +4578,3208 @@ impl ::protobuf::reflect::ProtobufValue for BytecodeRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.BlockRequest)
+// @@protoc_insertion_point(message:alkanes.CodeHashRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CodeHashRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.CodeHashRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.CodeHashRequest.code_hash)
+    pub code_hash: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.CodeHashRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CodeHashRequest {
+    fn default() -> &'a CodeHashRequest {
+        <CodeHashRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CodeHashRequest {
+    pub fn new() -> CodeHashRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &CodeHashRequest| { &m.id },
+            |m: &mut CodeHashRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code_hash",
+            |m: &CodeHashRequest| { &m.code_hash },
+            |m: &mut CodeHashRequest| { &mut m.code_hash },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CodeHashRequest>(
+            "CodeHashRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CodeHashRequest {
+    const NAME: &'static str = "CodeHashRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    self.code_hash = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.code_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.code_hash);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.code_hash.is_empty() {
+            os.write_bytes(2, &self.code_hash)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CodeHashRequest {
+        CodeHashRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.code_hash.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CodeHashRequest {
+        static instance: CodeHashRequest = CodeHashRequest {
+            id: ::protobuf::MessageField::none(),
+            code_hash: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CodeHashRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CodeHashRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CodeHashRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CodeHashRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.CodeHashResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CodeHashResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.CodeHashResponse.code_hash)
+    pub code_hash: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.CodeHashResponse.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneId>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.CodeHashResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CodeHashResponse {
+    fn default() -> &'a CodeHashResponse {
+        <CodeHashResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CodeHashResponse {
+    pub fn new() -> CodeHashResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code_hash",
+            |m: &CodeHashResponse| { &m.code_hash },
+            |m: &mut CodeHashResponse| { &mut m.code_hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &CodeHashResponse| { &m.alkanes },
+            |m: &mut CodeHashResponse| { &mut m.alkanes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CodeHashResponse>(
+            "CodeHashResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CodeHashResponse {
+    const NAME: &'static str = "CodeHashResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.code_hash = is.read_bytes()?;
+                },
+                18 => {
+                    self.alkanes.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.code_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.code_hash);
+        }
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.code_hash.is_empty() {
+            os.write_bytes(1, &self.code_hash)?;
+        }
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CodeHashResponse {
+        CodeHashResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.code_hash.clear();
+        self.alkanes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CodeHashResponse {
+        static instance: CodeHashResponse = CodeHashResponse {
+            code_hash: ::std::vec::Vec::new(),
+            alkanes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CodeHashResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CodeHashResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CodeHashResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CodeHashResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.BlockRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct BlockRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.BlockRequest.height)
-    pub height: u32,
+    // @@protoc_insertion_point(field:alkanes.BlockRequest.height)
+    pub height: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.BlockRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BlockRequest {
+    fn default() -> &'a BlockRequest {
+        <BlockRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlockRequest {
+    pub fn new() -> BlockRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &BlockRequest| { &m.height },
+            |m: &mut BlockRequest| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockRequest>(
+            "BlockRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BlockRequest {
+    const NAME: &'static str = "BlockRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.height = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.height);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.height != 0 {
+            os.write_uint32(1, self.height)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BlockRequest {
+        BlockRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.height = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BlockRequest {
+        static instance: BlockRequest = BlockRequest {
+            height: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BlockRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BlockRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.BlockResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BlockResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.BlockResponse.block)
+    pub block: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.BlockResponse.height)
+    pub height: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.BlockResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BlockResponse {
+    fn default() -> &'a BlockResponse {
+        <BlockResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlockResponse {
+    pub fn new() -> BlockResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "block",
+            |m: &BlockResponse| { &m.block },
+            |m: &mut BlockResponse| { &mut m.block },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &BlockResponse| { &m.height },
+            |m: &mut BlockResponse| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockResponse>(
+            "BlockResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BlockResponse {
+    const NAME: &'static str = "BlockResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.block = is.read_bytes()?;
+                },
+                16 => {
+                    self.height = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.block.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.block);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.height);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.block.is_empty() {
+            os.write_bytes(1, &self.block)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(2, self.height)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BlockResponse {
+        BlockResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.block.clear();
+        self.height = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BlockResponse {
+        static instance: BlockResponse = BlockResponse {
+            block: ::std::vec::Vec::new(),
+            height: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BlockResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BlockResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneEvent {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.topics)
+    pub topics: ::std::vec::Vec<Uint128>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.data)
+    pub data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneEvent {
+    fn default() -> &'a AlkaneEvent {
+        <AlkaneEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneEvent {
+    pub fn new() -> AlkaneEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &AlkaneEvent| { &m.alkane },
+            |m: &mut AlkaneEvent| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "topics",
+            |m: &AlkaneEvent| { &m.topics },
+            |m: &mut AlkaneEvent| { &mut m.topics },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &AlkaneEvent| { &m.data },
+            |m: &mut AlkaneEvent| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &AlkaneEvent| { &m.height },
+            |m: &mut AlkaneEvent| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &AlkaneEvent| { &m.outpoint },
+            |m: &mut AlkaneEvent| { &mut m.outpoint },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneEvent>(
+            "AlkaneEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneEvent {
+    const NAME: &'static str = "AlkaneEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                18 => {
+                    self.topics.push(is.read_message()?);
+                },
+                26 => {
+                    self.data = is.read_bytes()?;
+                },
+                32 => {
+                    self.height = is.read_uint64()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.topics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.height);
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.topics {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(4, self.height)?;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneEvent {
+        AlkaneEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.topics.clear();
+        self.data.clear();
+        self.height = 0;
+        self.outpoint.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneEvent {
+        static instance: AlkaneEvent = AlkaneEvent {
+            alkane: ::protobuf::MessageField::none(),
+            topics: ::std::vec::Vec::new(),
+            data: ::std::vec::Vec::new(),
+            height: 0,
+            outpoint: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.EventsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EventsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.topic)
+    pub topic: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.from_height)
+    pub from_height: u64,
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.to_height)
+    pub to_height: u64,
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:alkanes.EventsRequest.limit)
+    pub limit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.EventsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EventsRequest {
+    fn default() -> &'a EventsRequest {
+        <EventsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventsRequest {
+    pub fn new() -> EventsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &EventsRequest| { &m.alkane },
+            |m: &mut EventsRequest| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "topic",
+            |m: &EventsRequest| { &m.topic },
+            |m: &mut EventsRequest| { &mut m.topic },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from_height",
+            |m: &EventsRequest| { &m.from_height },
+            |m: &mut EventsRequest| { &mut m.from_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "to_height",
+            |m: &EventsRequest| { &m.to_height },
+            |m: &mut EventsRequest| { &mut m.to_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &EventsRequest| { &m.offset },
+            |m: &mut EventsRequest| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &EventsRequest| { &m.limit },
+            |m: &mut EventsRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventsRequest>(
+            "EventsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EventsRequest {
+    const NAME: &'static str = "EventsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.topic)?;
+                },
+                24 => {
+                    self.from_height = is.read_uint64()?;
+                },
+                32 => {
+                    self.to_height = is.read_uint64()?;
+                },
+                40 => {
+                    self.offset = is.read_uint64()?;
+                },
+                48 => {
+                    self.limit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.topic.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.from_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.from_height);
+        }
+        if self.to_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.to_height);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.offset);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.topic.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.from_height != 0 {
+            os.write_uint64(3, self.from_height)?;
+        }
+        if self.to_height != 0 {
+            os.write_uint64(4, self.to_height)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(5, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(6, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EventsRequest {
+        EventsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.topic.clear();
+        self.from_height = 0;
+        self.to_height = 0;
+        self.offset = 0;
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EventsRequest {
+        static instance: EventsRequest = EventsRequest {
+            alkane: ::protobuf::MessageField::none(),
+            topic: ::protobuf::MessageField::none(),
+            from_height: 0,
+            to_height: 0,
+            offset: 0,
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EventsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EventsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.TransactionEventsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TransactionEventsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.TransactionEventsRequest.txid)
+    pub txid: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.TransactionEventsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TransactionEventsRequest {
+    fn default() -> &'a TransactionEventsRequest {
+        <TransactionEventsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TransactionEventsRequest {
+    pub fn new() -> TransactionEventsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txid",
+            |m: &TransactionEventsRequest| { &m.txid },
+            |m: &mut TransactionEventsRequest| { &mut m.txid },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TransactionEventsRequest>(
+            "TransactionEventsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TransactionEventsRequest {
+    const NAME: &'static str = "TransactionEventsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.txid = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.txid.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.txid);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.txid.is_empty() {
+            os.write_bytes(1, &self.txid)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TransactionEventsRequest {
+        TransactionEventsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.txid.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TransactionEventsRequest {
+        static instance: TransactionEventsRequest = TransactionEventsRequest {
+            txid: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TransactionEventsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TransactionEventsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TransactionEventsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TransactionEventsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.EventsResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EventsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.EventsResponse.events)
+    pub events: ::std::vec::Vec<AlkaneEvent>,
+    // @@protoc_insertion_point(field:alkanes.EventsResponse.total)
+    pub total: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.EventsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EventsResponse {
+    fn default() -> &'a EventsResponse {
+        <EventsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventsResponse {
+    pub fn new() -> EventsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &EventsResponse| { &m.events },
+            |m: &mut EventsResponse| { &mut m.events },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &EventsResponse| { &m.total },
+            |m: &mut EventsResponse| { &mut m.total },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventsResponse>(
+            "EventsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EventsResponse {
+    const NAME: &'static str = "EventsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.events.push(is.read_message()?);
+                },
+                16 => {
+                    self.total = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.total);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EventsResponse {
+        EventsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.total = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EventsResponse {
+        static instance: EventsResponse = EventsResponse {
+            events: ::std::vec::Vec::new(),
+            total: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EventsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EventsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.LedgerRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LedgerRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.LedgerRequest.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.LedgerRequest.address)
+    pub address: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.LedgerRequest.holder)
+    pub holder: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.LedgerRequest.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:alkanes.LedgerRequest.limit)
+    pub limit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.LedgerRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LedgerRequest {
+    fn default() -> &'a LedgerRequest {
+        <LedgerRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LedgerRequest {
+    pub fn new() -> LedgerRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &LedgerRequest| { &m.alkane },
+            |m: &mut LedgerRequest| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &LedgerRequest| { &m.address },
+            |m: &mut LedgerRequest| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "holder",
+            |m: &LedgerRequest| { &m.holder },
+            |m: &mut LedgerRequest| { &mut m.holder },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &LedgerRequest| { &m.offset },
+            |m: &mut LedgerRequest| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &LedgerRequest| { &m.limit },
+            |m: &mut LedgerRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LedgerRequest>(
+            "LedgerRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LedgerRequest {
+    const NAME: &'static str = "LedgerRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                18 => {
+                    self.address = is.read_bytes()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.holder)?;
+                },
+                32 => {
+                    self.offset = is.read_uint64()?;
+                },
+                40 => {
+                    self.limit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.address);
+        }
+        if let Some(v) = self.holder.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.offset);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.address.is_empty() {
+            os.write_bytes(2, &self.address)?;
+        }
+        if let Some(v) = self.holder.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(4, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(5, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LedgerRequest {
+        LedgerRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.address.clear();
+        self.holder.clear();
+        self.offset = 0;
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LedgerRequest {
+        static instance: LedgerRequest = LedgerRequest {
+            alkane: ::protobuf::MessageField::none(),
+            address: ::std::vec::Vec::new(),
+            holder: ::protobuf::MessageField::none(),
+            offset: 0,
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LedgerRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LedgerRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LedgerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LedgerRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.LedgerEntry)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LedgerEntry {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.address)
+    pub address: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.holder)
+    pub holder: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.txid)
+    pub txid: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.vout)
+    pub vout: u32,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.amount)
+    pub amount: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.debit)
+    pub debit: bool,
+    // @@protoc_insertion_point(field:alkanes.LedgerEntry.height)
+    pub height: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.LedgerEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LedgerEntry {
+    fn default() -> &'a LedgerEntry {
+        <LedgerEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LedgerEntry {
+    pub fn new() -> LedgerEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &LedgerEntry| { &m.alkane },
+            |m: &mut LedgerEntry| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &LedgerEntry| { &m.address },
+            |m: &mut LedgerEntry| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "holder",
+            |m: &LedgerEntry| { &m.holder },
+            |m: &mut LedgerEntry| { &mut m.holder },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txid",
+            |m: &LedgerEntry| { &m.txid },
+            |m: &mut LedgerEntry| { &mut m.txid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vout",
+            |m: &LedgerEntry| { &m.vout },
+            |m: &mut LedgerEntry| { &mut m.vout },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "amount",
+            |m: &LedgerEntry| { &m.amount },
+            |m: &mut LedgerEntry| { &mut m.amount },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "debit",
+            |m: &LedgerEntry| { &m.debit },
+            |m: &mut LedgerEntry| { &mut m.debit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &LedgerEntry| { &m.height },
+            |m: &mut LedgerEntry| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LedgerEntry>(
+            "LedgerEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LedgerEntry {
+    const NAME: &'static str = "LedgerEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                18 => {
+                    self.address = is.read_bytes()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.holder)?;
+                },
+                34 => {
+                    self.txid = is.read_bytes()?;
+                },
+                40 => {
+                    self.vout = is.read_uint32()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.amount)?;
+                },
+                56 => {
+                    self.debit = is.read_bool()?;
+                },
+                64 => {
+                    self.height = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.address);
+        }
+        if let Some(v) = self.holder.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.txid.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.txid);
+        }
+        if self.vout != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.vout);
+        }
+        if let Some(v) = self.amount.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.debit != false {
+            my_size += 1 + 1;
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.height);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.address.is_empty() {
+            os.write_bytes(2, &self.address)?;
+        }
+        if let Some(v) = self.holder.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if !self.txid.is_empty() {
+            os.write_bytes(4, &self.txid)?;
+        }
+        if self.vout != 0 {
+            os.write_uint32(5, self.vout)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if self.debit != false {
+            os.write_bool(7, self.debit)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(8, self.height)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LedgerEntry {
+        LedgerEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.address.clear();
+        self.holder.clear();
+        self.txid.clear();
+        self.vout = 0;
+        self.amount.clear();
+        self.debit = false;
+        self.height = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LedgerEntry {
+        static instance: LedgerEntry = LedgerEntry {
+            alkane: ::protobuf::MessageField::none(),
+            address: ::std::vec::Vec::new(),
+            holder: ::protobuf::MessageField::none(),
+            txid: ::std::vec::Vec::new(),
+            vout: 0,
+            amount: ::protobuf::MessageField::none(),
+            debit: false,
+            height: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LedgerEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LedgerEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LedgerEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LedgerEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.LedgerResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LedgerResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.LedgerResponse.entries)
+    pub entries: ::std::vec::Vec<LedgerEntry>,
+    // @@protoc_insertion_point(field:alkanes.LedgerResponse.total)
+    pub total: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.LedgerResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LedgerResponse {
+    fn default() -> &'a LedgerResponse {
+        <LedgerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LedgerResponse {
+    pub fn new() -> LedgerResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &LedgerResponse| { &m.entries },
+            |m: &mut LedgerResponse| { &mut m.entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &LedgerResponse| { &m.total },
+            |m: &mut LedgerResponse| { &mut m.total },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LedgerResponse>(
+            "LedgerResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LedgerResponse {
+    const NAME: &'static str = "LedgerResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.entries.push(is.read_message()?);
+                },
+                16 => {
+                    self.total = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.total);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LedgerResponse {
+        LedgerResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.total = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LedgerResponse {
+        static instance: LedgerResponse = LedgerResponse {
+            entries: ::std::vec::Vec::new(),
+            total: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LedgerResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LedgerResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LedgerResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LedgerResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.HoldersRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HoldersRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.HoldersRequest.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.HoldersRequest.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:alkanes.HoldersRequest.limit)
+    pub limit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.HoldersRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HoldersRequest {
+    fn default() -> &'a HoldersRequest {
+        <HoldersRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HoldersRequest {
+    pub fn new() -> HoldersRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &HoldersRequest| { &m.alkane },
+            |m: &mut HoldersRequest| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &HoldersRequest| { &m.offset },
+            |m: &mut HoldersRequest| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &HoldersRequest| { &m.limit },
+            |m: &mut HoldersRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HoldersRequest>(
+            "HoldersRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HoldersRequest {
+    const NAME: &'static str = "HoldersRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                16 => {
+                    self.offset = is.read_uint64()?;
+                },
+                24 => {
+                    self.limit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.offset);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HoldersRequest {
+        HoldersRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.offset = 0;
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HoldersRequest {
+        static instance: HoldersRequest = HoldersRequest {
+            alkane: ::protobuf::MessageField::none(),
+            offset: 0,
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HoldersRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HoldersRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HoldersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HoldersRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneHolder)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneHolder {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneHolder.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneHolder.address)
+    pub address: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneHolder.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneHolder.balance)
+    pub balance: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneHolder.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneHolder {
+    fn default() -> &'a AlkaneHolder {
+        <AlkaneHolder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneHolder {
+    pub fn new() -> AlkaneHolder {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &AlkaneHolder| { &m.outpoint },
+            |m: &mut AlkaneHolder| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &AlkaneHolder| { &m.address },
+            |m: &mut AlkaneHolder| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &AlkaneHolder| { &m.alkane },
+            |m: &mut AlkaneHolder| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "balance",
+            |m: &AlkaneHolder| { &m.balance },
+            |m: &mut AlkaneHolder| { &mut m.balance },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneHolder>(
+            "AlkaneHolder",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneHolder {
+    const NAME: &'static str = "AlkaneHolder";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                18 => {
+                    self.address = is.read_bytes()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.balance)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.address);
+        }
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.address.is_empty() {
+            os.write_bytes(2, &self.address)?;
+        }
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneHolder {
+        AlkaneHolder::new()
+    }
+
+    fn clear(&mut self) {
+        self.outpoint.clear();
+        self.address.clear();
+        self.alkane.clear();
+        self.balance.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneHolder {
+        static instance: AlkaneHolder = AlkaneHolder {
+            outpoint: ::protobuf::MessageField::none(),
+            address: ::std::vec::Vec::new(),
+            alkane: ::protobuf::MessageField::none(),
+            balance: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneHolder {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneHolder").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneHolder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneHolder {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.HoldersResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HoldersResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.HoldersResponse.holders)
+    pub holders: ::std::vec::Vec<AlkaneHolder>,
+    // @@protoc_insertion_point(field:alkanes.HoldersResponse.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:alkanes.HoldersResponse.supply)
+    pub supply: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.HoldersResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HoldersResponse {
+    fn default() -> &'a HoldersResponse {
+        <HoldersResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HoldersResponse {
+    pub fn new() -> HoldersResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "holders",
+            |m: &HoldersResponse| { &m.holders },
+            |m: &mut HoldersResponse| { &mut m.holders },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &HoldersResponse| { &m.total },
+            |m: &mut HoldersResponse| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "supply",
+            |m: &HoldersResponse| { &m.supply },
+            |m: &mut HoldersResponse| { &mut m.supply },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HoldersResponse>(
+            "HoldersResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HoldersResponse {
+    const NAME: &'static str = "HoldersResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.holders.push(is.read_message()?);
+                },
+                16 => {
+                    self.total = is.read_uint64()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.supply)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.holders {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.total);
+        }
+        if let Some(v) = self.supply.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.holders {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        if let Some(v) = self.supply.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HoldersResponse {
+        HoldersResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.holders.clear();
+        self.total = 0;
+        self.supply.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HoldersResponse {
+        static instance: HoldersResponse = HoldersResponse {
+            holders: ::std::vec::Vec::new(),
+            total: 0,
+            supply: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HoldersResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HoldersResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HoldersResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HoldersResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneRecord)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneRecord {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.txid)
+    pub txid: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.kind)
+    pub kind: ::protobuf::EnumOrUnknown<AlkaneCreationKind>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.template)
+    pub template: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRecord.code_hash)
+    pub code_hash: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneRecord.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneRecord {
+    fn default() -> &'a AlkaneRecord {
+        <AlkaneRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneRecord {
+    pub fn new() -> AlkaneRecord {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneRecord| { &m.id },
+            |m: &mut AlkaneRecord| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &AlkaneRecord| { &m.height },
+            |m: &mut AlkaneRecord| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txid",
+            |m: &AlkaneRecord| { &m.txid },
+            |m: &mut AlkaneRecord| { &mut m.txid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &AlkaneRecord| { &m.kind },
+            |m: &mut AlkaneRecord| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "template",
+            |m: &AlkaneRecord| { &m.template },
+            |m: &mut AlkaneRecord| { &mut m.template },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code_hash",
+            |m: &AlkaneRecord| { &m.code_hash },
+            |m: &mut AlkaneRecord| { &mut m.code_hash },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneRecord>(
+            "AlkaneRecord",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneRecord {
+    const NAME: &'static str = "AlkaneRecord";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                16 => {
+                    self.height = is.read_uint64()?;
+                },
+                26 => {
+                    self.txid = is.read_bytes()?;
+                },
+                32 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.template)?;
+                },
+                50 => {
+                    self.code_hash = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.height);
+        }
+        if !self.txid.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.txid);
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(AlkaneCreationKind::CREATE) {
+            my_size += ::protobuf::rt::int32_size(4, self.kind.value());
+        }
+        if let Some(v) = self.template.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.code_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.code_hash);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if !self.txid.is_empty() {
+            os.write_bytes(3, &self.txid)?;
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(AlkaneCreationKind::CREATE) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if let Some(v) = self.template.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if !self.code_hash.is_empty() {
+            os.write_bytes(6, &self.code_hash)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneRecord {
+        AlkaneRecord::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.height = 0;
+        self.txid.clear();
+        self.kind = ::protobuf::EnumOrUnknown::new(AlkaneCreationKind::CREATE);
+        self.template.clear();
+        self.code_hash.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneRecord {
+        static instance: AlkaneRecord = AlkaneRecord {
+            id: ::protobuf::MessageField::none(),
+            height: 0,
+            txid: ::std::vec::Vec::new(),
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            template: ::protobuf::MessageField::none(),
+            code_hash: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneRecord {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneRecord").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneRecord {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesRequest.from_height)
+    pub from_height: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkanesRequest.to_height)
+    pub to_height: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkanesRequest.template)
+    pub template: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesRequest.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkanesRequest.limit)
+    pub limit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesRequest {
+    fn default() -> &'a AlkanesRequest {
+        <AlkanesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesRequest {
+    pub fn new() -> AlkanesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from_height",
+            |m: &AlkanesRequest| { &m.from_height },
+            |m: &mut AlkanesRequest| { &mut m.from_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "to_height",
+            |m: &AlkanesRequest| { &m.to_height },
+            |m: &mut AlkanesRequest| { &mut m.to_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "template",
+            |m: &AlkanesRequest| { &m.template },
+            |m: &mut AlkanesRequest| { &mut m.template },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &AlkanesRequest| { &m.offset },
+            |m: &mut AlkanesRequest| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &AlkanesRequest| { &m.limit },
+            |m: &mut AlkanesRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesRequest>(
+            "AlkanesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesRequest {
+    const NAME: &'static str = "AlkanesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.from_height = is.read_uint64()?;
+                },
+                16 => {
+                    self.to_height = is.read_uint64()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.template)?;
+                },
+                32 => {
+                    self.offset = is.read_uint64()?;
+                },
+                40 => {
+                    self.limit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.from_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.from_height);
+        }
+        if self.to_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.to_height);
+        }
+        if let Some(v) = self.template.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.offset);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.from_height != 0 {
+            os.write_uint64(1, self.from_height)?;
+        }
+        if self.to_height != 0 {
+            os.write_uint64(2, self.to_height)?;
+        }
+        if let Some(v) = self.template.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(4, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(5, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesRequest {
+        AlkanesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.from_height = 0;
+        self.to_height = 0;
+        self.template.clear();
+        self.offset = 0;
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesRequest {
+        static instance: AlkanesRequest = AlkanesRequest {
+            from_height: 0,
+            to_height: 0,
+            template: ::protobuf::MessageField::none(),
+            offset: 0,
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesResponse.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneRecord>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesResponse.total)
+    pub total: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesResponse {
+    fn default() -> &'a AlkanesResponse {
+        <AlkanesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesResponse {
+    pub fn new() -> AlkanesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &AlkanesResponse| { &m.alkanes },
+            |m: &mut AlkanesResponse| { &mut m.alkanes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &AlkanesResponse| { &m.total },
+            |m: &mut AlkanesResponse| { &mut m.total },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesResponse>(
+            "AlkanesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesResponse {
+    const NAME: &'static str = "AlkanesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.alkanes.push(is.read_message()?);
+                },
+                16 => {
+                    self.total = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.total);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesResponse {
+        AlkanesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkanes.clear();
+        self.total = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesResponse {
+        static instance: AlkanesResponse = AlkanesResponse {
+            alkanes: ::std::vec::Vec::new(),
+            total: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.PrevoutBalances)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PrevoutBalances {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.PrevoutBalances.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.PrevoutBalances.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.PrevoutBalances.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PrevoutBalances {
+    fn default() -> &'a PrevoutBalances {
+        <PrevoutBalances as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrevoutBalances {
+    pub fn new() -> PrevoutBalances {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &PrevoutBalances| { &m.outpoint },
+            |m: &mut PrevoutBalances| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &PrevoutBalances| { &m.balances },
+            |m: &mut PrevoutBalances| { &mut m.balances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PrevoutBalances>(
+            "PrevoutBalances",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PrevoutBalances {
+    const NAME: &'static str = "PrevoutBalances";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                18 => {
+                    self.balances.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.balances {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PrevoutBalances {
+        PrevoutBalances::new()
+    }
+
+    fn clear(&mut self) {
+        self.outpoint.clear();
+        self.balances.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PrevoutBalances {
+        static instance: PrevoutBalances = PrevoutBalances {
+            outpoint: ::protobuf::MessageField::none(),
+            balances: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PrevoutBalances {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PrevoutBalances").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PrevoutBalances {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrevoutBalances {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.SimulateTransactionRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SimulateTransactionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.transaction)
+    pub transaction: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.prevouts)
+    pub prevouts: ::std::vec::Vec<PrevoutBalances>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.height)
+    pub height: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.SimulateTransactionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SimulateTransactionRequest {
+    fn default() -> &'a SimulateTransactionRequest {
+        <SimulateTransactionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SimulateTransactionRequest {
+    pub fn new() -> SimulateTransactionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction",
+            |m: &SimulateTransactionRequest| { &m.transaction },
+            |m: &mut SimulateTransactionRequest| { &mut m.transaction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "prevouts",
+            |m: &SimulateTransactionRequest| { &m.prevouts },
+            |m: &mut SimulateTransactionRequest| { &mut m.prevouts },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &SimulateTransactionRequest| { &m.height },
+            |m: &mut SimulateTransactionRequest| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateTransactionRequest>(
+            "SimulateTransactionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SimulateTransactionRequest {
+    const NAME: &'static str = "SimulateTransactionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.transaction = is.read_bytes()?;
+                },
+                18 => {
+                    self.prevouts.push(is.read_message()?);
+                },
+                24 => {
+                    self.height = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.transaction.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.transaction);
+        }
+        for value in &self.prevouts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.height);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.transaction.is_empty() {
+            os.write_bytes(1, &self.transaction)?;
+        }
+        for v in &self.prevouts {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if self.height != 0 {
+            os.write_uint64(3, self.height)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SimulateTransactionRequest {
+        SimulateTransactionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.transaction.clear();
+        self.prevouts.clear();
+        self.height = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SimulateTransactionRequest {
+        static instance: SimulateTransactionRequest = SimulateTransactionRequest {
+            transaction: ::std::vec::Vec::new(),
+            prevouts: ::std::vec::Vec::new(),
+            height: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SimulateTransactionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateTransactionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SimulateTransactionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SimulateTransactionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.OutputBalances)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct OutputBalances {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.OutputBalances.vout)
+    pub vout: u32,
+    // @@protoc_insertion_point(field:alkanes.OutputBalances.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.BlockRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.OutputBalances.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BlockRequest {
-    fn default() -> &'a BlockRequest {
-        <BlockRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a OutputBalances {
+    fn default() -> &'a OutputBalances {
+        <OutputBalances as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BlockRequest {
-    pub fn new() -> BlockRequest {
+impl OutputBalances {
+    pub fn new() -> OutputBalances {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "height",
-            |m: &BlockRequest| { &m.height },
-            |m: &mut BlockRequest| { &mut m.height },
+            "vout",
+            |m: &OutputBalances| { &m.vout },
+            |m: &mut OutputBalances| { &mut m.vout },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockRequest>(
-            "BlockRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &OutputBalances| { &m.balances },
+            |m: &mut OutputBalances| { &mut m.balances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OutputBalances>(
+            "OutputBalances",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BlockRequest {
-    const NAME: &'static str = "BlockRequest";
+impl ::protobuf::Message for OutputBalances {
+    const NAME: &'static str = "OutputBalances";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3881,7 +7789,10 @@ impl ::protobuf::Message for BlockRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.height = is.read_uint32()?;
+                    self.vout = is.read_uint32()?;
+                },
+                18 => {
+                    self.balances.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3895,18 +7806,25 @@ impl ::protobuf::Message for BlockRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.height != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.height);
+        if self.vout != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.vout);
         }
+        for value in &self.balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.height != 0 {
-            os.write_uint32(1, self.height)?;
+        if self.vout != 0 {
+            os.write_uint32(1, self.vout)?;
         }
+        for v in &self.balances {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3919,88 +7837,97 @@ impl ::protobuf::Message for BlockRequest {
         &mut self.special_fields
     }
 
-    fn new() -> BlockRequest {
-        BlockRequest::new()
+    fn new() -> OutputBalances {
+        OutputBalances::new()
     }
 
     fn clear(&mut self) {
-        self.height = 0;
+        self.vout = 0;
+        self.balances.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BlockRequest {
-        static instance: BlockRequest = BlockRequest {
-            height: 0,
+    fn default_instance() -> &'static OutputBalances {
+        static instance: OutputBalances = OutputBalances {
+            vout: 0,
+            balances: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BlockRequest {
+impl ::protobuf::MessageFull for OutputBalances {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OutputBalances").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BlockRequest {
+impl ::std::fmt::Display for OutputBalances {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BlockRequest {
+impl ::protobuf::reflect::ProtobufValue for OutputBalances {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.BlockResponse)
+// @@protoc_insertion_point(message:alkanes.SimulateTransactionResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BlockResponse {
+pub struct SimulateTransactionResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.BlockResponse.block)
-    pub block: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:alkanes.BlockResponse.height)
-    pub height: u32,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.outputs)
+    pub outputs: ::std::vec::Vec<OutputBalances>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.traces)
+    pub traces: ::std::vec::Vec<Trace>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.errors)
+    pub errors: ::std::vec::Vec<::std::string::String>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.BlockResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.SimulateTransactionResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BlockResponse {
-    fn default() -> &'a BlockResponse {
-        <BlockResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SimulateTransactionResponse {
+    fn default() -> &'a SimulateTransactionResponse {
+        <SimulateTransactionResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BlockResponse {
-    pub fn new() -> BlockResponse {
+impl SimulateTransactionResponse {
+    pub fn new() -> SimulateTransactionResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "block",
-            |m: &BlockResponse| { &m.block },
-            |m: &mut BlockResponse| { &mut m.block },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "outputs",
+            |m: &SimulateTransactionResponse| { &m.outputs },
+            |m: &mut SimulateTransactionResponse| { &mut m.outputs },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "height",
-            |m: &BlockResponse| { &m.height },
-            |m: &mut BlockResponse| { &mut m.height },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "traces",
+            |m: &SimulateTransactionResponse| { &m.traces },
+            |m: &mut SimulateTransactionResponse| { &mut m.traces },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockResponse>(
-            "BlockResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "errors",
+            |m: &SimulateTransactionResponse| { &m.errors },
+            |m: &mut SimulateTransactionResponse| { &mut m.errors },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateTransactionResponse>(
+            "SimulateTransactionResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BlockResponse {
-    const NAME: &'static str = "BlockResponse";
+impl ::protobuf::Message for SimulateTransactionResponse {
+    const NAME: &'static str = "SimulateTransactionResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4010,10 +7937,13 @@ impl ::protobuf::Message for BlockResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.block = is.read_bytes()?;
+                    self.outputs.push(is.read_message()?);
                 },
-                16 => {
-                    self.height = is.read_uint32()?;
+                18 => {
+                    self.traces.push(is.read_message()?);
+                },
+                26 => {
+                    self.errors.push(is.read_string()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4027,24 +7957,32 @@ impl ::protobuf::Message for BlockResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.block.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.block);
-        }
-        if self.height != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.height);
-        }
+        for value in &self.outputs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.traces {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.errors {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.block.is_empty() {
-            os.write_bytes(1, &self.block)?;
-        }
-        if self.height != 0 {
-            os.write_uint32(2, self.height)?;
-        }
+        for v in &self.outputs {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        for v in &self.traces {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.errors {
+            os.write_string(3, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4057,40 +7995,42 @@ impl ::protobuf::Message for BlockResponse {
         &mut self.special_fields
     }
 
-    fn new() -> BlockResponse {
-        BlockResponse::new()
+    fn new() -> SimulateTransactionResponse {
+        SimulateTransactionResponse::new()
     }
 
     fn clear(&mut self) {
-        self.block.clear();
-        self.height = 0;
+        self.outputs.clear();
+        self.traces.clear();
+        self.errors.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BlockResponse {
-        static instance: BlockResponse = BlockResponse {
-            block: ::std::vec::Vec::new(),
-            height: 0,
+    fn default_instance() -> &'static SimulateTransactionResponse {
+        static instance: SimulateTransactionResponse = SimulateTransactionResponse {
+            outputs: ::std::vec::Vec::new(),
+            traces: ::std::vec::Vec::new(),
+            errors: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BlockResponse {
+impl ::protobuf::MessageFull for SimulateTransactionResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateTransactionResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BlockResponse {
+impl ::std::fmt::Display for SimulateTransactionResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BlockResponse {
+impl ::protobuf::reflect::ProtobufValue for SimulateTransactionResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -4228,6 +8168,73 @@ impl AlkanesTraceStatusFlag {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:alkanes.AlkaneCreationKind)
+pub enum AlkaneCreationKind {
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneCreationKind.CREATE)
+    CREATE = 0,
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneCreationKind.RESERVED)
+    RESERVED = 1,
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneCreationKind.FACTORY)
+    FACTORY = 2,
+}
+
+impl ::protobuf::Enum for AlkaneCreationKind {
+    const NAME: &'static str = "AlkaneCreationKind";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AlkaneCreationKind> {
+        match value {
+            0 => ::std::option::Option::Some(AlkaneCreationKind::CREATE),
+            1 => ::std::option::Option::Some(AlkaneCreationKind::RESERVED),
+            2 => ::std::option::Option::Some(AlkaneCreationKind::FACTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<AlkaneCreationKind> {
+        match str {
+            "CREATE" => ::std::option::Option::Some(AlkaneCreationKind::CREATE),
+            "RESERVED" => ::std::option::Option::Some(AlkaneCreationKind::RESERVED),
+            "FACTORY" => ::std::option::Option::Some(AlkaneCreationKind::FACTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [AlkaneCreationKind] = &[
+        AlkaneCreationKind::CREATE,
+        AlkaneCreationKind::RESERVED,
+        AlkaneCreationKind::FACTORY,
+    ];
+}
+
+impl ::protobuf::EnumFull for AlkaneCreationKind {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("AlkaneCreationKind").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for AlkaneCreationKind {
+    fn default() -> Self {
+        AlkaneCreationKind::CREATE
+    }
+}
+
+impl AlkaneCreationKind {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<AlkaneCreationKind>("AlkaneCreationKind")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\ralkanes.proto\x12\x07alkanes\")\n\x07uint128\x12\x0e\n\x02lo\x18\x01\
     \x20\x01(\x04R\x02lo\x12\x0e\n\x02hi\x18\x02\x20\x01(\x04R\x02hi\"T\n\
@@ -4236,7 +8243,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     tx\"[\n\x0eAlkaneTransfer\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes\
     .AlkaneIdR\x02id\x12&\n\x05value\x18\x02\x20\x01(\x0b2\x10.alkanes.uint1\
     28R\x05value\"O\n\x14MultiSimulateRequest\x127\n\x07parcels\x18\x01\x20\
-    \x03(\x0b2\x1d.alkanes.MessageContextParcelR\x07parcels\"\xa4\x02\n\x14M\
+    \x03(\x0b2\x1d.alkanes.MessageContextParcelR\x07parcels\"\xdb\x02\n\x14M\
     essageContextParcel\x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alkanes.\
     AlkaneTransferR\x07alkanes\x12\x20\n\x0btransaction\x18\x02\x20\x01(\x0c\
     R\x0btransaction\x12\x14\n\x05block\x18\x03\x20\x01(\x0cR\x05block\x12\
@@ -4244,59 +8251,143 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x06\x20\x01(\rR\x07txindex\x12\x1a\n\x08calldata\x18\x05\x20\x01(\
     \x0cR\x08calldata\x12\x12\n\x04vout\x18\x07\x20\x01(\rR\x04vout\x12\x18\
     \n\x07pointer\x18\x08\x20\x01(\rR\x07pointer\x12%\n\x0erefund_pointer\
-    \x18\t\x20\x01(\rR\rrefundPointer\"6\n\x0cKeyValuePair\x12\x10\n\x03key\
-    \x18\x01\x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\
-    \x05value\"\x8e\x01\n\x14ExtendedCallResponse\x121\n\x07alkanes\x18\x01\
-    \x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x07alkanes\x12/\n\x07storage\
-    \x18\x02\x20\x03(\x0b2\x15.alkanes.KeyValuePairR\x07storage\x12\x12\n\
-    \x04data\x18\x03\x20\x01(\x0cR\x04data\"\xe1\x01\n\x07Context\x12)\n\x06\
-    myself\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06myself\x12)\n\x06c\
-    aller\x18\x02\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06caller\x12(\n\x06in\
-    puts\x18\x03\x20\x03(\x0b2\x10.alkanes.uint128R\x06inputs\x12\x12\n\x04v\
-    out\x18\x04\x20\x01(\rR\x04vout\x12B\n\x10incoming_alkanes\x18\x05\x20\
-    \x03(\x0b2\x17.alkanes.AlkaneTransferR\x0fincomingAlkanes\"J\n\x0cTraceC\
-    ontext\x12&\n\x05inner\x18\x01\x20\x01(\x0b2\x10.alkanes.ContextR\x05inn\
-    er\x12\x12\n\x04fuel\x18\x02\x20\x01(\x04R\x04fuel\"\x82\x01\n\x13Alkane\
-    sEnterContext\x12:\n\tcall_type\x18\x01\x20\x01(\x0e2\x1d.alkanes.Alkane\
-    sTraceCallTypeR\x08callType\x12/\n\x07context\x18\x02\x20\x01(\x0b2\x15.\
-    alkanes.TraceContextR\x07context\"\x88\x01\n\x12AlkanesExitContext\x127\
-    \n\x06status\x18\x01\x20\x01(\x0e2\x1f.alkanes.AlkanesTraceStatusFlagR\
+    \x18\t\x20\x01(\rR\rrefundPointer\x125\n\toverrides\x18\n\x20\x03(\x0b2\
+    \x17.alkanes.AlkaneOverrideR\toverrides\"\xb5\x01\n\x0eAlkaneOverride\
+    \x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\x12/\n\
+    \x07storage\x18\x02\x20\x03(\x0b2\x15.alkanes.KeyValuePairR\x07storage\
+    \x12\x1a\n\x08bytecode\x18\x03\x20\x01(\x0cR\x08bytecode\x123\n\x08balan\
+    ces\x18\x04\x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x08balances\"6\n\
+    \x0cKeyValuePair\x12\x10\n\x03key\x18\x01\x20\x01(\x0cR\x03key\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\x0cR\x05value\"\x8e\x01\n\x14ExtendedCallResp\
+    onse\x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\
+    \x07alkanes\x12/\n\x07storage\x18\x02\x20\x03(\x0b2\x15.alkanes.KeyValue\
+    PairR\x07storage\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\"\xe1\
+    \x01\n\x07Context\x12)\n\x06myself\x18\x01\x20\x01(\x0b2\x11.alkanes.Alk\
+    aneIdR\x06myself\x12)\n\x06caller\x18\x02\x20\x01(\x0b2\x11.alkanes.Alka\
+    neIdR\x06caller\x12(\n\x06inputs\x18\x03\x20\x03(\x0b2\x10.alkanes.uint1\
+    28R\x06inputs\x12\x12\n\x04vout\x18\x04\x20\x01(\rR\x04vout\x12B\n\x10in\
+    coming_alkanes\x18\x05\x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x0finco\
+    mingAlkanes\"J\n\x0cTraceContext\x12&\n\x05inner\x18\x01\x20\x01(\x0b2\
+    \x10.alkanes.ContextR\x05inner\x12\x12\n\x04fuel\x18\x02\x20\x01(\x04R\
+    \x04fuel\"\x82\x01\n\x13AlkanesEnterContext\x12:\n\tcall_type\x18\x01\
+    \x20\x01(\x0e2\x1d.alkanes.AlkanesTraceCallTypeR\x08callType\x12/\n\x07c\
+    ontext\x18\x02\x20\x01(\x0b2\x15.alkanes.TraceContextR\x07context\"]\n\
+    \x0fStorageSlotDiff\x12\x10\n\x03key\x18\x01\x20\x01(\x0cR\x03key\x12\
+    \x1b\n\told_value\x18\x02\x20\x01(\x0cR\x08oldValue\x12\x1b\n\tnew_value\
+    \x18\x03\x20\x01(\x0cR\x08newValue\"\xc5\x01\n\x12AlkanesExitContext\x12\
+    7\n\x06status\x18\x01\x20\x01(\x0e2\x1f.alkanes.AlkanesTraceStatusFlagR\
     \x06status\x129\n\x08response\x18\x02\x20\x01(\x0b2\x1d.alkanes.Extended\
-    CallResponseR\x08response\"A\n\rAlkanesCreate\x120\n\nnew_alkane\x18\x01\
-    \x20\x01(\x0b2\x11.alkanes.AlkaneIdR\tnewAlkane\"\xe2\x01\n\x11AlkanesTr\
-    aceEvent\x12C\n\renter_context\x18\x01\x20\x01(\x0b2\x1c.alkanes.Alkanes\
-    EnterContextH\0R\x0centerContext\x12@\n\x0cexit_context\x18\x02\x20\x01(\
-    \x0b2\x1b.alkanes.AlkanesExitContextH\0R\x0bexitContext\x12=\n\rcreate_a\
-    lkane\x18\x03\x20\x01(\x0b2\x16.alkanes.AlkanesCreateH\0R\x0ccreateAlkan\
-    eB\x07\n\x05event\"\x8b\x01\n\x11AlkanesBlockEvent\x12-\n\x06traces\x18\
-    \x01\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x06traces\x12-\n\x08outpoin\
-    t\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12\x18\n\x07t\
-    xindex\x18\x03\x20\x01(\x04R\x07txindex\"L\n\x16AlkanesBlockTraceEvent\
-    \x122\n\x06events\x18\x01\x20\x03(\x0b2\x1a.alkanes.AlkanesBlockEventR\
-    \x06events\"B\n\x0cAlkanesTrace\x122\n\x06events\x18\x01\x20\x03(\x0b2\
-    \x1a.alkanes.AlkanesTraceEventR\x06events\"\x80\x01\n\x10SimulateRespons\
-    e\x12;\n\texecution\x18\x01\x20\x01(\x0b2\x1d.alkanes.ExtendedCallRespon\
-    seR\texecution\x12\x19\n\x08gas_used\x18\x02\x20\x01(\x04R\x07gasUsed\
-    \x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05error\"f\n\x15MultiSimulateRe\
-    sponse\x127\n\tresponses\x18\x01\x20\x03(\x0b2\x19.alkanes.SimulateRespo\
-    nseR\tresponses\x12\x14\n\x05error\x18\x02\x20\x01(\tR\x05error\";\n\x16\
-    AlkaneInventoryRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.Al\
-    kaneIdR\x02id\"L\n\x17AlkaneInventoryResponse\x121\n\x07alkanes\x18\x01\
-    \x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x07alkanes\"2\n\x08Outpoint\
-    \x12\x12\n\x04txid\x18\x01\x20\x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\
-    \x02\x20\x01(\rR\x04vout\"c\n\x05Trace\x12-\n\x08outpoint\x18\x01\x20\
-    \x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12+\n\x05trace\x18\x02\x20\
-    \x01(\x0b2\x15.alkanes.AlkanesTraceR\x05trace\")\n\x11TraceBlockRequest\
-    \x12\x14\n\x05block\x18\x01\x20\x01(\x04R\x05block\"<\n\x12TraceBlockRes\
-    ponse\x12&\n\x06traces\x18\x01\x20\x03(\x0b2\x0e.alkanes.TraceR\x06trace\
-    s\"4\n\x0fBytecodeRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes\
-    .AlkaneIdR\x02id\"&\n\x0cBlockRequest\x12\x16\n\x06height\x18\x01\x20\
-    \x01(\rR\x06height\"=\n\rBlockResponse\x12\x14\n\x05block\x18\x01\x20\
-    \x01(\x0cR\x05block\x12\x16\n\x06height\x18\x02\x20\x01(\rR\x06height*L\
-    \n\x14AlkanesTraceCallType\x12\x08\n\x04NONE\x10\0\x12\x08\n\x04CALL\x10\
-    \x01\x12\x10\n\x0cDELEGATECALL\x10\x02\x12\x0e\n\nSTATICCALL\x10\x03*2\n\
-    \x16AlkanesTraceStatusFlag\x12\x0b\n\x07SUCCESS\x10\0\x12\x0b\n\x07FAILU\
-    RE\x10\x01b\x06proto3\
+    CallResponseR\x08response\x12;\n\x0cstorage_diff\x18\x03\x20\x03(\x0b2\
+    \x18.alkanes.StorageSlotDiffR\x0bstorageDiff\"A\n\rAlkanesCreate\x120\n\
+    \nnew_alkane\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\tnewAlkane\"\
+    \xe2\x01\n\x11AlkanesTraceEvent\x12C\n\renter_context\x18\x01\x20\x01(\
+    \x0b2\x1c.alkanes.AlkanesEnterContextH\0R\x0centerContext\x12@\n\x0cexit\
+    _context\x18\x02\x20\x01(\x0b2\x1b.alkanes.AlkanesExitContextH\0R\x0bexi\
+    tContext\x12=\n\rcreate_alkane\x18\x03\x20\x01(\x0b2\x16.alkanes.Alkanes\
+    CreateH\0R\x0ccreateAlkaneB\x07\n\x05event\"\x8b\x01\n\x11AlkanesBlockEv\
+    ent\x12-\n\x06traces\x18\x01\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x06\
+    traces\x12-\n\x08outpoint\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\
+    \x08outpoint\x12\x18\n\x07txindex\x18\x03\x20\x01(\x04R\x07txindex\"L\n\
+    \x16AlkanesBlockTraceEvent\x122\n\x06events\x18\x01\x20\x03(\x0b2\x1a.al\
+    kanes.AlkanesBlockEventR\x06events\"B\n\x0cAlkanesTrace\x122\n\x06events\
+    \x18\x01\x20\x03(\x0b2\x1a.alkanes.AlkanesTraceEventR\x06events\"\x80\
+    \x01\n\x10SimulateResponse\x12;\n\texecution\x18\x01\x20\x01(\x0b2\x1d.a\
+    lkanes.ExtendedCallResponseR\texecution\x12\x19\n\x08gas_used\x18\x02\
+    \x20\x01(\x04R\x07gasUsed\x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05erro\
+    r\"f\n\x15MultiSimulateResponse\x127\n\tresponses\x18\x01\x20\x03(\x0b2\
+    \x19.alkanes.SimulateResponseR\tresponses\x12\x14\n\x05error\x18\x02\x20\
+    \x01(\tR\x05error\"k\n\x13EstimateFuelRequest\x125\n\x06parcel\x18\x01\
+    \x20\x01(\x0b2\x1d.alkanes.MessageContextParcelR\x06parcel\x12\x1d\n\nbl\
+    ock_size\x18\x02\x20\x01(\x04R\tblockSize\"\xcc\x01\n\x14EstimateFuelRes\
+    ponse\x12\x19\n\x08gas_used\x18\x01\x20\x01(\x04R\x07gasUsed\x12\x14\n\
+    \x05vsize\x18\x02\x20\x01(\x04R\x05vsize\x12\x12\n\x04fuel\x18\x03\x20\
+    \x01(\x04R\x04fuel\x12\x18\n\x07success\x18\x04\x20\x01(\x08R\x07success\
+    \x12\x14\n\x05error\x18\x05\x20\x01(\tR\x05error\x12%\n\x0erequired_vsiz\
+    e\x18\x06\x20\x01(\x04R\rrequiredVsize\x12\x18\n\x07padding\x18\x07\x20\
+    \x01(\x04R\x07padding\";\n\x16AlkaneInventoryRequest\x12!\n\x02id\x18\
+    \x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\"L\n\x17AlkaneInventoryRe\
+    sponse\x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alkanes.AlkaneTransfe\
+    rR\x07alkanes\"2\n\x08Outpoint\x12\x12\n\x04txid\x18\x01\x20\x01(\x0cR\
+    \x04txid\x12\x12\n\x04vout\x18\x02\x20\x01(\rR\x04vout\"c\n\x05Trace\x12\
+    -\n\x08outpoint\x18\x01\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\
+    \x12+\n\x05trace\x18\x02\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x05trac\
+    e\")\n\x11TraceBlockRequest\x12\x14\n\x05block\x18\x01\x20\x01(\x04R\x05\
+    block\"<\n\x12TraceBlockResponse\x12&\n\x06traces\x18\x01\x20\x03(\x0b2\
+    \x0e.alkanes.TraceR\x06traces\"4\n\x0fBytecodeRequest\x12!\n\x02id\x18\
+    \x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\"Q\n\x0fCodeHashRequest\
+    \x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\x12\x1b\
+    \n\tcode_hash\x18\x02\x20\x01(\x0cR\x08codeHash\"\\\n\x10CodeHashRespons\
+    e\x12\x1b\n\tcode_hash\x18\x01\x20\x01(\x0cR\x08codeHash\x12+\n\x07alkan\
+    es\x18\x02\x20\x03(\x0b2\x11.alkanes.AlkaneIdR\x07alkanes\"&\n\x0cBlockR\
+    equest\x12\x16\n\x06height\x18\x01\x20\x01(\rR\x06height\"=\n\rBlockResp\
+    onse\x12\x14\n\x05block\x18\x01\x20\x01(\x0cR\x05block\x12\x16\n\x06heig\
+    ht\x18\x02\x20\x01(\rR\x06height\"\xbd\x01\n\x0bAlkaneEvent\x12)\n\x06al\
+    kane\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06alkane\x12(\n\x06top\
+    ics\x18\x02\x20\x03(\x0b2\x10.alkanes.uint128R\x06topics\x12\x12\n\x04da\
+    ta\x18\x03\x20\x01(\x0cR\x04data\x12\x16\n\x06height\x18\x04\x20\x01(\
+    \x04R\x06height\x12-\n\x08outpoint\x18\x05\x20\x01(\x0b2\x11.alkanes.Out\
+    pointR\x08outpoint\"\xce\x01\n\rEventsRequest\x12)\n\x06alkane\x18\x01\
+    \x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06alkane\x12&\n\x05topic\x18\x02\
+    \x20\x01(\x0b2\x10.alkanes.uint128R\x05topic\x12\x1f\n\x0bfrom_height\
+    \x18\x03\x20\x01(\x04R\nfromHeight\x12\x1b\n\tto_height\x18\x04\x20\x01(\
+    \x04R\x08toHeight\x12\x16\n\x06offset\x18\x05\x20\x01(\x04R\x06offset\
+    \x12\x14\n\x05limit\x18\x06\x20\x01(\x04R\x05limit\".\n\x18TransactionEv\
+    entsRequest\x12\x12\n\x04txid\x18\x01\x20\x01(\x0cR\x04txid\"T\n\x0eEven\
+    tsResponse\x12,\n\x06events\x18\x01\x20\x03(\x0b2\x14.alkanes.AlkaneEven\
+    tR\x06events\x12\x14\n\x05total\x18\x02\x20\x01(\x04R\x05total\"\xad\x01\
+    \n\rLedgerRequest\x12)\n\x06alkane\x18\x01\x20\x01(\x0b2\x11.alkanes.Alk\
+    aneIdR\x06alkane\x12\x18\n\x07address\x18\x02\x20\x01(\x0cR\x07address\
+    \x12)\n\x06holder\x18\x03\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06holder\
+    \x12\x16\n\x06offset\x18\x04\x20\x01(\x04R\x06offset\x12\x14\n\x05limit\
+    \x18\x05\x20\x01(\x04R\x05limit\"\xfd\x01\n\x0bLedgerEntry\x12)\n\x06alk\
+    ane\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06alkane\x12\x18\n\x07a\
+    ddress\x18\x02\x20\x01(\x0cR\x07address\x12)\n\x06holder\x18\x03\x20\x01\
+    (\x0b2\x11.alkanes.AlkaneIdR\x06holder\x12\x12\n\x04txid\x18\x04\x20\x01\
+    (\x0cR\x04txid\x12\x12\n\x04vout\x18\x05\x20\x01(\rR\x04vout\x12(\n\x06a\
+    mount\x18\x06\x20\x01(\x0b2\x10.alkanes.uint128R\x06amount\x12\x14\n\x05\
+    debit\x18\x07\x20\x01(\x08R\x05debit\x12\x16\n\x06height\x18\x08\x20\x01\
+    (\x04R\x06height\"V\n\x0eLedgerResponse\x12.\n\x07entries\x18\x01\x20\
+    \x03(\x0b2\x14.alkanes.LedgerEntryR\x07entries\x12\x14\n\x05total\x18\
+    \x02\x20\x01(\x04R\x05total\"i\n\x0eHoldersRequest\x12)\n\x06alkane\x18\
+    \x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06alkane\x12\x16\n\x06offset\
+    \x18\x02\x20\x01(\x04R\x06offset\x12\x14\n\x05limit\x18\x03\x20\x01(\x04\
+    R\x05limit\"\xae\x01\n\x0cAlkaneHolder\x12-\n\x08outpoint\x18\x01\x20\
+    \x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12\x18\n\x07address\x18\
+    \x02\x20\x01(\x0cR\x07address\x12)\n\x06alkane\x18\x03\x20\x01(\x0b2\x11\
+    .alkanes.AlkaneIdR\x06alkane\x12*\n\x07balance\x18\x04\x20\x01(\x0b2\x10\
+    .alkanes.uint128R\x07balance\"\x82\x01\n\x0fHoldersResponse\x12/\n\x07ho\
+    lders\x18\x01\x20\x03(\x0b2\x15.alkanes.AlkaneHolderR\x07holders\x12\x14\
+    \n\x05total\x18\x02\x20\x01(\x04R\x05total\x12(\n\x06supply\x18\x03\x20\
+    \x01(\x0b2\x10.alkanes.uint128R\x06supply\"\xda\x01\n\x0cAlkaneRecord\
+    \x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\x12\x16\
+    \n\x06height\x18\x02\x20\x01(\x04R\x06height\x12\x12\n\x04txid\x18\x03\
+    \x20\x01(\x0cR\x04txid\x12/\n\x04kind\x18\x04\x20\x01(\x0e2\x1b.alkanes.\
+    AlkaneCreationKindR\x04kind\x12-\n\x08template\x18\x05\x20\x01(\x0b2\x11\
+    .alkanes.AlkaneIdR\x08template\x12\x1b\n\tcode_hash\x18\x06\x20\x01(\x0c\
+    R\x08codeHash\"\xab\x01\n\x0eAlkanesRequest\x12\x1f\n\x0bfrom_height\x18\
+    \x01\x20\x01(\x04R\nfromHeight\x12\x1b\n\tto_height\x18\x02\x20\x01(\x04\
+    R\x08toHeight\x12-\n\x08template\x18\x03\x20\x01(\x0b2\x11.alkanes.Alkan\
+    eIdR\x08template\x12\x16\n\x06offset\x18\x04\x20\x01(\x04R\x06offset\x12\
+    \x14\n\x05limit\x18\x05\x20\x01(\x04R\x05limit\"X\n\x0fAlkanesResponse\
+    \x12/\n\x07alkanes\x18\x01\x20\x03(\x0b2\x15.alkanes.AlkaneRecordR\x07al\
+    kanes\x12\x14\n\x05total\x18\x02\x20\x01(\x04R\x05total\"u\n\x0fPrevoutB\
+    alances\x12-\n\x08outpoint\x18\x01\x20\x01(\x0b2\x11.alkanes.OutpointR\
+    \x08outpoint\x123\n\x08balances\x18\x02\x20\x03(\x0b2\x17.alkanes.Alkane\
+    TransferR\x08balances\"\x8c\x01\n\x1aSimulateTransactionRequest\x12\x20\
+    \n\x0btransaction\x18\x01\x20\x01(\x0cR\x0btransaction\x124\n\x08prevout\
+    s\x18\x02\x20\x03(\x0b2\x18.alkanes.PrevoutBalancesR\x08prevouts\x12\x16\
+    \n\x06height\x18\x03\x20\x01(\x04R\x06height\"Y\n\x0eOutputBalances\x12\
+    \x12\n\x04vout\x18\x01\x20\x01(\rR\x04vout\x123\n\x08balances\x18\x02\
+    \x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x08balances\"\x90\x01\n\x1bSi\
+    mulateTransactionResponse\x121\n\x07outputs\x18\x01\x20\x03(\x0b2\x17.al\
+    kanes.OutputBalancesR\x07outputs\x12&\n\x06traces\x18\x02\x20\x03(\x0b2\
+    \x0e.alkanes.TraceR\x06traces\x12\x16\n\x06errors\x18\x03\x20\x03(\tR\
+    \x06errors*L\n\x14AlkanesTraceCallType\x12\x08\n\x04NONE\x10\0\x12\x08\n\
+    \x04CALL\x10\x01\x12\x10\n\x0cDELEGATECALL\x10\x02\x12\x0e\n\nSTATICCALL\
+    \x10\x03*2\n\x16AlkanesTraceStatusFlag\x12\x0b\n\x07SUCCESS\x10\0\x12\
+    \x0b\n\x07FAILURE\x10\x01*;\n\x12AlkaneCreationKind\x12\n\n\x06CREATE\
+    \x10\0\x12\x0c\n\x08RESERVED\x10\x01\x12\x0b\n\x07FACTORY\x10\x02b\x06pr\
+    oto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -4314,17 +8405,19 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(27);
+            let mut messages = ::std::vec::Vec::with_capacity(50);
             messages.push(Uint128::generated_message_descriptor_data());
             messages.push(AlkaneId::generated_message_descriptor_data());
             messages.push(AlkaneTransfer::generated_message_descriptor_data());
             messages.push(MultiSimulateRequest::generated_message_descriptor_data());
             messages.push(MessageContextParcel::generated_message_descriptor_data());
+            messages.push(AlkaneOverride::generated_message_descriptor_data());
             messages.push(KeyValuePair::generated_message_descriptor_data());
             messages.push(ExtendedCallResponse::generated_message_descriptor_data());
             messages.push(Context::generated_message_descriptor_data());
             messages.push(TraceContext::generated_message_descriptor_data());
             messages.push(AlkanesEnterContext::generated_message_descriptor_data());
+            messages.push(StorageSlotDiff::generated_message_descriptor_data());
             messages.push(AlkanesExitContext::generated_message_descriptor_data());
             messages.push(AlkanesCreate::generated_message_descriptor_data());
             messages.push(AlkanesTraceEvent::generated_message_descriptor_data());
@@ -4333,6 +8426,8 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(AlkanesTrace::generated_message_descriptor_data());
             messages.push(SimulateResponse::generated_message_descriptor_data());
             messages.push(MultiSimulateResponse::generated_message_descriptor_data());
+            messages.push(EstimateFuelRequest::generated_message_descriptor_data());
+            messages.push(EstimateFuelResponse::generated_message_descriptor_data());
             messages.push(AlkaneInventoryRequest::generated_message_descriptor_data());
             messages.push(AlkaneInventoryResponse::generated_message_descriptor_data());
             messages.push(Outpoint::generated_message_descriptor_data());
@@ -4340,11 +8435,31 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(TraceBlockRequest::generated_message_descriptor_data());
             messages.push(TraceBlockResponse::generated_message_descriptor_data());
             messages.push(BytecodeRequest::generated_message_descriptor_data());
+            messages.push(CodeHashRequest::generated_message_descriptor_data());
+            messages.push(CodeHashResponse::generated_message_descriptor_data());
             messages.push(BlockRequest::generated_message_descriptor_data());
             messages.push(BlockResponse::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(2);
+            messages.push(AlkaneEvent::generated_message_descriptor_data());
+            messages.push(EventsRequest::generated_message_descriptor_data());
+            messages.push(TransactionEventsRequest::generated_message_descriptor_data());
+            messages.push(EventsResponse::generated_message_descriptor_data());
+            messages.push(LedgerRequest::generated_message_descriptor_data());
+            messages.push(LedgerEntry::generated_message_descriptor_data());
+            messages.push(LedgerResponse::generated_message_descriptor_data());
+            messages.push(HoldersRequest::generated_message_descriptor_data());
+            messages.push(AlkaneHolder::generated_message_descriptor_data());
+            messages.push(HoldersResponse::generated_message_descriptor_data());
+            messages.push(AlkaneRecord::generated_message_descriptor_data());
+            messages.push(AlkanesRequest::generated_message_descriptor_data());
+            messages.push(AlkanesResponse::generated_message_descriptor_data());
+            messages.push(PrevoutBalances::generated_message_descriptor_data());
+            messages.push(SimulateTransactionRequest::generated_message_descriptor_data());
+            messages.push(OutputBalances::generated_message_descriptor_data());
+            messages.push(SimulateTransactionResponse::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(3);
             enums.push(AlkanesTraceCallType::generated_enum_descriptor_data());
             enums.push(AlkanesTraceStatusFlag::generated_enum_descriptor_data());
+            enums.push(AlkaneCreationKind::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
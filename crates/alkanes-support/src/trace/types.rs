@@ -14,10 +14,19 @@ pub struct TraceContext {
     pub fuel: u64,
 }
 
+// a storage slot written when a call exits, with its value before and after
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageSlotDiff {
+    pub key: Vec<u8>,
+    pub old_value: Vec<u8>,
+    pub new_value: Vec<u8>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TraceResponse {
     pub inner: ExtendedCallResponse,
     pub fuel_used: u64,
+    pub storage_diff: Vec<StorageSlotDiff>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +44,7 @@ impl Into<TraceResponse> for ExtendedCallResponse {
         TraceResponse {
             inner: self,
             fuel_used: 0,
+            storage_diff: vec![],
         }
     }
 }
@@ -71,10 +81,35 @@ impl Into<proto::alkanes::Context> for Context {
     }
 }
 
+impl Into<proto::alkanes::StorageSlotDiff> for StorageSlotDiff {
+    fn into(self) -> proto::alkanes::StorageSlotDiff {
+        let mut result = proto::alkanes::StorageSlotDiff::new();
+        result.key = self.key;
+        result.old_value = self.old_value;
+        result.new_value = self.new_value;
+        result
+    }
+}
+
+impl From<proto::alkanes::StorageSlotDiff> for StorageSlotDiff {
+    fn from(v: proto::alkanes::StorageSlotDiff) -> StorageSlotDiff {
+        StorageSlotDiff {
+            key: v.key,
+            old_value: v.old_value,
+            new_value: v.new_value,
+        }
+    }
+}
+
 impl Into<proto::alkanes::AlkanesExitContext> for TraceResponse {
     fn into(self) -> proto::alkanes::AlkanesExitContext {
         let mut result = proto::alkanes::AlkanesExitContext::new();
         result.response = MessageField::some(self.inner.into());
+        result.storage_diff = self
+            .storage_diff
+            .into_iter()
+            .map(|v| v.into())
+            .collect::<Vec<proto::alkanes::StorageSlotDiff>>();
         result
     }
 }
@@ -195,6 +230,11 @@ impl From<proto::alkanes::AlkanesExitContext> for TraceResponse {
         TraceResponse {
             inner: response,
             fuel_used: 0,
+            storage_diff: v
+                .storage_diff
+                .into_iter()
+                .map(|v| v.into())
+                .collect::<Vec<StorageSlotDiff>>(),
         }
     }
 }
@@ -241,9 +281,8 @@ impl From<proto::alkanes::AlkanesTraceEvent> for TraceEvent {
                 }
                 proto::alkanes::alkanes_trace_event::Event::ExitContext(v) => {
                     match v.status.value() {
-                        0 => TraceEvent::ReturnContext(field_or_default(v.response)),
-                        1 => TraceEvent::RevertContext(field_or_default(v.response)),
-                        _ => TraceEvent::RevertContext(field_or_default(v.response)),
+                        0 => TraceEvent::ReturnContext(v.into()),
+                        _ => TraceEvent::RevertContext(v.into()),
                     }
                 }
                 proto::alkanes::alkanes_trace_event::Event::CreateAlkane(v) => {
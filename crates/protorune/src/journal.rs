@@ -0,0 +1,246 @@
+use anyhow::{anyhow, Result};
+use metashrew_core::index_pointer::IndexPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consume_sized_int;
+use once_cell::sync::Lazy;
+use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations};
+use std::collections::HashSet;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+// Undo journal for reorg handling and historical views. While a block is
+// being indexed, every key recorded here has its value as of the start of the
+// block appended to the journal for that height. Rolling back replays those
+// entries in reverse, and views replay them in memory to read past state.
+
+pub static JOURNAL: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/journal/byheight/"));
+
+// deepest reorg we will roll back, and by default the furthest back a view
+// can read. Entries older than this are pruned as each block is closed out
+pub const JOURNAL_DEPTH: u64 = 100;
+
+// built with the `archive` feature, entries are never pruned, so views can
+// read any height since the journal started. That costs the storage of every
+// block's entries, and a view undoes each block between the tip and the
+// height it reads. Reorgs stay limited to JOURNAL_DEPTH either way
+pub const ARCHIVE: bool = cfg!(feature = "archive");
+
+static mut _JOURNAL_HEIGHT: Option<u64> = None;
+static mut _JOURNALED: Option<HashSet<Vec<u8>>> = None;
+
+pub fn tip_pointer() -> IndexPointer {
+    IndexPointer::from_keyword("/journal/tip")
+}
+
+// first height journaled since the journal was last empty; nothing before it
+// can be rewound
+pub fn start_pointer() -> IndexPointer {
+    IndexPointer::from_keyword("/journal/start")
+}
+
+#[allow(static_mut_refs)]
+pub fn begin(height: u64) {
+    unsafe {
+        _JOURNAL_HEIGHT = Some(height);
+        _JOURNALED = Some(HashSet::new());
+    }
+}
+
+#[allow(static_mut_refs)]
+pub fn end() {
+    unsafe {
+        if let Some(height) = _JOURNAL_HEIGHT.take() {
+            if tip().is_none() {
+                start_pointer().set_value::<u64>(height);
+            }
+            tip_pointer().set_value::<u64>(height);
+            if !ARCHIVE && height >= JOURNAL_DEPTH {
+                prune(height - JOURNAL_DEPTH);
+            }
+        }
+        _JOURNALED = None;
+    }
+}
+
+#[allow(static_mut_refs)]
+pub fn is_active() -> bool {
+    unsafe { _JOURNAL_HEIGHT.is_some() }
+}
+
+pub fn tip() -> Option<u64> {
+    let ptr = tip_pointer();
+    if ptr.get().len() == 0 {
+        None
+    } else {
+        Some(ptr.get_value::<u64>())
+    }
+}
+
+#[allow(static_mut_refs)]
+pub fn record_key(key: &Vec<u8>) {
+    unsafe {
+        let height = match _JOURNAL_HEIGHT {
+            Some(v) => v,
+            None => return,
+        };
+        let journaled = _JOURNALED.get_or_insert_with(HashSet::new);
+        if !journaled.insert(key.clone()) {
+            return;
+        }
+        // read through the base pointer so values written by an uncommitted
+        // atomic checkpoint are never mistaken for the prior state
+        let prior = IndexPointer::wrap(key).get();
+        let mut entry: Vec<u8> = (key.len() as u32).to_le_bytes().to_vec();
+        entry.extend(key);
+        entry.extend(prior.as_ref());
+        JOURNAL.select_value::<u64>(height).append(Arc::new(entry));
+    }
+}
+
+pub fn record<T: KeyValuePointer>(ptr: &T) {
+    if is_active() {
+        record_key(ptr.unwrap().as_ref());
+    }
+}
+
+pub fn record_list<T: KeyValuePointer>(ptr: &T) {
+    if is_active() {
+        record(&ptr.length_key());
+    }
+}
+
+pub fn record_sheet<T: KeyValuePointer, P: KeyValuePointer + Clone>(
+    ptr: &T,
+    sheet: &BalanceSheet<P>,
+) {
+    if !is_active() {
+        return;
+    }
+    let runes_ptr = ptr.keyword("/runes");
+    let balances_ptr = ptr.keyword("/balances");
+    let runes_to_balances_ptr = ptr.keyword("/id_to_balance");
+    record_list(&runes_ptr);
+    record_list(&balances_ptr);
+    for i in 0..runes_ptr.length() {
+        record(&balances_ptr.select_index(i));
+        record(&runes_to_balances_ptr.select(&runes_ptr.select_index(i).get()));
+    }
+    for rune in sheet.balances().keys() {
+        let rune_bytes: Vec<u8> = (*rune).into();
+        record(&runes_to_balances_ptr.select(&rune_bytes));
+    }
+}
+
+fn prune(height: u64) {
+    let list = JOURNAL.select_value::<u64>(height);
+    for i in 0..list.length() {
+        list.select_index(i).nullify();
+    }
+    list.length_key().set_value::<u32>(0);
+}
+
+// lowest height `at_height` can rewind to: every block after it must still
+// have its journal
+pub fn window_start() -> Option<u64> {
+    let tip = tip()?;
+    let start = start_pointer().get_value::<u64>().saturating_sub(1);
+    if ARCHIVE {
+        Some(start)
+    } else {
+        Some(std::cmp::max(tip.saturating_sub(JOURNAL_DEPTH), start))
+    }
+}
+
+fn decode_entry(entry: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut cursor = Cursor::new(entry.clone());
+    let key_length = consume_sized_int::<u32>(&mut cursor)? as usize;
+    let mut key = vec![0u8; key_length];
+    cursor.read_exact(&mut key)?;
+    let mut prior = Vec::new();
+    cursor.read_to_end(&mut prior)?;
+    Ok((key, prior))
+}
+
+fn undo_entry(entry: &Vec<u8>) -> Result<()> {
+    let (key, prior) = decode_entry(entry)?;
+    IndexPointer::wrap(&key).set(Arc::new(prior));
+    Ok(())
+}
+
+// restores the state as of the start of `height`, undoing that block and every
+// journaled block after it
+pub fn rollback(height: u64) -> Result<()> {
+    let tip = match tip() {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+    if height > tip {
+        return Ok(());
+    }
+    if tip - height >= JOURNAL_DEPTH {
+        return Err(anyhow!(
+            "reorg to height {} is deeper than the journal depth of {} blocks",
+            height,
+            JOURNAL_DEPTH
+        ));
+    }
+    for h in (height..=tip).rev() {
+        let list = JOURNAL.select_value::<u64>(h);
+        let entries = list.get_list();
+        for entry in entries.iter().rev() {
+            undo_entry(entry.as_ref())?;
+        }
+        list.length_key().set_value::<u32>(0);
+    }
+    if height == 0 {
+        tip_pointer().set(Arc::new(vec![]));
+    } else {
+        tip_pointer().set_value::<u64>(height - 1);
+    }
+    Ok(())
+}
+
+// Runs `f` against the journaled state as it stood once `height` was indexed.
+// Every later block is undone in memory and the tip values are written back
+// when `f` returns, so this is only for views: a flush in between would
+// persist the rewound state. Heights older than the retained window are an
+// error rather than a silent read of the tip.
+pub fn at_height<T>(height: u64, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let tip = match tip() {
+        Some(v) if height < v => v,
+        _ => return f(),
+    };
+    let oldest = window_start().unwrap_or(tip);
+    if height < oldest {
+        return Err(anyhow!(
+            "height {} is outside the journal window, which starts at {}",
+            height,
+            oldest
+        ));
+    }
+    // decode everything before touching state, so a bad entry fails the view
+    // without leaving the tip half rewound
+    let mut entries: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+    for h in ((height + 1)..=tip).rev() {
+        for entry in JOURNAL.select_value::<u64>(h).get_list().iter().rev() {
+            entries.push(decode_entry(entry.as_ref())?);
+        }
+    }
+    let mut saved: Vec<(Vec<u8>, Arc<Vec<u8>>)> = vec![];
+    for (key, prior) in entries {
+        let mut ptr = IndexPointer::wrap(&key);
+        saved.push((key, ptr.get()));
+        ptr.set(Arc::new(prior));
+    }
+    let result = f();
+    for (key, value) in saved.into_iter().rev() {
+        IndexPointer::wrap(&key).set(value);
+    }
+    result
+}
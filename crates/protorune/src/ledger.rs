@@ -0,0 +1,324 @@
+use crate::balance_sheet::load_sheet;
+use crate::journal;
+use crate::tables::{self, RuneTable};
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::{OutPoint, Transaction, Txid};
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::{
+    consensus_decode, consensus_encode, consume_exact, consume_sized_int,
+};
+use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations, ProtoruneRuneId};
+use std::io::Cursor;
+use std::sync::Arc;
+
+// Transfer ledger. Every balance change of a protorune is appended twice,
+// once under the rune and once under the holder, so movements can be paged
+// through without replaying transactions. The same writes keep each rune's
+// circulating supply and the set of outpoints and ids currently holding it.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerHolder {
+    // the address an outpoint is spendable by
+    Address(Vec<u8>),
+    // a balance held by an id of the protocol itself, e.g. an alkane
+    Id(ProtoruneRuneId),
+}
+
+impl LedgerHolder {
+    pub fn key(&self) -> Vec<u8> {
+        match self {
+            LedgerHolder::Address(v) => v.clone(),
+            LedgerHolder::Id(v) => (*v).into(),
+        }
+    }
+}
+
+// the transaction and vout a balance change is attributed to. For outputs
+// this is the outpoint credited, for spent inputs the input index, and for
+// balances held by ids the shadow vout of the protostone
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LedgerOrigin {
+    pub txid: Txid,
+    pub vout: u32,
+    pub height: u64,
+}
+
+// a member of a rune's holder set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HolderKey {
+    Outpoint(OutPoint),
+    Id(ProtoruneRuneId),
+}
+
+impl HolderKey {
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let mut result = Vec::<u8>::new();
+        match self {
+            HolderKey::Outpoint(v) => {
+                result.push(0);
+                result.extend(consensus_encode(v)?);
+            }
+            HolderKey::Id(v) => {
+                result.push(1);
+                result.extend(Vec::<u8>::from(*v));
+            }
+        }
+        Ok(result)
+    }
+    pub fn parse(v: &[u8]) -> Result<HolderKey> {
+        match v.first() {
+            Some(0) => Ok(HolderKey::Outpoint(consensus_decode::<OutPoint>(
+                &mut Cursor::new(v[1..].to_vec()),
+            )?)),
+            Some(1) => Ok(HolderKey::Id(v[1..].to_vec().try_into()?)),
+            _ => Err(anyhow!("malformed holder key")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub rune: ProtoruneRuneId,
+    pub holder: LedgerHolder,
+    pub txid: Txid,
+    pub vout: u32,
+    pub amount: u128,
+    pub debit: bool,
+    pub height: u64,
+}
+
+impl LedgerEntry {
+    pub fn new(
+        rune: ProtoruneRuneId,
+        holder: LedgerHolder,
+        origin: &LedgerOrigin,
+        amount: u128,
+        debit: bool,
+    ) -> Self {
+        LedgerEntry {
+            rune,
+            holder,
+            txid: origin.txid,
+            vout: origin.vout,
+            amount,
+            debit,
+            height: origin.height,
+        }
+    }
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result: Vec<u8> = self.rune.into();
+        let (tag, holder) = match &self.holder {
+            LedgerHolder::Address(v) => (0u8, v.clone()),
+            LedgerHolder::Id(v) => (1u8, (*v).into()),
+        };
+        result.push(tag);
+        result.extend(&(holder.len() as u32).to_le_bytes());
+        result.extend(&holder);
+        result.extend(self.txid.as_byte_array());
+        result.extend(&self.vout.to_le_bytes());
+        result.extend(&self.amount.to_le_bytes());
+        result.push(self.debit as u8);
+        result.extend(&self.height.to_le_bytes());
+        result
+    }
+    pub fn parse(cursor: &mut Cursor<Vec<u8>>) -> Result<LedgerEntry> {
+        let rune = ProtoruneRuneId::new(
+            consume_sized_int::<u128>(cursor)?,
+            consume_sized_int::<u128>(cursor)?,
+        );
+        let tag = consume_sized_int::<u8>(cursor)?;
+        let length = consume_sized_int::<u32>(cursor)? as usize;
+        let holder_bytes = consume_exact(cursor, length)?;
+        let holder = match tag {
+            0 => LedgerHolder::Address(holder_bytes),
+            1 => LedgerHolder::Id(holder_bytes.try_into()?),
+            _ => return Err(anyhow!("unknown ledger holder tag {}", tag)),
+        };
+        Ok(LedgerEntry {
+            rune,
+            holder,
+            txid: Txid::from_slice(&consume_exact(cursor, 32)?)?,
+            vout: consume_sized_int::<u32>(cursor)?,
+            amount: consume_sized_int::<u128>(cursor)?,
+            debit: consume_sized_int::<u8>(cursor)? != 0,
+            height: consume_sized_int::<u64>(cursor)?,
+        })
+    }
+}
+
+pub fn append_entry(atomic: &mut AtomicPointer, table: &RuneTable, entry: &LedgerEntry) {
+    if entry.amount == 0 {
+        return;
+    }
+    let supply = table.SUPPLY.select(&entry.rune.into());
+    journal::record(&supply);
+    let mut supply = atomic.derive(&supply);
+    let current = supply.get_value::<u128>();
+    supply.set_value::<u128>(if entry.debit {
+        current.saturating_sub(entry.amount)
+    } else {
+        current.saturating_add(entry.amount)
+    });
+    let bytes = Arc::new(entry.serialize());
+    let by_rune = table.LEDGER_BY_RUNE_ID.select(&entry.rune.into());
+    journal::record_list(&by_rune);
+    atomic.derive(&by_rune).append(bytes.clone());
+    let key = entry.holder.key();
+    // outpoints that are not spendable by an address are only kept per rune
+    if key.is_empty() {
+        return;
+    }
+    let by_holder = table.LEDGER_BY_HOLDER.select(&key);
+    journal::record_list(&by_holder);
+    atomic.derive(&by_holder).append(bytes);
+}
+
+// adds or removes a holder from a rune's holder set. The set is a list plus
+// each member's position in it, so removal swaps the last member into the gap
+pub fn set_holder(
+    atomic: &mut AtomicPointer,
+    table: &RuneTable,
+    rune: &ProtoruneRuneId,
+    holder: &HolderKey,
+    holds: bool,
+) -> Result<()> {
+    let key = holder.serialize()?;
+    let rune_bytes: Vec<u8> = (*rune).into();
+    let list = table.HOLDERS.select(&rune_bytes);
+    let positions = table.HOLDER_POSITION.select(&rune_bytes).keyword("/");
+    let position = positions.select(&key);
+    let index = atomic.derive(&position).get_value::<u32>();
+    if holds == (index != 0) {
+        return Ok(());
+    }
+    journal::record(&position);
+    journal::record_list(&list);
+    let mut members = atomic.derive(&list);
+    if holds {
+        members.append(Arc::new(key));
+        let length = members.length();
+        atomic.derive(&position).set_value::<u32>(length);
+    } else {
+        let length = members.length();
+        let last = members.select_index(length - 1).get();
+        let last_position = positions.select(last.as_ref());
+        journal::record(&list.select_index(index - 1));
+        journal::record(&list.select_index(length - 1));
+        journal::record(&last_position);
+        members.select_index(index - 1).set(last);
+        atomic.derive(&last_position).set_value::<u32>(index);
+        members.select_index(length - 1).set(Arc::new(vec![]));
+        members.length_key().set_value::<u32>(length - 1);
+        atomic.derive(&position).set_value::<u32>(0);
+    }
+    Ok(())
+}
+
+// appends an entry for every rune in the sheet of an outpoint that was just
+// created or spent, in rune order so the ledger comes out the same on every
+// indexer
+pub fn append_sheet<P: KeyValuePointer + Clone>(
+    atomic: &mut AtomicPointer,
+    table: &RuneTable,
+    sheet: &BalanceSheet<P>,
+    outpoint: &OutPoint,
+    origin: &LedgerOrigin,
+    debit: bool,
+) -> Result<()> {
+    let holder = outpoint_holder(outpoint)?;
+    let mut balances = sheet
+        .balances()
+        .iter()
+        .filter(|(_k, v)| **v != 0)
+        .map(|(k, v)| (*k, *v))
+        .collect::<Vec<(ProtoruneRuneId, u128)>>();
+    balances.sort();
+    for (rune, amount) in balances {
+        append_entry(
+            atomic,
+            table,
+            &LedgerEntry::new(rune, holder.clone(), origin, amount, debit),
+        );
+        set_holder(
+            atomic,
+            table,
+            &rune,
+            &HolderKey::Outpoint(*outpoint),
+            !debit,
+        )?;
+    }
+    Ok(())
+}
+
+// Protorunes on an input that no protostone took up stay behind on the spent
+// outpoint, where nothing can move them again. That covers transactions with
+// no runestone, a runestone without protostones, or a cenotaph. They are
+// debited once the transaction is indexed so holder sets and supply only count
+// balances that can still be spent; inputs a protostone consumed are already
+// cleared and debit nothing here. The debits go through the transaction's own
+// atomic pointer, so its caller commits them together with the runestone
+pub fn debit_stranded_inputs(
+    atomic: &mut AtomicPointer,
+    table: &RuneTable,
+    tx: &Transaction,
+    height: u64,
+) -> Result<()> {
+    let txid = tx.compute_txid();
+    for (i, input) in tx.input.iter().enumerate() {
+        // read through `atomic`, which already holds whatever the transaction's
+        // protostones cleared
+        let outpoint = atomic.derive(
+            &table
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&input.previous_output)?),
+        );
+        if outpoint.keyword("/runes").length() == 0 {
+            continue;
+        }
+        append_sheet(
+            atomic,
+            table,
+            &load_sheet(&outpoint),
+            &input.previous_output,
+            &LedgerOrigin {
+                txid,
+                vout: i as u32,
+                height,
+            },
+            true,
+        )?;
+    }
+    Ok(())
+}
+
+// the address an outpoint is spendable by, if any
+fn outpoint_holder(outpoint: &OutPoint) -> Result<LedgerHolder> {
+    Ok(LedgerHolder::Address(
+        tables::OUTPOINT_SPENDABLE_BY
+            .select(&consensus_encode(outpoint)?)
+            .get()
+            .as_ref()
+            .clone(),
+    ))
+}
+
+// returns up to `limit` entries of a ledger list starting at `offset`, along
+// with the length of the whole list
+pub fn page(list: &IndexPointer, offset: u64, limit: u64) -> Result<(Vec<LedgerEntry>, u64)> {
+    let total = list.length() as u64;
+    let end = std::cmp::min(total, offset.saturating_add(limit));
+    let mut entries = Vec::<LedgerEntry>::new();
+    for i in offset..end {
+        entries.push(LedgerEntry::parse(&mut Cursor::new(
+            list.select_index(i as u32).get().as_ref().clone(),
+        ))?);
+    }
+    Ok((entries, total))
+}
@@ -36,6 +36,8 @@ use std::ops::Sub;
 use std::sync::Arc;
 
 pub mod balance_sheet;
+pub mod journal;
+pub mod ledger;
 pub mod message;
 pub mod protoburn;
 pub mod protorune_init;
@@ -573,10 +575,14 @@ impl Protorune {
     }
 
     pub fn index_unspendables<T: MessageContext>(block: &Block, height: u64) -> Result<()> {
+        let table = tables::RuneTable::for_protocol(T::protocol_tag());
         for (index, tx) in block.txdata.iter().enumerate() {
+            // the runestone and the inputs it strands are written through one
+            // atomic pointer, committed once the transaction is indexed
+            let mut atomic = AtomicPointer::default();
             if let Some(Artifact::Runestone(ref runestone)) = Runestone::decipher(tx) {
-                let mut atomic = AtomicPointer::default();
                 let runestone_output_index: u32 = Self::get_runestone_output_index(tx)?;
+                atomic.checkpoint();
                 match Self::index_runestone::<T>(
                     &mut atomic,
                     tx,
@@ -595,10 +601,18 @@ impl Protorune {
                     }
                 };
             }
+            ledger::debit_stranded_inputs(&mut atomic, &table, tx, height)?;
+            atomic.commit();
             for input in &tx.input {
                 //all inputs must be used up, even in cenotaphs
                 let key = consensus_encode(&input.previous_output)?;
-                clear_balances(&mut tables::RUNES.OUTPOINT_TO_RUNES.select(&key));
+                let mut ptr = tables::RUNES.OUTPOINT_TO_RUNES.select(&key);
+                // clearing an empty sheet writes nothing, so only sheets that
+                // hold runes need to be journaled
+                if ptr.keyword("/runes").length() != 0 {
+                    journal::record_sheet(&ptr, &BalanceSheet::<IndexPointer>::default());
+                    clear_balances(&mut ptr);
+                }
             }
         }
         Ok(())
@@ -632,6 +646,8 @@ impl Protorune {
                     #[cfg(feature = "cache")]
                     updated_addresses.insert(address.to_vec());
 
+                    journal::record_list(&tables::OUTPOINTS_FOR_ADDRESS.select(&address));
+                    journal::record(&tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes));
                     tables::OUTPOINTS_FOR_ADDRESS
                         .select(&address.clone())
                         .append(Arc::new(outpoint_bytes.clone()));
@@ -727,6 +743,7 @@ impl Protorune {
         let ptr = tables::RUNES
             .HEIGHT_TO_TRANSACTION_IDS
             .select_value::<u64>(height);
+        journal::record_list(&ptr);
         for tx in &block.txdata {
             ptr.append(Arc::new(tx.compute_txid().as_byte_array().to_vec()));
         }
@@ -788,20 +805,34 @@ impl Protorune {
             //     "saving balancesheet: {:#?} to outpoint: {:#?}",
             //     sheet, outpoint
             // );
-            sheet.save(
-                &mut atomic.derive(
-                    &table
-                        .OUTPOINT_TO_RUNES
-                        .select(&consensus_encode(&outpoint)?),
-                ),
-                false,
+            let mut ptr = atomic.derive(
+                &table
+                    .OUTPOINT_TO_RUNES
+                    .select(&consensus_encode(&outpoint)?),
             );
+            journal::record_sheet(&ptr, &sheet);
+            sheet.save(&mut ptr, false);
+            ledger::append_sheet(
+                atomic,
+                table,
+                &sheet,
+                &outpoint,
+                &ledger::LedgerOrigin {
+                    txid: outpoint.txid,
+                    vout: outpoint.vout,
+                    height,
+                },
+                false,
+            )?;
         }
         if map.contains_key(&u32::MAX) {
-            map.get(&u32::MAX)
+            let sheet = map
+                .get(&u32::MAX)
                 .map(|v| v.clone())
-                .unwrap_or_else(|| BalanceSheet::default())
-                .save(&mut atomic.derive(&table.RUNTIME_BALANCE), false);
+                .unwrap_or_else(|| BalanceSheet::default());
+            let mut ptr = atomic.derive(&table.RUNTIME_BALANCE);
+            journal::record_sheet(&ptr, &sheet);
+            sheet.save(&mut ptr, false);
         }
         index_unique_protorunes::<T>(
             atomic,
@@ -857,6 +888,20 @@ impl Protorune {
                     ))
                 })
                 .collect::<Result<Vec<BalanceSheet<AtomicPointer>>>>()?;
+            for (i, (input, sheet)) in tx.input.iter().zip(sheets.iter()).enumerate() {
+                ledger::append_sheet(
+                    atomic,
+                    &table,
+                    sheet,
+                    &input.previous_output,
+                    &ledger::LedgerOrigin {
+                        txid: tx.compute_txid(),
+                        vout: i as u32,
+                        height,
+                    },
+                    true,
+                )?;
+            }
             let mut balance_sheet = BalanceSheet::concat(sheets);
             protostones.process_burns(
                 &mut atomic.derive(&IndexPointer::default()),
@@ -965,7 +1010,9 @@ impl Protorune {
             for input in &tx.input {
                 //all inputs must be used up, even in cenotaphs
                 let key = consensus_encode(&input.previous_output)?;
-                clear_balances(&mut table.OUTPOINT_TO_RUNES.select(&key));
+                let mut ptr = table.OUTPOINT_TO_RUNES.select(&key);
+                journal::record_sheet(&ptr, &BalanceSheet::<IndexPointer>::default());
+                clear_balances(&mut ptr);
             }
         }
         Ok(())
@@ -30,6 +30,11 @@ pub struct RuneTable {
     pub RUNE_ID_TO_INITIALIZED: IndexPointer,
     pub INTERNAL_MINT: IndexPointer,
     pub TXID_TO_TXINDEX: IndexPointer,
+    pub LEDGER_BY_RUNE_ID: IndexPointer,
+    pub LEDGER_BY_HOLDER: IndexPointer,
+    pub HOLDERS: IndexPointer,
+    pub HOLDER_POSITION: IndexPointer,
+    pub SUPPLY: IndexPointer,
     /*
     pub HEIGHT_TO_BLOCKHASH: IndexPointer::from_keyword("/blockhash/byheight/"),
     pub BLOCKHASH_TO_HEIGHT: IndexPointer::from_keyword("/height/byblockhash/"),
@@ -82,6 +87,11 @@ impl RuneTable {
             RUNE_ID_TO_INITIALIZED: IndexPointer::from_keyword("/runes/null"),
             INTERNAL_MINT: IndexPointer::from_keyword("/runes/null"),
             TXID_TO_TXINDEX: IndexPointer::from_keyword("/txindex/byid"),
+            LEDGER_BY_RUNE_ID: IndexPointer::from_keyword("/runes/null"),
+            LEDGER_BY_HOLDER: IndexPointer::from_keyword("/runes/null"),
+            HOLDERS: IndexPointer::from_keyword("/runes/null"),
+            HOLDER_POSITION: IndexPointer::from_keyword("/runes/null"),
+            SUPPLY: IndexPointer::from_keyword("/runes/null"),
         }
     }
     pub fn for_protocol(tag: u128) -> Self {
@@ -129,6 +139,19 @@ impl RuneTable {
                 format!("/runes/proto/{tag}/mint/isinternal").as_str(),
             ),
             TXID_TO_TXINDEX: IndexPointer::from_keyword("/txindex/byid"),
+            LEDGER_BY_RUNE_ID: IndexPointer::from_keyword(
+                format!("/runes/proto/{tag}/ledger/byruneid/").as_str(),
+            ),
+            LEDGER_BY_HOLDER: IndexPointer::from_keyword(
+                format!("/runes/proto/{tag}/ledger/byholder/").as_str(),
+            ),
+            HOLDERS: IndexPointer::from_keyword(
+                format!("/runes/proto/{tag}/holders/byruneid/").as_str(),
+            ),
+            HOLDER_POSITION: IndexPointer::from_keyword(
+                format!("/runes/proto/{tag}/holders/position/").as_str(),
+            ),
+            SUPPLY: IndexPointer::from_keyword(format!("/runes/proto/{tag}/supply/").as_str()),
         }
     }
 }
@@ -0,0 +1,48 @@
+use alkanes::native::{dump_state, replay_directory, BlockFormat, ReplayOptions};
+use anyhow::{anyhow, Result};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+const USAGE: &str = "usage: alkanes-replay <block-dir> [--auxpow] [--height-prefixed] [--from <height>] [--to <height>] [--dump <file>]";
+
+fn parse_height(v: Option<String>) -> Result<u32> {
+    v.ok_or_else(|| anyhow!(USAGE))?
+        .parse::<u32>()
+        .map_err(|e| anyhow!("invalid height: {}", e))
+}
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let mut dir: Option<PathBuf> = None;
+    let mut dump: Option<PathBuf> = None;
+    let mut options = ReplayOptions::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--auxpow" => options.format = BlockFormat::Auxpow,
+            "--height-prefixed" => options.height_prefixed = true,
+            "--from" => options.from = Some(parse_height(args.next())?),
+            "--to" => options.to = Some(parse_height(args.next())?),
+            "--dump" => dump = Some(PathBuf::from(args.next().ok_or_else(|| anyhow!(USAGE))?)),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+            _ => return Err(anyhow!(USAGE)),
+        }
+    }
+    let dir = dir.ok_or_else(|| anyhow!(USAGE))?;
+    let summary = replay_directory(&dir, &options)?;
+    if let Some(height) = summary.missing {
+        eprintln!("no block file for height {}, stopping replay", height);
+    }
+    match summary.last {
+        Some(height) => eprintln!("indexed through height {}", height),
+        None => eprintln!("no block files found in {}", dir.display()),
+    }
+    if let Some(path) = dump {
+        dump_state(&mut BufWriter::new(File::create(path)?))?;
+    }
+    Ok(())
+}
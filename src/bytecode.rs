@@ -0,0 +1,130 @@
+use alkanes_support::{crypto::sha256, gz::decompress, id::AlkaneId};
+use anyhow::{anyhow, Result};
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::journal;
+use std::sync::Arc;
+
+// Bytecode is stored once under the sha256 of its uncompressed wasm, at
+// /alkanes-code/<hash>, and /alkanes/<id> holds CODE_POINTER_TAG followed by
+// that hash. Alkanes deployed before this layout hold their gzipped bytecode
+// directly until load_code first runs them. A gzip stream always opens with
+// 0x1f 0x8b, so the tag tells the two apart.
+
+pub const CODE_POINTER_TAG: u8 = 0x00;
+
+pub fn code_pointer(hash: &[u8]) -> IndexPointer {
+    IndexPointer::from_keyword("/alkanes-code/").select(&hash.to_vec())
+}
+
+// every alkane pointing at the code, in deployment order
+pub fn code_alkanes_pointer(hash: &[u8]) -> IndexPointer {
+    code_pointer(hash).keyword("/alkanes")
+}
+
+// what /alkanes/<id> holds to point at the code hashed as `hash`
+pub fn code_reference(hash: &[u8; 32]) -> Vec<u8> {
+    [&[CODE_POINTER_TAG], &hash[..]].concat()
+}
+
+fn as_code_hash(v: &[u8]) -> Option<[u8; 32]> {
+    match v.split_first() {
+        Some((&CODE_POINTER_TAG, hash)) => hash.try_into().ok(),
+        _ => None,
+    }
+}
+
+fn alkane_pointer<T: KeyValuePointer>(root: &T, id: &AlkaneId) -> T {
+    root.keyword("/alkanes/").select(&(*id).into())
+}
+
+// the gzipped bytecode of `id`, resolved through its code hash. `root` is the
+// pointer the alkanes table hangs off, so reads see uncommitted writes when it
+// is an AtomicPointer
+pub fn load_bytecode<T: KeyValuePointer>(root: &T, id: &AlkaneId) -> Arc<Vec<u8>> {
+    let stored = alkane_pointer(root, id).get();
+    match as_code_hash(&stored) {
+        Some(hash) => root.keyword("/alkanes-code/").select(&hash.to_vec()).get(),
+        None => stored,
+    }
+}
+
+// the code hash and gzipped bytecode of `id` from one read of its entry, or
+// None if nothing is deployed there. Bytecode stored before the hash layout
+// is inflated and hashed once, then moved under its hash so later loads read
+// the hash straight from the entry
+pub fn load_code<T: KeyValuePointer>(
+    root: &T,
+    id: &AlkaneId,
+) -> Result<Option<([u8; 32], Arc<Vec<u8>>)>> {
+    let stored = alkane_pointer(root, id).get();
+    if stored.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(match as_code_hash(&stored) {
+        Some(hash) => (
+            hash,
+            root.keyword("/alkanes-code/").select(&hash.to_vec()).get(),
+        ),
+        None => {
+            let hash = sha256(&decompress(stored.as_ref().clone())?);
+            point_at_code(root, id, stored.clone(), &hash);
+            (hash, stored)
+        }
+    }))
+}
+
+// the sha256 of the uncompressed wasm deployed at `id`, or None if nothing is
+pub fn code_hash<T: KeyValuePointer>(root: &T, id: &AlkaneId) -> Result<Option<[u8; 32]>> {
+    let stored = alkane_pointer(root, id).get();
+    if stored.is_empty() {
+        return Ok(None);
+    }
+    match as_code_hash(&stored) {
+        Some(hash) => Ok(Some(hash)),
+        None => Ok(Some(sha256(&decompress(stored.as_ref().clone())?))),
+    }
+}
+
+// points `id` at the code hashed as `hash`, writing the code itself only if
+// no alkane has stored it yet
+pub fn store_bytecode(
+    atomic: &mut AtomicPointer,
+    id: &AlkaneId,
+    compressed: Arc<Vec<u8>>,
+    hash: [u8; 32],
+) -> Result<()> {
+    if !alkane_pointer(atomic, id).get().is_empty() {
+        return Err(anyhow!("{:?} already holds a binary", id));
+    }
+    point_at_code(atomic, id, compressed, &hash);
+    Ok(())
+}
+
+fn point_at_code<T: KeyValuePointer>(
+    root: &T,
+    id: &AlkaneId,
+    compressed: Arc<Vec<u8>>,
+    hash: &[u8; 32],
+) {
+    let mut code = root.keyword("/alkanes-code/").select(&hash.to_vec());
+    if code.get().is_empty() {
+        journal::record(&code);
+        code.set(compressed);
+    }
+    let mut pointer = alkane_pointer(root, id);
+    journal::record(&pointer);
+    pointer.set(Arc::new(code_reference(hash)));
+    let alkanes = code.keyword("/alkanes");
+    journal::record_list(&alkanes);
+    alkanes.append(Arc::new((*id).into()));
+}
+
+// every alkane recorded against `hash`
+pub fn alkanes_with_code(hash: &[u8]) -> Result<Vec<AlkaneId>> {
+    code_alkanes_pointer(hash)
+        .get_list()
+        .into_iter()
+        .map(|v| v.as_ref().clone().try_into())
+        .collect()
+}
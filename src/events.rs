@@ -0,0 +1,92 @@
+use crate::tables::{EVENTS_BY_ALKANE, EVENTS_BY_TOPIC, EVENTS_BY_TXID};
+use crate::utils::lower_bound;
+use crate::view::page_limit;
+use alkanes_support::event::{requested_topic, AlkaneEvent};
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::Result;
+use bitcoin::hashes::Hash;
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::Message;
+use protorune::journal;
+use std::sync::Arc;
+
+// events are written through the atomic pointer of the frame that emitted
+// them, so a reverted call discards its events along with its storage
+pub fn save_event(atomic: &mut AtomicPointer, event: AlkaneEvent) -> Result<()> {
+    let txid = event.outpoint.txid.as_byte_array().to_vec();
+    let alkane: Vec<u8> = event.alkane.clone().into();
+    let mut topics = event.topics.clone();
+    topics.sort();
+    topics.dedup();
+    let bytes =
+        Arc::new(<AlkaneEvent as Into<proto::alkanes::AlkaneEvent>>::into(event).write_to_bytes()?);
+    journal::record_list(&EVENTS_BY_TXID.select(&txid));
+    atomic
+        .derive(&EVENTS_BY_TXID.select(&txid))
+        .append(bytes.clone());
+    journal::record_list(&EVENTS_BY_ALKANE.select(&alkane));
+    atomic
+        .derive(&EVENTS_BY_ALKANE.select(&alkane))
+        .append(bytes.clone());
+    for topic in topics {
+        let list = topic_pointer(&alkane, topic);
+        journal::record_list(&list);
+        atomic.derive(&list).append(bytes.clone());
+    }
+    Ok(())
+}
+
+fn topic_pointer(alkane: &Vec<u8>, topic: u128) -> IndexPointer {
+    EVENTS_BY_TOPIC
+        .select(alkane)
+        .select(&topic.to_le_bytes().to_vec())
+}
+
+fn decode_events(list: Vec<Arc<Vec<u8>>>) -> Result<Vec<proto::alkanes::AlkaneEvent>> {
+    list.into_iter()
+        .map(|v| Ok(proto::alkanes::AlkaneEvent::parse_from_bytes(v.as_ref())?))
+        .collect()
+}
+
+pub fn events_by_transaction(
+    req: &proto::alkanes::TransactionEventsRequest,
+) -> Result<proto::alkanes::EventsResponse> {
+    let mut result = proto::alkanes::EventsResponse::new();
+    result.events = decode_events(EVENTS_BY_TXID.select(&req.txid).get_list())?;
+    Ok(result)
+}
+
+// events emitted by an alkane, optionally narrowed to those carrying a topic
+// and to a height range, a page at a time. A to_height of 0 leaves the range
+// open-ended.
+pub fn events_by_alkane(
+    req: &proto::alkanes::EventsRequest,
+) -> Result<proto::alkanes::EventsResponse> {
+    let alkane: AlkaneId = req.alkane.clone().unwrap_or_default().into();
+    let alkane: Vec<u8> = alkane.into();
+    let list = match requested_topic(req) {
+        Some(topic) => topic_pointer(&alkane, topic),
+        None => EVENTS_BY_ALKANE.select(&alkane),
+    };
+    // both lists are in emission order, so the height range is a contiguous run
+    let height_of = |v: &Vec<u8>| -> Result<u64> {
+        Ok(proto::alkanes::AlkaneEvent::parse_from_bytes(v.as_ref())?.height)
+    };
+    let start = lower_bound(&list, req.from_height, height_of)?;
+    let end = match req.to_height {
+        0 => list.length(),
+        v => lower_bound(&list, v.saturating_add(1), height_of)?,
+    };
+    let mut result = proto::alkanes::EventsResponse::new();
+    result.total = end.saturating_sub(start) as u64;
+    let first = std::cmp::min((start as u64).saturating_add(req.offset), end as u64);
+    let last = std::cmp::min(end as u64, first + page_limit(req.limit));
+    result.events = decode_events(
+        (first..last)
+            .map(|i| list.select_index(i as u32).get())
+            .collect(),
+    )?;
+    Ok(result)
+}
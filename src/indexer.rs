@@ -1,5 +1,6 @@
 use crate::message::AlkaneMessageContext;
 use crate::network::{genesis, is_genesis};
+use crate::vm::cache;
 use crate::vm::fuel::FuelTank;
 use anyhow::Result;
 use bitcoin::blockdata::block::Block;
@@ -9,6 +10,7 @@ use metashrew_core::{
     stdio::{stdout, Write},
 };
 use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::journal;
 use protorune::message::MessageContext;
 use protorune::Protorune;
 use protorune_support::network::{set_network, NetworkParams};
@@ -82,15 +84,27 @@ use std::sync::Arc;
 
 pub fn index_block(block: &Block, height: u32) -> Result<()> {
     configure_network();
+    // a height we have already indexed is being replayed, so the chain reorged:
+    // undo everything journaled from that height up to the old tip first
+    if let Some(tip) = journal::tip() {
+        if (height as u64) <= tip {
+            journal::rollback(height.into())?;
+        }
+    }
     let really_is_genesis = is_genesis(height.into());
     if really_is_genesis {
         genesis(&block).unwrap();
     }
     FuelTank::initialize(&block);
+    cache::reset();
 
-    // Get the set of updated addresses from the indexing process
-    let updated_addresses =
-        Protorune::index_block::<AlkaneMessageContext>(block.clone(), height.into())?;
+    journal::begin(height.into());
+    // Get the set of updated addresses from the indexing process. The journal
+    // is closed before any error propagates, so a failed block never leaves
+    // it recording into the next one
+    let indexed = Protorune::index_block::<AlkaneMessageContext>(block.clone(), height.into());
+    journal::end();
+    let updated_addresses = indexed?;
 
     #[cfg(feature = "cache")]
     {
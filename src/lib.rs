@@ -1,5 +1,5 @@
 use crate::indexer::configure_network;
-use crate::view::{meta_safe, multi_simulate_safe, parcel_from_protobuf, simulate_safe};
+use crate::view::{meta_safe, multi_simulate_safe, parcel_from_protobuf};
 use alkanes_support::proto;
 use bitcoin::{Block, OutPoint};
 #[allow(unused_imports)]
@@ -16,11 +16,16 @@ use protobuf::{Message, MessageField};
 use std::io::Cursor;
 use view::parcels_from_protobuf;
 pub mod block;
+pub mod bytecode;
 pub mod etl;
+pub mod events;
 pub mod indexer;
 pub mod message;
+#[cfg(feature = "native")]
+pub mod native;
 pub mod network;
 pub mod precompiled;
+pub mod registry;
 pub mod tables;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod tests;
@@ -51,21 +56,29 @@ Then links everything together, leading to duplicate symbols
 Thus, going to add not(test) to all these functions
 */
 
+// Views from here on read the state as of the height their input is prefixed
+// with (see view::at_height). A default build keeps the last JOURNAL_DEPTH
+// blocks of history and errors on anything older. Indexers that serve
+// point-in-time reads further back are built with the `archive` feature
 #[cfg(not(test))]
 #[no_mangle]
 pub fn multisimluate() -> i32 {
     configure_network();
     let data = input();
-    let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
     let reader = &data[4..];
     let mut result: proto::alkanes::MultiSimulateResponse =
         proto::alkanes::MultiSimulateResponse::new();
-    let responses = multi_simulate_safe(
-        &parcels_from_protobuf(
-            proto::alkanes::MultiSimulateRequest::parse_from_bytes(reader).unwrap(),
-        ),
-        u64::MAX,
+    let parcels = parcels_from_protobuf(
+        proto::alkanes::MultiSimulateRequest::parse_from_bytes(reader).unwrap(),
     );
+    let responses = match view::at_height(height, || Ok(multi_simulate_safe(&parcels, u64::MAX))) {
+        Ok(v) => v,
+        Err(e) => {
+            result.error = e.to_string();
+            return export_bytes(result.write_to_bytes().unwrap());
+        }
+    };
 
     for response in responses {
         let mut res = proto::alkanes::SimulateResponse::new();
@@ -89,15 +102,13 @@ pub fn multisimluate() -> i32 {
 pub fn simulate() -> i32 {
     configure_network();
     let data = input();
-    let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
     let reader = &data[4..];
     let mut result: proto::alkanes::SimulateResponse = proto::alkanes::SimulateResponse::new();
-    match simulate_safe(
-        &parcel_from_protobuf(
-            proto::alkanes::MessageContextParcel::parse_from_bytes(reader).unwrap(),
-        ),
-        u64::MAX,
-    ) {
+    match proto::alkanes::MessageContextParcel::parse_from_bytes(reader)
+        .map_err(anyhow::Error::from)
+        .and_then(|parcel| view::at_height(height, || view::simulate_request(parcel)))
+    {
         Ok((response, gas_used)) => {
             result.execution = MessageField::some(response.into());
             result.gas_used = gas_used;
@@ -109,16 +120,53 @@ pub fn simulate() -> i32 {
     export_bytes(result.write_to_bytes().unwrap())
 }
 
+#[cfg(not(test))]
+#[no_mangle]
+pub fn simulatetransaction() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request = proto::alkanes::SimulateTransactionRequest::parse_from_bytes(
+        &consume_to_end(&mut data).unwrap(),
+    )
+    .unwrap();
+    let result =
+        view::at_height(height, || view::simulate_transaction(&request)).unwrap_or_else(|e| {
+            let mut response = proto::alkanes::SimulateTransactionResponse::new();
+            response.errors.push(e.to_string());
+            response
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn estimatefuel() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request =
+        proto::alkanes::EstimateFuelRequest::parse_from_bytes(&consume_to_end(&mut data).unwrap())
+            .unwrap();
+    let result = view::at_height(height, || view::estimate_fuel(&request)).unwrap_or_else(|e| {
+        let mut response = proto::alkanes::EstimateFuelResponse::new();
+        response.error = e.to_string();
+        response
+    });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
 #[cfg(not(test))]
 #[no_mangle]
 pub fn meta() -> i32 {
     configure_network();
     let data = input();
-    let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
     let reader = &data[4..];
-    match meta_safe(&parcel_from_protobuf(
+    let parcel = parcel_from_protobuf(
         proto::alkanes::MessageContextParcel::parse_from_bytes(reader).unwrap(),
-    )) {
+    );
+    match view::at_height(height, || meta_safe(&parcel)) {
         Ok(response) => export_bytes(response),
         Err(_) => export_bytes(vec![]),
     }
@@ -153,9 +201,10 @@ pub fn runesbyoutpoint() -> i32 {
 pub fn spendablesbyaddress() -> i32 {
   configure_network();
   let mut data: Cursor<Vec<u8>> = Cursor::new(input());
-  let _height = consume_sized_int::<u32>(&mut data).unwrap();
+  let height = consume_sized_int::<u32>(&mut data).unwrap();
+  let input_data = consume_to_end(&mut data).unwrap();
   let result: protorune_support::proto::protorune::WalletResponse =
-  view::protorunes_by_address(&consume_to_end(&mut data).unwrap())
+  view::at_height(height, || view::protorunes_by_address(&input_data))
     .unwrap_or_else(|_| protorune_support::proto::protorune::WalletResponse::new());
   export_bytes(result.write_to_bytes().unwrap())
 }
@@ -177,12 +226,12 @@ pub fn spendablesbyaddress() -> i32 {
 pub fn protorunesbyaddress() -> i32 {
   configure_network();
   let mut data: Cursor<Vec<u8>> = Cursor::new(input());
-  let _height = consume_sized_int::<u32>(&mut data).unwrap();
+  let height = consume_sized_int::<u32>(&mut data).unwrap();
   let input_data = consume_to_end(&mut data).unwrap();
   let request = protorune_support::proto::protorune::ProtorunesWalletRequest::parse_from_bytes(&input_data).unwrap();
 
   let mut result: protorune_support::proto::protorune::WalletResponse =
-  view::protorunes_by_address(&input_data)
+  view::at_height(height, || view::protorunes_by_address(&input_data))
     .unwrap_or_else(|_| protorune_support::proto::protorune::WalletResponse::new());
 
   result.outpoints = result
@@ -301,8 +350,112 @@ pub fn trace() -> i32 {
 pub fn getbytecode() -> i32 {
     configure_network();
     let mut data: Cursor<Vec<u8>> = Cursor::new(input());
-    let _height = consume_sized_int::<u32>(&mut data).unwrap();
-    export_bytes(view::getbytecode(&consume_to_end(&mut data).unwrap()).unwrap_or_default())
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let input_data = consume_to_end(&mut data).unwrap();
+    export_bytes(view::at_height(height, || view::getbytecode(&input_data)).unwrap_or_default())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn codehash() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let input_data = consume_to_end(&mut data).unwrap();
+    let request = proto::alkanes::CodeHashRequest::parse_from_bytes(&input_data).unwrap();
+    let result: proto::alkanes::CodeHashResponse =
+        view::at_height(height, || view::code_hash(&request))
+            .unwrap_or_else(|_| proto::alkanes::CodeHashResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn alkanesinventory() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let input_data = consume_to_end(&mut data).unwrap();
+    let request = proto::alkanes::AlkaneInventoryRequest::parse_from_bytes(&input_data).unwrap();
+    let result: proto::alkanes::AlkaneInventoryResponse =
+        view::at_height(height, || view::alkane_inventory(&request))
+            .unwrap_or_else(|_| proto::alkanes::AlkaneInventoryResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn alkanes() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request =
+        proto::alkanes::AlkanesRequest::parse_from_bytes(&consume_to_end(&mut data).unwrap())
+            .unwrap();
+    let result: proto::alkanes::AlkanesResponse =
+        view::at_height(height, || view::alkanes(&request))
+            .unwrap_or_else(|_| proto::alkanes::AlkanesResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn holders() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request =
+        proto::alkanes::HoldersRequest::parse_from_bytes(&consume_to_end(&mut data).unwrap())
+            .unwrap();
+    let result: proto::alkanes::HoldersResponse =
+        view::at_height(height, || view::holders(&request))
+            .unwrap_or_else(|_| proto::alkanes::HoldersResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn transferhistory() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request =
+        proto::alkanes::LedgerRequest::parse_from_bytes(&consume_to_end(&mut data).unwrap())
+            .unwrap();
+    let result: proto::alkanes::LedgerResponse = view::at_height(height, || view::ledger(&request))
+        .unwrap_or_else(|_| proto::alkanes::LedgerResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn alkanesevents() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request =
+        proto::alkanes::EventsRequest::parse_from_bytes(&consume_to_end(&mut data).unwrap())
+            .unwrap();
+    let result: proto::alkanes::EventsResponse =
+        view::at_height(height, || events::events_by_alkane(&request))
+            .unwrap_or_else(|_| proto::alkanes::EventsResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn transactionevents() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let request = proto::alkanes::TransactionEventsRequest::parse_from_bytes(
+        &consume_to_end(&mut data).unwrap(),
+    )
+    .unwrap();
+    let result: proto::alkanes::EventsResponse =
+        view::at_height(height, || events::events_by_transaction(&request))
+            .unwrap_or_else(|_| proto::alkanes::EventsResponse::new());
+    export_bytes(result.write_to_bytes().unwrap())
 }
 
 #[cfg(not(test))]
@@ -310,9 +463,10 @@ pub fn getbytecode() -> i32 {
 pub fn protorunesbyoutpoint() -> i32 {
     configure_network();
     let mut data: Cursor<Vec<u8>> = Cursor::new(input());
-    let _height = consume_sized_int::<u32>(&mut data).unwrap();
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    let input_data = consume_to_end(&mut data).unwrap();
     let result: protorune_support::proto::protorune::OutpointResponse =
-        view::protorunes_by_outpoint(&consume_to_end(&mut data).unwrap())
+        view::at_height(height, || view::protorunes_by_outpoint(&input_data))
             .unwrap_or_else(|_| protorune_support::proto::protorune::OutpointResponse::new());
 
     export_bytes(result.write_to_bytes().unwrap())
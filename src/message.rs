@@ -1,6 +1,8 @@
 use crate::network::{genesis::GENESIS_BLOCK, is_active};
 use crate::trace::save_trace;
-use crate::utils::{credit_balances, debit_balances, pipe_storagemap_to};
+use crate::utils::{
+    credit_balances, debit_balances, ledger_origin, merge_storage_diff, pipe_storagemap_to,
+};
 use crate::vm::{
     fuel::{FuelTank, VirtualFuelBytes},
     runtime::AlkanesRuntimeContext,
@@ -66,7 +68,14 @@ pub fn handle_message(
         parcel, &cellpack,
     )));
     let mut atomic = parcel.atomic.derive(&IndexPointer::default());
-    let (caller, myself, binary) = run_special_cellpacks(context.clone(), &cellpack)?;
+    let (caller, myself, binary) = match run_special_cellpacks(context.clone(), &cellpack) {
+        Ok(v) => v,
+        Err(e) => {
+            // a deploy rejected before it runs still leaves a trace behind
+            save_revert(parcel, &context, &e)?;
+            return Err(e);
+        }
+    };
 
     #[cfg(feature = "debug-log")]
     {
@@ -78,7 +87,7 @@ pub fn handle_message(
         );
     }
 
-    credit_balances(&mut atomic, &myself, &parcel.runes);
+    credit_balances(&mut atomic, &myself, &parcel.runes, &ledger_origin(parcel))?;
     prepare_context(context.clone(), &caller, &myself, false);
     let txsize = parcel.transaction.vfsize() as u64;
     if FuelTank::is_top() {
@@ -98,10 +107,13 @@ pub fn handle_message(
     run_after_special(context.clone(), binary, fuel)
         .and_then(|(response, gas_used)| {
             FuelTank::consume_fuel(gas_used)?;
-            pipe_storagemap_to(
-                &response.storage,
-                &mut atomic.derive(
-                    &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
+            let storage_diff = merge_storage_diff(
+                &context.lock().unwrap().storage_diff,
+                pipe_storagemap_to(
+                    &response.storage,
+                    &mut atomic.derive(
+                        &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
+                    ),
                 ),
             );
             let mut combined = parcel.runtime_balances.as_ref().clone();
@@ -111,12 +123,18 @@ pub fn handle_message(
                 response.alkanes.clone().into(),
             );
             combined.debit_mintable(&sheet, &mut atomic)?;
-            debit_balances(&mut atomic, &myself, &response.alkanes)?;
+            debit_balances(
+                &mut atomic,
+                &myself,
+                &response.alkanes,
+                &ledger_origin(parcel),
+            )?;
             let cloned = context.clone().lock().unwrap().trace.clone();
             let response_alkanes = response.alkanes.clone();
             cloned.clock(TraceEvent::ReturnContext(TraceResponse {
                 inner: response.into(),
                 fuel_used: gas_used,
+                storage_diff,
             }));
             save_trace(
                 &OutPoint {
@@ -163,27 +181,36 @@ pub fn handle_message(
             }
 
             FuelTank::drain_fuel();
-            let mut response = ExtendedCallResponse::default();
-
-            response.data = vec![0x08, 0xc3, 0x79, 0xa0];
-            response.data.extend(e.to_string().as_bytes());
-            let cloned = context.clone().lock().unwrap().trace.clone();
-            cloned.clock(TraceEvent::RevertContext(TraceResponse {
-                inner: response,
-                fuel_used: u64::MAX,
-            }));
-            save_trace(
-                &OutPoint {
-                    txid: parcel.transaction.compute_txid(),
-                    vout: parcel.vout,
-                },
-                parcel.height,
-                cloned,
-            )?;
+            save_revert(parcel, &context, &e)?;
             Err(e)
         })
 }
 
+fn save_revert(
+    parcel: &MessageContextParcel,
+    context: &Arc<Mutex<AlkanesRuntimeContext>>,
+    e: &anyhow::Error,
+) -> Result<()> {
+    let mut response = ExtendedCallResponse::default();
+
+    response.data = vec![0x08, 0xc3, 0x79, 0xa0];
+    response.data.extend(e.to_string().as_bytes());
+    let cloned = context.clone().lock().unwrap().trace.clone();
+    cloned.clock(TraceEvent::RevertContext(TraceResponse {
+        inner: response,
+        fuel_used: u64::MAX,
+        storage_diff: vec![],
+    }));
+    save_trace(
+        &OutPoint {
+            txid: parcel.transaction.compute_txid(),
+            vout: parcel.vout,
+        },
+        parcel.height,
+        cloned,
+    )
+}
+
 impl MessageContext for AlkaneMessageContext {
     fn protocol_tag() -> u128 {
         1
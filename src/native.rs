@@ -0,0 +1,266 @@
+use crate::etl;
+use crate::indexer::index_block;
+use anyhow::{anyhow, Result};
+use bitcoin::Block;
+use metashrew_core::{flush, get_cache};
+use metashrew_support::block::AuxpowBlock;
+use metashrew_support::utils::consensus_decode;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+// Native replay harness. Built with the `native` feature, which swaps the
+// metashrew host imports for metashrew-core's in-process store, so
+// `index_block` can be driven outside of a metashrew server.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BlockFormat {
+    #[default]
+    Raw,
+    Auxpow,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ReplayOptions {
+    pub format: BlockFormat,
+    // files carry a little-endian u32 height ahead of the block, the same
+    // layout metashrew hands to `_start`
+    pub height_prefixed: bool,
+    pub from: Option<u32>,
+    pub to: Option<u32>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockFile {
+    pub height: u32,
+    pub path: PathBuf,
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    let data = fs::read(path)?;
+    if path.extension().map(|v| v == "hex").unwrap_or(false) {
+        Ok(hex::decode(String::from_utf8(data)?.trim())?)
+    } else {
+        Ok(data)
+    }
+}
+
+pub fn decode_block(data: &[u8], format: BlockFormat) -> Result<Block> {
+    match format {
+        BlockFormat::Raw => consensus_decode::<Block>(&mut Cursor::<Vec<u8>>::new(data.to_vec())),
+        BlockFormat::Auxpow => {
+            Ok(AuxpowBlock::parse(&mut Cursor::<Vec<u8>>::new(data.to_vec()))?.to_consensus())
+        }
+    }
+}
+
+// reads the height and the block bytes out of a block file
+pub fn load_block_file(path: &Path, options: &ReplayOptions) -> Result<(u32, Vec<u8>)> {
+    let data = read_file(path)?;
+    if options.height_prefixed {
+        if data.len() < 4 {
+            return Err(anyhow!("{}: missing height prefix", path.display()));
+        }
+        let height = u32::from_le_bytes((&data[0..4]).try_into()?);
+        Ok((height, data[4..].to_vec()))
+    } else {
+        Ok((height_from_path(path)?, data))
+    }
+}
+
+fn height_from_path(path: &Path) -> Result<u32> {
+    path.file_stem()
+        .and_then(|v| v.to_str())
+        .and_then(|v| v.parse::<u32>().ok())
+        .ok_or_else(|| anyhow!("{}: file name is not a block height", path.display()))
+}
+
+// lists the block files in a directory in height order, filtered to the
+// requested range
+pub fn list_block_files(dir: &Path, options: &ReplayOptions) -> Result<Vec<BlockFile>> {
+    let mut files = Vec::<BlockFile>::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let height = if options.height_prefixed {
+            load_block_file(&path, options)?.0
+        } else {
+            match height_from_path(&path) {
+                Ok(v) => v,
+                Err(_) => continue,
+            }
+        };
+        if options.from.map(|v| height < v).unwrap_or(false)
+            || options.to.map(|v| height > v).unwrap_or(false)
+        {
+            continue;
+        }
+        files.push(BlockFile { height, path });
+    }
+    files.sort_by_key(|v| v.height);
+    Ok(files)
+}
+
+// indexes a single block exactly as `_start` would
+pub fn replay_block(block: &Block, height: u32) -> Result<()> {
+    index_block(block, height)?;
+    etl::index_extensions(height, block);
+    flush();
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplaySummary {
+    // last height indexed, if any
+    pub last: Option<u32>,
+    // height with no block file that replay stopped at, if it stopped early
+    pub missing: Option<u32>,
+}
+
+// replays the block files in `dir` in height order. Replay stops at the first
+// gap in heights, since indexing past a missing block would leave state no
+// real chain could reach
+pub fn replay_directory(dir: &Path, options: &ReplayOptions) -> Result<ReplaySummary> {
+    let mut summary = ReplaySummary::default();
+    for file in list_block_files(dir, options)? {
+        if let Some(previous) = summary.last {
+            if file.height > previous + 1 {
+                summary.missing = Some(previous + 1);
+                break;
+            }
+        }
+        let (height, data) = load_block_file(&file.path, options)?;
+        let block = decode_block(&data, options.format)
+            .map_err(|e| anyhow!("{}: {}", file.path.display(), e))?;
+        replay_block(&block, height)
+            .map_err(|e| anyhow!("failed to index block {}: {}", height, e))?;
+        summary.last = Some(height);
+    }
+    Ok(summary)
+}
+
+// every non-empty key in the in-process store, sorted by key, so two replays
+// can be compared with a plain diff
+pub fn state_entries() -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut entries = get_cache()
+        .iter()
+        .filter(|(_k, v)| v.len() != 0)
+        .map(|(k, v)| (k.as_ref().clone(), v.as_ref().clone()))
+        .collect::<Vec<(Vec<u8>, Vec<u8>)>>();
+    entries.sort();
+    entries
+}
+
+pub fn dump_state<W: std::io::Write>(writer: &mut W) -> Result<()> {
+    for (k, v) in state_entries() {
+        writeln!(writer, "{} {}", hex::encode(k), hex::encode(v))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::clear;
+    use bitcoin::consensus::serialize;
+    use protorune::journal;
+    use protorune::test_helpers::create_block_with_coinbase_tx;
+    use std::sync::Mutex;
+
+    // the in-process store is shared, so tests that index run one at a time
+    static STORE: Mutex<()> = Mutex::new(());
+
+    fn block_dir(name: &str, heights: &[u32]) -> Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!("alkanes-replay-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir)?;
+        for height in heights {
+            let mut data = height.to_le_bytes().to_vec();
+            data.extend(serialize(&create_block_with_coinbase_tx(*height)));
+            // names carry no height, so ordering has to come from the prefix
+            fs::write(dir.join(format!("block-{}.bin", u32::MAX - height)), data)?;
+        }
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_load_height_prefixed_block_file() -> Result<()> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/tests/static/849236.txt");
+        let options = ReplayOptions {
+            height_prefixed: true,
+            ..Default::default()
+        };
+        let (height, data) = load_block_file(&path, &options)?;
+        let block = decode_block(&data, options.format)?;
+        assert_eq!(
+            height,
+            u32::from_le_bytes(fs::read(&path)?[0..4].try_into()?)
+        );
+        assert!(block.txdata.len() > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_directory_stops_at_gap() -> Result<()> {
+        let _store = STORE.lock().unwrap();
+        clear();
+        let dir = block_dir("gap", &[840_001, 840_000, 840_003])?;
+        let options = ReplayOptions {
+            height_prefixed: true,
+            ..Default::default()
+        };
+        let files = list_block_files(&dir, &options)?;
+        assert_eq!(
+            files.iter().map(|v| v.height).collect::<Vec<u32>>(),
+            vec![840_000, 840_001, 840_003]
+        );
+        assert_eq!(
+            replay_directory(&dir, &options)?,
+            ReplaySummary {
+                last: Some(840_001),
+                missing: Some(840_002),
+            }
+        );
+        assert_eq!(journal::tip(), Some(840_001));
+
+        let options = ReplayOptions {
+            height_prefixed: true,
+            from: Some(840_003),
+            ..Default::default()
+        };
+        assert_eq!(list_block_files(&dir, &options)?.len(), 1);
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_block_and_dump_state() -> Result<()> {
+        let _store = STORE.lock().unwrap();
+        clear();
+        replay_block(&create_block_with_coinbase_tx(840_000), 840_000)?;
+        assert_eq!(journal::tip(), Some(840_000));
+
+        let entries = state_entries();
+        assert!(entries.len() > 0);
+        assert!(entries.windows(2).all(|v| v[0].0 < v[1].0));
+        let mut dump = Vec::<u8>::new();
+        dump_state(&mut dump)?;
+        let lines = String::from_utf8(dump)?
+            .lines()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(lines.len(), entries.len());
+        assert_eq!(
+            lines[0],
+            format!(
+                "{} {}",
+                hex::encode(&entries[0].0),
+                hex::encode(&entries[0].1)
+            )
+        );
+        Ok(())
+    }
+}
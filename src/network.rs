@@ -1,13 +1,16 @@
+use crate::bytecode::store_bytecode;
 use crate::message::AlkaneMessageContext;
 use crate::precompiled::{
     alkanes_std_genesis_alkane_dogecoin_build, alkanes_std_genesis_alkane_fractal_build,
     alkanes_std_genesis_alkane_luckycoin_build, alkanes_std_genesis_alkane_mainnet_build,
     alkanes_std_genesis_alkane_regtest_build,
 };
+use crate::registry::{save_alkane, AlkaneRecord, CreationKind};
 use crate::utils::pipe_storagemap_to;
 use crate::view::simulate_parcel;
 use crate::vm::utils::sequence_pointer;
 use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::sha256;
 use alkanes_support::gz::compress;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransferParcel;
@@ -17,6 +20,7 @@ use bitcoin::{Block, OutPoint, Transaction, Txid};
 use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 use metashrew_support::index_pointer::KeyValuePointer;
 use protorune::balance_sheet::PersistentRecord;
+use protorune::ledger::{self, LedgerOrigin};
 use protorune::message::{MessageContext, MessageContextParcel};
 #[allow(unused_imports)]
 use protorune::tables::{RuneTable, RUNES};
@@ -80,6 +84,18 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "3977b30a97c9b9d609afb4b7cc138e17b21d1e0c5e360d25debf1441de933bf4";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 0;
+    // height from which deployed binaries are validated before they are
+    // stored. Networks that have not scheduled it yet leave it at u64::MAX
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = 0;
+    // height from which the crypto host functions are linked, through
+    // FUEL_SCHEDULE_V1. Likewise u64::MAX where it is not scheduled
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = 0;
+    // height from which __emit_event is linked, through FUEL_SCHEDULE_V2. It
+    // must not come before CRYPTO_ACTIVATION_HEIGHT, as V2 builds on V1
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = 0;
+    // height from which host functions deduct their cost, through
+    // FUEL_SCHEDULE_V3. It must not come before EVENTS_ACTIVATION_HEIGHT
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = 0;
 }
 
 #[cfg(feature = "mainnet")]
@@ -88,6 +104,10 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "3977b30a97c9b9d609afb4b7cc138e17b21d1e0c5e360d25debf1441de933bf4";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 872_101;
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = u64::MAX;
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = u64::MAX;
 }
 
 #[cfg(feature = "fractal")]
@@ -96,6 +116,10 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "cf2b52ffaaf1c094df22f190b888fb0e474fe62990547a34e144ec9f8e135b07";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 228_194;
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = u64::MAX;
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = u64::MAX;
 }
 
 #[cfg(feature = "dogecoin")]
@@ -104,6 +128,10 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "cf2b52ffaaf1c094df22f190b888fb0e474fe62990547a34e144ec9f8e135b07";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 872_101;
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = u64::MAX;
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = u64::MAX;
 }
 
 #[cfg(feature = "luckycoin")]
@@ -112,6 +140,10 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "cf2b52ffaaf1c094df22f190b888fb0e474fe62990547a34e144ec9f8e135b07";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 872_101;
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = u64::MAX;
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = u64::MAX;
 }
 
 #[cfg(feature = "bellscoin")]
@@ -120,6 +152,10 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT: &str =
         "2c58484a86e117a445c547d8f3acb56b569f7ea036637d909224d52a5b990259";
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 288_906;
+    pub const DEPLOY_VALIDATION_HEIGHT: u64 = u64::MAX;
+    pub const CRYPTO_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const EVENTS_ACTIVATION_HEIGHT: u64 = u64::MAX;
+    pub const HOST_FUEL_ACTIVATION_HEIGHT: u64 = u64::MAX;
 }
 
 pub fn is_active(height: u64) -> bool {
@@ -154,12 +190,13 @@ pub fn is_genesis(height: u64) -> bool {
 }
 
 pub fn genesis(block: &Block) -> Result<()> {
-    IndexPointer::from_keyword("/alkanes/")
-        .select(&(AlkaneId { block: 2, tx: 0 }).into())
-        .set(Arc::new(compress(genesis_alkane_bytes())?));
     let mut atomic: AtomicPointer = AtomicPointer::default();
-    sequence_pointer(&atomic).set_value::<u128>(1);
     let myself = AlkaneId { block: 2, tx: 0 };
+    // stored by code hash like any other deploy
+    let wasm = genesis_alkane_bytes();
+    let hash = sha256(&wasm);
+    store_bytecode(&mut atomic, &myself, Arc::new(compress(wasm)?), hash)?;
+    sequence_pointer(&atomic).set_value::<u128>(1);
     let parcel = MessageContextParcel {
         atomic: atomic.derive(&IndexPointer::default()),
         runes: vec![],
@@ -190,7 +227,7 @@ pub fn genesis(block: &Block) -> Result<()> {
             Err(e)
         }
     })?;
-    let outpoint_bytes = outpoint_encode(&OutPoint {
+    let outpoint = OutPoint {
         txid: Txid::from_byte_array(
             <Vec<u8> as AsRef<[u8]>>::as_ref(
                 &hex::decode(genesis::GENESIS_OUTPOINT)?
@@ -202,16 +239,39 @@ pub fn genesis(block: &Block) -> Result<()> {
             .try_into()?,
         ),
         vout: 0,
-    })?;
-    <AlkaneTransferParcel as Into<BalanceSheet<AtomicPointer>>>::into(response.alkanes.into())
-        .save(
-            &mut atomic.derive(
-                &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
-                    .OUTPOINT_TO_RUNES
-                    .select(&outpoint_bytes),
-            ),
-            false,
-        );
+    };
+    // 2:0 has no deploy transaction. Its record carries the genesis outpoint,
+    // where its balance is credited
+    save_alkane(
+        &mut atomic,
+        AlkaneRecord {
+            id: myself,
+            height: genesis::GENESIS_BLOCK,
+            txid: outpoint.txid,
+            kind: CreationKind::Create,
+            code_hash: hash,
+        },
+    )?;
+    let outpoint_bytes = outpoint_encode(&outpoint)?;
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let sheet =
+        <AlkaneTransferParcel as Into<BalanceSheet<AtomicPointer>>>::into(response.alkanes.into());
+    sheet.save(
+        &mut atomic.derive(&table.OUTPOINT_TO_RUNES.select(&outpoint_bytes)),
+        false,
+    );
+    ledger::append_sheet(
+        &mut atomic,
+        &table,
+        &sheet,
+        &outpoint,
+        &LedgerOrigin {
+            txid: outpoint.txid,
+            vout: outpoint.vout,
+            height: genesis::GENESIS_OUTPOINT_BLOCK_HEIGHT,
+        },
+        false,
+    )?;
     pipe_storagemap_to(
         &response.storage,
         &mut atomic.derive(&IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into())),
@@ -0,0 +1,98 @@
+use crate::tables::{ALKANES_BY_TEMPLATE, ALKANES_REGISTRY};
+use crate::utils::lower_bound;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::Result;
+use bitcoin::hashes::Hash;
+use bitcoin::Txid;
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::{EnumOrUnknown, Message, MessageField};
+use protorune::journal;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreationKind {
+    Create,
+    Reserved,
+    // a clone of the alkane at the wrapped id
+    Factory(AlkaneId),
+}
+
+#[derive(Clone, Debug)]
+pub struct AlkaneRecord {
+    pub id: AlkaneId,
+    pub height: u64,
+    pub txid: Txid,
+    pub kind: CreationKind,
+    pub code_hash: [u8; 32],
+}
+
+impl Into<proto::alkanes::AlkaneRecord> for AlkaneRecord {
+    fn into(self) -> proto::alkanes::AlkaneRecord {
+        let mut result = proto::alkanes::AlkaneRecord::new();
+        result.id = MessageField::some(self.id.into());
+        result.height = self.height;
+        result.txid = self.txid.as_byte_array().to_vec();
+        result.kind = EnumOrUnknown::new(match self.kind {
+            CreationKind::Create => proto::alkanes::AlkaneCreationKind::CREATE,
+            CreationKind::Reserved => proto::alkanes::AlkaneCreationKind::RESERVED,
+            CreationKind::Factory(template) => {
+                result.template = MessageField::some(template.into());
+                proto::alkanes::AlkaneCreationKind::FACTORY
+            }
+        });
+        result.code_hash = self.code_hash.to_vec();
+        result
+    }
+}
+
+// written through the atomic pointer of the creating frame, so a creation
+// that reverts leaves no record, as it leaves no bytecode
+pub fn save_alkane(atomic: &mut AtomicPointer, record: AlkaneRecord) -> Result<()> {
+    let template = match record.kind {
+        CreationKind::Factory(template) => Some(template),
+        _ => None,
+    };
+    let bytes = Arc::new(
+        <AlkaneRecord as Into<proto::alkanes::AlkaneRecord>>::into(record).write_to_bytes()?,
+    );
+    journal::record_list(&ALKANES_REGISTRY);
+    atomic.derive(&ALKANES_REGISTRY).append(bytes.clone());
+    if let Some(template) = template {
+        let list = ALKANES_BY_TEMPLATE.select(&template.into());
+        journal::record_list(&list);
+        atomic.derive(&list).append(bytes);
+    }
+    Ok(())
+}
+
+// records in creation order, narrowed to a height range, then paged. The
+// matching count before paging is returned alongside. Creation order is height
+// order, so the range is found by binary search rather than a scan
+pub fn page(
+    list: &IndexPointer,
+    from_height: u64,
+    to_height: u64,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<proto::alkanes::AlkaneRecord>, u64)> {
+    let height_of = |v: &Vec<u8>| -> Result<u64> {
+        Ok(proto::alkanes::AlkaneRecord::parse_from_bytes(v.as_ref())?.height)
+    };
+    let start = lower_bound(list, from_height, height_of)?;
+    let end = match to_height {
+        0 => list.length(),
+        v => lower_bound(list, v.saturating_add(1), height_of)?,
+    };
+    let first = std::cmp::min((start as u64).saturating_add(offset), end as u64);
+    let last = std::cmp::min(end as u64, first.saturating_add(limit));
+    let records = (first..last)
+        .map(|i| {
+            Ok(proto::alkanes::AlkaneRecord::parse_from_bytes(
+                list.select_index(i as u32).get().as_ref(),
+            )?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((records, end.saturating_sub(start) as u64))
+}
@@ -6,3 +6,23 @@ pub static TRACES: Lazy<IndexPointer> = Lazy::new(|| IndexPointer::from_keyword(
 
 pub static TRACES_BY_HEIGHT: Lazy<IndexPointer> =
     Lazy::new(|| IndexPointer::from_keyword("/trace/"));
+
+// events emitted by alkanes, listed once under the emitting transaction and
+// once under the emitting alkane, in the order they were emitted
+pub static EVENTS_BY_TXID: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/bytxid/"));
+
+pub static EVENTS_BY_ALKANE: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/byalkane/"));
+
+// and once more under the emitting alkane for each distinct topic it carries
+pub static EVENTS_BY_TOPIC: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/bytopic/"));
+
+// every alkane created, in creation order, and clones again under the
+// alkane they were cloned from
+pub static ALKANES_REGISTRY: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/registry/alkanes"));
+
+pub static ALKANES_BY_TEMPLATE: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/registry/bytemplate/"));
@@ -6,10 +6,14 @@ use crate::tests::std::alkanes_std_genesis_alkane_build;
 use crate::tests::std::alkanes_std_genesis_protorune_build;
 use crate::tests::std::alkanes_std_merkle_distributor_build;
 use crate::tests::std::alkanes_std_orbital_build;
+use crate::tests::std::alkanes_std_orbital_collection_build;
+use crate::tests::std::alkanes_std_otc_build;
 use crate::tests::std::alkanes_std_owned_token_build;
 use crate::tests::std::alkanes_std_proxy_build;
+use crate::tests::std::alkanes_std_refunder_build;
 use crate::tests::std::alkanes_std_test_build;
 use crate::tests::std::alkanes_std_upgradeable_build;
+use crate::tests::std::alkanes_std_vesting_build;
 use crate::view::meta_safe;
 use crate::vm::fuel::FuelTank;
 use crate::vm::instance::AlkanesInstance;
@@ -326,7 +330,20 @@ fn test_logger_alkane_abi() -> Result<()> {
         ("mint_tokens", 4, vec![], "void"),
         ("return_data_1", 5, vec![], "Vec<u8>"),
         ("get_transaction", 50, vec![], "void"),
+        ("emit_event", 30, vec![], "void"),
+        ("emit_event_and_revert", 31, vec![], "void"),
         ("hash_loop", 78, vec![], "void"),
+        ("hash_precompiles", 79, vec![], "Vec<u8>"),
+        (
+            "verify_schnorr",
+            80,
+            vec![
+                ("pubkey", "Vec<u128>"),
+                ("message", "Vec<u128>"),
+                ("signature", "Vec<u128>"),
+            ],
+            "Vec<u8>",
+        ),
         ("return_default_data", 99, vec![], "Vec<u8>"),
         (
             "process_numbers",
@@ -363,9 +380,12 @@ fn test_orbital_abi() -> Result<()> {
     // Expected methods with their opcodes, parameter names and types, and return types
     let expected_methods = vec![
         ("initialize", 0, vec![], "void"),
+        ("initialize_item", 1, vec![("index", "u128")], "void"),
         ("get_name", 99, vec![], "String"),
         ("get_symbol", 100, vec![], "String"),
         ("get_total_supply", 101, vec![], "u128"),
+        ("get_index", 102, vec![], "u128"),
+        ("get_collection", 103, vec![], "Vec<u8>"),
         ("get_data", 1000, vec![], "Vec<u8>"),
     ];
 
@@ -376,6 +396,54 @@ fn test_orbital_abi() -> Result<()> {
     )
 }
 
+#[wasm_bindgen_test]
+fn test_orbital_collection_abi() -> Result<()> {
+    clear();
+
+    // Expected methods with their opcodes, parameter names and types, and return types
+    let expected_methods = vec![
+        (
+            "initialize",
+            0,
+            vec![
+                ("name", "u128"),
+                ("symbol", "u128"),
+                ("max_supply", "u128"),
+                ("template_block", "u128"),
+                ("template_tx", "u128"),
+                ("price_block", "u128"),
+                ("price_tx", "u128"),
+                ("price_amount", "u128"),
+                ("auth_token_units", "u128"),
+            ],
+            "void",
+        ),
+        ("set_item_data", 1, vec![("index", "u128")], "void"),
+        ("withdraw", 2, vec![], "void"),
+        ("mint", 77, vec![], "void"),
+        ("get_name", 99, vec![], "String"),
+        ("get_symbol", 100, vec![], "String"),
+        ("get_total_supply", 101, vec![], "u128"),
+        ("get_max_supply", 102, vec![], "u128"),
+        ("get_item", 103, vec![("index", "u128")], "Vec<u8>"),
+        (
+            "get_items",
+            104,
+            vec![("offset", "u128"), ("limit", "u128")],
+            "Vec<u8>",
+        ),
+        ("get_item_data", 105, vec![("index", "u128")], "Vec<u8>"),
+        ("get_mint_price", 106, vec![], "Vec<u8>"),
+        ("get_template", 107, vec![], "Vec<u8>"),
+    ];
+
+    test_contract_abi(
+        "OrbitalCollection",
+        alkanes_std_orbital_collection_build::get_bytes(),
+        expected_methods,
+    )
+}
+
 #[wasm_bindgen_test]
 fn test_merkle_distributor_abi() -> Result<()> {
     clear();
@@ -389,6 +457,7 @@ fn test_merkle_distributor_abi() -> Result<()> {
             "void",
         ),
         ("claim", 1, vec![], "void"),
+        ("is_claimed", 2, vec![("index", "u128")], "u128"),
     ];
 
     test_contract_abi(
@@ -398,6 +467,89 @@ fn test_merkle_distributor_abi() -> Result<()> {
     )
 }
 
+#[wasm_bindgen_test]
+fn test_refunder_abi() -> Result<()> {
+    clear();
+
+    // Expected methods with their opcodes, parameter names and types, and return types
+    let expected_methods = vec![
+        ("initialize", 0, vec![("auth_token_units", "u128")], "void"),
+        ("refund", 50, vec![], "void"),
+        ("escrow", 51, vec![], "void"),
+        (
+            "recover",
+            52,
+            vec![("block", "u128"), ("tx", "u128"), ("amount", "u128")],
+            "void",
+        ),
+        ("get_escrowed", 1000, vec![], "Vec<u8>"),
+    ];
+
+    test_contract_abi(
+        "Refunder",
+        alkanes_std_refunder_build::get_bytes(),
+        expected_methods,
+    )
+}
+
+#[wasm_bindgen_test]
+fn test_vesting_abi() -> Result<()> {
+    clear();
+
+    // Expected methods with their opcodes, parameter names and types, and return types
+    let expected_methods = vec![
+        (
+            "initialize",
+            0,
+            vec![
+                ("start_height", "u128"),
+                ("cliff_height", "u128"),
+                ("end_height", "u128"),
+                ("revocable", "u128"),
+            ],
+            "void",
+        ),
+        ("claim", 1, vec![], "void"),
+        ("revoke", 2, vec![], "void"),
+        ("get_vested", 100, vec![], "u128"),
+        ("get_claimed", 101, vec![], "u128"),
+        ("get_remaining", 102, vec![], "u128"),
+        ("get_claimable", 103, vec![], "u128"),
+        ("get_schedule", 104, vec![], "Vec<u8>"),
+    ];
+
+    test_contract_abi(
+        "Vesting",
+        alkanes_std_vesting_build::get_bytes(),
+        expected_methods,
+    )
+}
+
+#[wasm_bindgen_test]
+fn test_otc_abi() -> Result<()> {
+    clear();
+
+    // Expected methods with their opcodes, parameter names and types, and return types
+    let expected_methods = vec![
+        (
+            "list",
+            1,
+            vec![
+                ("ask_sats", "u128"),
+                ("script_length", "u128"),
+                ("script", "Vec<u128>"),
+            ],
+            "u128",
+        ),
+        ("buy", 2, vec![("listing", "u128")], "void"),
+        ("cancel", 3, vec![("listing", "u128")], "void"),
+        ("get_listing", 100, vec![("listing", "u128")], "Vec<u8>"),
+        ("get_listing_count", 101, vec![], "u128"),
+    ];
+
+    test_contract_abi("Otc", alkanes_std_otc_build::get_bytes(), expected_methods)
+}
+
 #[wasm_bindgen_test]
 fn test_genesis_alkane_abi() -> Result<()> {
     clear();
@@ -0,0 +1,402 @@
+use crate::bytecode::load_bytecode;
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers, assert_binary_deployed_to_id};
+use crate::tests::std::{
+    alkanes_std_amm_factory_build, alkanes_std_amm_pool_build, alkanes_std_auth_token_build,
+    alkanes_std_owned_token_build,
+};
+use crate::view::call_view;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::{AMM_FACTORY_ID, AUTH_TOKEN_FACTORY_ID};
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use bitcoin::{OutPoint, Transaction};
+use metashrew_core::index_pointer::IndexPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::BalanceSheetOperations;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const AMM_POOL_ID: u128 = 0xfff0;
+const FUEL: u64 = 100_000_000;
+
+const TOKEN_A: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const TOKEN_B: AlkaneId = AlkaneId { block: 2, tx: 3 };
+const POOL: AlkaneId = AlkaneId { block: 2, tx: 6 };
+const FACTORY: AlkaneId = AlkaneId {
+    block: 4,
+    tx: AMM_FACTORY_ID,
+};
+
+// a 500_000/500_000 pool of TOKEN_A and TOKEN_B at POOL; the returned block's
+// last transaction holds the 499_000 LP tokens minted for it, the other
+// 500_000 of each asset and every auth token, the factory's included
+fn deploy() -> Result<bitcoin::Block> {
+    let cellpacks: Vec<Cellpack> = vec![
+        Cellpack {
+            target: AlkaneId {
+                block: 3,
+                tx: AUTH_TOKEN_FACTORY_ID,
+            },
+            inputs: vec![100],
+        },
+        // token a at 2:1, its auth token at 2:2
+        Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![0, 1, 1_000_000],
+        },
+        // token b at 2:3, its auth token at 2:4
+        Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![0, 1, 1_000_000],
+        },
+        Cellpack {
+            target: AlkaneId {
+                block: 3,
+                tx: AMM_POOL_ID,
+            },
+            inputs: vec![99],
+        },
+        // factory auth token at 2:5
+        Cellpack {
+            target: AlkaneId {
+                block: 3,
+                tx: AMM_FACTORY_ID,
+            },
+            inputs: vec![0, AMM_POOL_ID],
+        },
+        Cellpack {
+            target: FACTORY,
+            inputs: vec![
+                1,
+                TOKEN_A.block,
+                TOKEN_A.tx,
+                TOKEN_B.block,
+                TOKEN_B.tx,
+                500_000,
+                500_000,
+            ],
+        },
+    ];
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+            alkanes_std_amm_pool_build::get_bytes(),
+            alkanes_std_amm_factory_build::get_bytes(),
+            vec![],
+        ],
+        cellpacks,
+    );
+    index_block(&test_block, 840_000)?;
+    Ok(test_block)
+}
+
+fn last_outpoint(block: &bitcoin::Block) -> Result<OutPoint> {
+    Ok(OutPoint {
+        txid: block
+            .txdata
+            .last()
+            .ok_or(anyhow!("no last el"))?
+            .compute_txid(),
+        vout: 0,
+    })
+}
+
+// calls `cellpack` with only the amounts in `parcel` taken from whatever
+// `previous_output` holds, everything else staying on output 0 along with
+// the call's result
+fn call_with(
+    previous_output: OutPoint,
+    parcel: Vec<(AlkaneId, u128)>,
+    cellpack: Cellpack,
+    height: u32,
+) -> Result<Transaction> {
+    let tx = alkane_helpers::create_cellpack_with_parcel(previous_output, parcel, cellpack);
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]),
+        height,
+    )?;
+    Ok(tx)
+}
+
+fn balance_at(tx: &Transaction, id: &AlkaneId) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint::new(tx.compute_txid(), 0))?),
+    )
+    .get(&id.clone().into()))
+}
+
+fn u128_at(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+fn reserves() -> Result<(u128, u128)> {
+    let data = call_view(&POOL, &vec![97], FUEL)?;
+    Ok((u128_at(&data, 0), u128_at(&data, 16)))
+}
+
+fn total_supply() -> Result<u128> {
+    Ok(u128_at(&call_view(&POOL, &vec![101], FUEL)?, 0))
+}
+
+// the fee is the last field of GetPoolDetails
+fn fee() -> Result<u128> {
+    let data = call_view(&POOL, &vec![98], FUEL)?;
+    Ok(u128_at(&data, data.len() - 16))
+}
+
+#[wasm_bindgen_test]
+fn test_amm_create_pool_and_burn() -> Result<()> {
+    clear();
+    let block_height = 840_000;
+    let token_a = TOKEN_A;
+    let token_b = TOKEN_B;
+    let pool = POOL;
+    let test_block = deploy()?;
+
+    assert_binary_deployed_to_id(
+        AlkaneId {
+            block: 4,
+            tx: AMM_FACTORY_ID,
+        },
+        alkanes_std_amm_factory_build::get_bytes(),
+    )?;
+    assert_binary_deployed_to_id(pool.clone(), alkanes_std_amm_pool_build::get_bytes())?;
+
+    let tx = test_block.txdata.last().ok_or(anyhow!("no last el"))?;
+    let sheet = load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint {
+                txid: tx.compute_txid(),
+                vout: 0,
+            })?),
+    );
+    // sqrt(500_000 * 500_000) minus the locked minimum liquidity
+    assert_eq!(sheet.get(&pool.clone().into()), 499_000);
+    assert_eq!(sheet.get(&token_a.clone().into()), 500_000);
+    assert_eq!(sheet.get(&token_b.clone().into()), 500_000);
+
+    let mut burn_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]],
+        vec![Cellpack {
+            target: pool.clone(),
+            inputs: vec![2],
+        }],
+    );
+    burn_block.txdata[1].input[0].previous_output = OutPoint {
+        txid: tx.compute_txid(),
+        vout: 0,
+    };
+    index_block(&burn_block, block_height + 1)?;
+    let burn_tx = burn_block.txdata.last().ok_or(anyhow!("no last el"))?;
+    let sheet = load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint {
+                txid: burn_tx.compute_txid(),
+                vout: 0,
+            })?),
+    );
+    assert_eq!(sheet.get(&pool.into()), 0);
+    assert_eq!(sheet.get(&token_a.into()), 999_000);
+    assert_eq!(sheet.get(&token_b.into()), 999_000);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_amm_burn_with_pool_assets_attached() -> Result<()> {
+    clear();
+    let deployed = last_outpoint(&deploy()?)?;
+    // half the LP tokens, sent along with all of the spare token a
+    let tx = call_with(
+        deployed,
+        vec![(POOL, 249_500), (TOKEN_A, 500_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![2],
+        },
+        840_001,
+    )?;
+    // the attached token a comes back as it was and only counts once towards
+    // the payout, which is the burned share of the reserves from before
+    assert_eq!(balance_at(&tx, &POOL)?, 249_500);
+    assert_eq!(balance_at(&tx, &TOKEN_A)?, 749_500);
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 749_500);
+    assert_eq!(reserves()?, (250_500, 250_500));
+    assert_eq!(total_supply()?, 250_500);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_amm_add_liquidity_to_existing_reserves() -> Result<()> {
+    clear();
+    let deployed = last_outpoint(&deploy()?)?;
+    let tx = call_with(
+        deployed,
+        vec![(TOKEN_A, 100_000), (TOKEN_B, 100_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![1],
+        },
+        840_001,
+    )?;
+    // minted in proportion to the reserves the deposit joined
+    assert_eq!(balance_at(&tx, &POOL)?, 599_000);
+    assert_eq!(balance_at(&tx, &TOKEN_A)?, 400_000);
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 400_000);
+    assert_eq!(reserves()?, (600_000, 600_000));
+    assert_eq!(total_supply()?, 600_000);
+
+    // an unbalanced deposit is minted against the scarcer side
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(TOKEN_A, 60_000), (TOKEN_B, 30_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![1],
+        },
+        840_002,
+    )?;
+    assert_eq!(balance_at(&tx, &POOL)?, 629_000);
+    assert_eq!(reserves()?, (660_000, 630_000));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_amm_swap() -> Result<()> {
+    clear();
+    let deployed = last_outpoint(&deploy()?)?;
+    let tx = call_with(
+        deployed,
+        vec![(TOKEN_A, 10_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![3, 9_000],
+        },
+        840_001,
+    )?;
+    // 10_000 * 997 * 500_000 / (500_000 * 1000 + 10_000 * 997)
+    assert_eq!(balance_at(&tx, &TOKEN_A)?, 490_000);
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 509_775);
+    assert_eq!(reserves()?, (510_000, 490_225));
+
+    // a minimum above the output reverts and refunds the input
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(TOKEN_A, 10_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![3, 1_000_000],
+        },
+        840_002,
+    )?;
+    assert_eq!(balance_at(&tx, &TOKEN_A)?, 490_000);
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 509_775);
+    assert_eq!(reserves()?, (510_000, 490_225));
+
+    // so does supplying both pool assets at once
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(TOKEN_A, 10_000), (TOKEN_B, 10_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![3, 0],
+        },
+        840_003,
+    )?;
+    assert_eq!(balance_at(&tx, &TOKEN_A)?, 490_000);
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 509_775);
+    assert_eq!(reserves()?, (510_000, 490_225));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_amm_set_fee() -> Result<()> {
+    clear();
+    let deployed = last_outpoint(&deploy()?)?;
+    assert_eq!(fee()?, 3);
+
+    // the pool only takes its fee from the factory
+    let tx = call_with(
+        deployed,
+        vec![],
+        Cellpack {
+            target: POOL,
+            inputs: vec![20, 10],
+        },
+        840_001,
+    )?;
+    assert_eq!(fee()?, 3);
+
+    // and the factory only passes it on for the holder of its auth token,
+    // which stays behind on output 0 here
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(TOKEN_A, 1)],
+        Cellpack {
+            target: FACTORY,
+            inputs: vec![21, POOL.block, POOL.tx, 10],
+        },
+        840_002,
+    )?;
+    assert_eq!(fee()?, 3);
+
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(AlkaneId { block: 2, tx: 5 }, 1)],
+        Cellpack {
+            target: FACTORY,
+            inputs: vec![21, POOL.block, POOL.tx, 10],
+        },
+        840_003,
+    )?;
+    assert_eq!(fee()?, 10);
+    assert_eq!(balance_at(&tx, &AlkaneId { block: 2, tx: 5 })?, 1);
+
+    // the new fee applies to the next swap
+    let tx = call_with(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(TOKEN_A, 10_000)],
+        Cellpack {
+            target: POOL,
+            inputs: vec![3, 0],
+        },
+        840_004,
+    )?;
+    // 10_000 * 990 * 500_000 / (500_000 * 1000 + 10_000 * 990)
+    assert_eq!(balance_at(&tx, &TOKEN_B)?, 509_707);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_amm_factory_deploy_must_initialize() -> Result<()> {
+    clear();
+    // deployed with any call but Initialize, the call fails and the deploy
+    // goes with it, so nobody is left to claim the factory's auth token later
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![alkanes_std_amm_factory_build::get_bytes()],
+        vec![Cellpack {
+            target: AlkaneId {
+                block: 3,
+                tx: AMM_FACTORY_ID,
+            },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&test_block, 840_000)?;
+    assert!(load_bytecode(&IndexPointer::default(), &FACTORY).is_empty());
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::bytecode::{alkanes_with_code, code_hash, load_bytecode, load_code, CODE_POINTER_TAG};
+use crate::index_block;
+use crate::network::genesis_alkane_bytes;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view::{self, call_view};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::sha256;
+use alkanes_support::gz::compress;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::Result;
+use metashrew_core::index_pointer::IndexPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_factory_clone_shares_bytecode() -> Result<()> {
+    clear();
+    let original = AlkaneId { block: 2, tx: 1 };
+    let clone = AlkaneId { block: 2, tx: 2 };
+    let test_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![4],
+            },
+            Cellpack {
+                target: AlkaneId { block: 5, tx: 1 },
+                inputs: vec![4],
+            },
+        ],
+    );
+    index_block(&test_block, 840_000)?;
+
+    let hash = sha256(&alkanes_std_test_build::get_bytes());
+    let root = IndexPointer::default();
+    assert_eq!(code_hash(&root, &original)?, Some(hash));
+    assert_eq!(code_hash(&root, &clone)?, Some(hash));
+    assert_eq!(
+        load_bytecode(&root, &clone),
+        load_bytecode(&root, &original)
+    );
+    alkane_helpers::assert_binary_deployed_to_id(clone, alkanes_std_test_build::get_bytes())?;
+
+    // both alkanes hold only a pointer to the one stored copy
+    for id in [original, clone] {
+        let stored = IndexPointer::from_keyword("/alkanes/")
+            .select(&id.into())
+            .get();
+        assert_eq!(stored.len(), 33);
+        assert_eq!(stored[0], CODE_POINTER_TAG);
+    }
+    assert_eq!(alkanes_with_code(&hash)?, vec![original, clone]);
+
+    let mut request = proto::alkanes::CodeHashRequest::new();
+    request.id = Some(clone.into()).into();
+    let response = view::code_hash(&request)?;
+    assert_eq!(response.code_hash, hash.to_vec());
+    assert_eq!(response.alkanes.len(), 2);
+
+    assert_eq!(
+        call_view(&clone, &vec![5], 100_000_000)?,
+        vec![0x05, 0x06, 0x07, 0x08]
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_genesis_alkane_stored_by_hash() -> Result<()> {
+    clear();
+    index_block(
+        &alkane_helpers::init_with_multiple_cellpacks_with_tx(vec![], vec![]),
+        840_000,
+    )?;
+    let genesis = AlkaneId { block: 2, tx: 0 };
+    let hash = sha256(&genesis_alkane_bytes());
+    let stored = IndexPointer::from_keyword("/alkanes/")
+        .select(&genesis.into())
+        .get();
+    assert_eq!(stored[0], CODE_POINTER_TAG);
+    assert_eq!(code_hash(&IndexPointer::default(), &genesis)?, Some(hash));
+    assert_eq!(alkanes_with_code(&hash)?, vec![genesis]);
+    alkane_helpers::assert_binary_deployed_to_id(genesis, genesis_alkane_bytes())?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_legacy_bytecode_moved_under_hash_on_load() -> Result<()> {
+    clear();
+    let legacy = AlkaneId { block: 2, tx: 1 };
+    let compressed = compress(alkanes_std_test_build::get_bytes())?;
+    let mut entry = IndexPointer::from_keyword("/alkanes/").select(&legacy.into());
+    entry.set(Arc::new(compressed.clone()));
+
+    let hash = sha256(&alkanes_std_test_build::get_bytes());
+    let root = IndexPointer::default();
+    let (loaded_hash, loaded) = load_code(&root, &legacy)?.unwrap();
+    assert_eq!(loaded_hash, hash);
+    assert_eq!(loaded.as_ref(), &compressed);
+
+    // the entry now points at the code, so the next load reads the hash
+    // instead of inflating the bytecode again
+    assert_eq!(
+        entry.get().as_ref(),
+        &[&[CODE_POINTER_TAG], &hash[..]].concat()
+    );
+    assert_eq!(alkanes_with_code(&hash)?, vec![legacy]);
+    assert_eq!(
+        load_code(&root, &legacy)?,
+        Some((hash, Arc::new(compressed)))
+    );
+    Ok(())
+}
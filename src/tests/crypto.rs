@@ -0,0 +1,184 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view::call_view;
+use crate::vm::fuel::{
+    CryptoCosts, FuelSchedule, FUEL_HASH160, FUEL_KECCAK256, FUEL_PER_HASH_BYTE, FUEL_RIPEMD160,
+    FUEL_SCHEDULE_V1, FUEL_SCHNORR_VERIFY, FUEL_SHA256,
+};
+use crate::vm::instance::AlkanesInstance;
+use crate::vm::runtime::AlkanesRuntimeContext;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::{
+    ecdsa_verify, hash160, keccak256, ripemd160, schnorr_verify, sha256,
+};
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::secp256k1::{Keypair, Message, Secp256k1, SecretKey};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use std::sync::{Arc, Mutex};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+// V1 with every precompile free, so the difference from V1 is what the
+// precompiles were charged
+const FREE_CRYPTO_SCHEDULE: FuelSchedule = FuelSchedule {
+    version: u32::MAX,
+    crypto: Some(CryptoCosts {
+        sha256: 0,
+        keccak256: 0,
+        ripemd160: 0,
+        hash160: 0,
+        per_hash_byte: 0,
+        schnorr_verify: 0,
+        ecdsa_verify: 0,
+    }),
+    ..FUEL_SCHEDULE_V1
+};
+
+// fuel the test alkane uses running `inputs` under `schedule`
+fn fuel_used(schedule: &'static FuelSchedule, inputs: Vec<u128>) -> Result<u64> {
+    let mut context = AlkanesRuntimeContext::default();
+    context.myself = AlkaneId { block: 2, tx: 1 };
+    context.inputs = inputs;
+    let mut instance = AlkanesInstance::from_alkane_with_schedule(
+        Arc::new(Mutex::new(context)),
+        Arc::new(alkanes_std_test_build::get_bytes()),
+        FUEL,
+        schedule,
+    )?;
+    instance.execute()?;
+    Ok(FUEL - instance.store.get_fuel()?)
+}
+
+fn to_words(v: &[u8]) -> Vec<u128> {
+    v.chunks(16)
+        .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn test_digests() -> Result<()> {
+    assert_eq!(
+        hex::encode(sha256(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex::encode(keccak256(b"")),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        hex::encode(ripemd160(b"abc")),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+    );
+    assert_eq!(hash160(b"abc"), ripemd160(&sha256(b"abc")));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_signature_verification() -> Result<()> {
+    let secp = Secp256k1::new();
+    let secret = SecretKey::from_slice(&[0x11; 32])?;
+    let digest = sha256(b"alkanes");
+    let message = Message::from_digest(digest);
+    let other = sha256(b"protorunes");
+
+    let keypair = Keypair::from_secret_key(&secp, &secret);
+    let xonly = keypair.x_only_public_key().0.serialize();
+    let signature = secp
+        .sign_schnorr_no_aux_rand(&message, &keypair)
+        .serialize();
+    assert!(schnorr_verify(&xonly, &digest, &signature));
+    assert!(!schnorr_verify(&xonly, &other, &signature));
+    assert!(!schnorr_verify(&xonly[1..], &digest, &signature));
+
+    let pubkey = secret.public_key(&secp);
+    let signature = secp.sign_ecdsa(&message, &secret);
+    let compact = signature.serialize_compact();
+    let der = signature.serialize_der();
+    assert!(ecdsa_verify(&pubkey.serialize(), &digest, &compact));
+    assert!(ecdsa_verify(
+        &pubkey.serialize_uncompressed(),
+        &digest,
+        &der
+    ));
+    assert!(!ecdsa_verify(&pubkey.serialize(), &other, &compact));
+    assert!(!ecdsa_verify(&pubkey.serialize(), &digest, &compact[1..]));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_crypto_precompiles() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+
+    let data = b"alkanes";
+    assert_eq!(
+        call_view(&alkane, &vec![79], FUEL)?,
+        [
+            sha256(data).to_vec(),
+            keccak256(data).to_vec(),
+            ripemd160(data).to_vec(),
+            hash160(data).to_vec(),
+        ]
+        .concat()
+    );
+
+    let secp = Secp256k1::new();
+    let keypair = Keypair::from_secret_key(&secp, &SecretKey::from_slice(&[0x22; 32])?);
+    let xonly = keypair.x_only_public_key().0.serialize();
+    let digest = sha256(data);
+    let signature = secp
+        .sign_schnorr_no_aux_rand(&Message::from_digest(digest), &keypair)
+        .serialize();
+    let verify = |digest: &[u8]| -> Result<Vec<u8>> {
+        let mut inputs = vec![80];
+        for v in [&xonly[..], digest, &signature[..]] {
+            let words = to_words(v);
+            inputs.push(words.len() as u128);
+            inputs.extend(words);
+        }
+        call_view(&alkane, &inputs, FUEL)
+    };
+    assert_eq!(verify(&digest)?, vec![1]);
+    assert_eq!(verify(&sha256(b"protorunes"))?, vec![0]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_crypto_precompiles_charge_fuel() -> Result<()> {
+    // each digest of b"alkanes" pays its base cost plus 7 bytes hashed
+    let digests = FUEL_SHA256 + FUEL_KECCAK256 + FUEL_RIPEMD160 + FUEL_HASH160;
+    assert_eq!(
+        fuel_used(&FUEL_SCHEDULE_V1, vec![79])? - fuel_used(&FREE_CRYPTO_SCHEDULE, vec![79])?,
+        digests + 4 * 7 * FUEL_PER_HASH_BYTE
+    );
+
+    // a failed verification is charged all the same
+    let mut inputs = vec![80];
+    for v in [[0x02u8; 32], [0x03u8; 32]] {
+        inputs.push(2);
+        inputs.extend(to_words(&v));
+    }
+    inputs.push(4);
+    inputs.extend(to_words(&[0x04u8; 64]));
+    assert_eq!(
+        fuel_used(&FUEL_SCHEDULE_V1, inputs.clone())? - fuel_used(&FREE_CRYPTO_SCHEDULE, inputs)?,
+        FUEL_SCHNORR_VERIFY
+    );
+    Ok(())
+}
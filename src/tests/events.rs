@@ -0,0 +1,156 @@
+use crate::events::{events_by_alkane, events_by_transaction};
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::vm::fuel::{FuelSchedule, FUEL_EMIT_EVENT, FUEL_PER_STORE_BYTE, FUEL_SCHEDULE_V2};
+use crate::vm::instance::AlkanesInstance;
+use crate::vm::runtime::AlkanesRuntimeContext;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{EventsRequest, TransactionEventsRequest, Uint128};
+use anyhow::Result;
+use bitcoin::hashes::Hash;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use protobuf::MessageField;
+use std::sync::{Arc, Mutex};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const EVENT_TOPIC: u128 = 0x7e57;
+
+const FUEL: u64 = 100_000_000;
+
+// V2 with events free. Under V2 per_store_byte otherwise only prices host
+// functions that check the fuel left without deducting it, so it can be
+// zeroed too
+const FREE_EVENTS_SCHEDULE: FuelSchedule = FuelSchedule {
+    version: u32::MAX,
+    emit_event: Some(0),
+    per_store_byte: 0,
+    ..FUEL_SCHEDULE_V2
+};
+
+// fuel the test alkane uses running `inputs` under `schedule`
+fn fuel_used(schedule: &'static FuelSchedule, inputs: Vec<u128>) -> Result<u64> {
+    let mut context = AlkanesRuntimeContext::default();
+    context.myself = AlkaneId { block: 2, tx: 1 };
+    context.inputs = inputs;
+    let mut instance = AlkanesInstance::from_alkane_with_schedule(
+        Arc::new(Mutex::new(context)),
+        Arc::new(alkanes_std_test_build::get_bytes()),
+        FUEL,
+        schedule,
+    )?;
+    instance.execute()?;
+    Ok(FUEL - instance.store.get_fuel()?)
+}
+
+#[wasm_bindgen_test]
+fn test_emitted_events_are_indexed() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let test_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![30],
+            },
+            Cellpack {
+                target: alkane.clone(),
+                inputs: vec![31],
+            },
+        ],
+    );
+    index_block(&test_block, 840_000)?;
+    let next_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]],
+        vec![Cellpack {
+            target: alkane.clone(),
+            inputs: vec![30],
+        }],
+    );
+    index_block(&next_block, 840_001)?;
+
+    let request = |topic: Option<u128>, from_height: u64, to_height: u64| EventsRequest {
+        alkane: MessageField::some(alkane.clone().into()),
+        topic: topic.map(Uint128::from).into(),
+        from_height,
+        to_height,
+        ..Default::default()
+    };
+
+    // the event emitted ahead of the revert is dropped with the rest of the call
+    let events = events_by_alkane(&request(None, 0, 0))?.events;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].height, 840_000);
+    assert_eq!(events[1].height, 840_001);
+    assert_eq!(events[0].data, vec![0x01, 0x02, 0x03]);
+    assert_eq!(
+        events[0]
+            .topics
+            .iter()
+            .map(|v| u128::from(v.clone()))
+            .collect::<Vec<u128>>(),
+        vec![EVENT_TOPIC, alkane.tx]
+    );
+
+    assert_eq!(
+        events_by_alkane(&request(Some(EVENT_TOPIC), 0, 0))?
+            .events
+            .len(),
+        2
+    );
+    assert!(events_by_alkane(&request(Some(0xdead), 0, 0))?
+        .events
+        .is_empty());
+    assert_eq!(
+        events_by_alkane(&request(None, 840_001, 0))?.events[0].height,
+        840_001
+    );
+    assert_eq!(
+        events_by_alkane(&request(None, 0, 840_000))?.events.len(),
+        1
+    );
+
+    // a topic reads from its own index and pages like the full list
+    let page = events_by_alkane(&EventsRequest {
+        offset: 1,
+        limit: 1,
+        ..request(Some(alkane.tx), 0, 0)
+    })?;
+    assert_eq!(page.total, 2);
+    assert_eq!(page.events.len(), 1);
+    assert_eq!(page.events[0].height, 840_001);
+    assert!(events_by_alkane(&EventsRequest {
+        offset: 2,
+        ..request(None, 0, 0)
+    })?
+    .events
+    .is_empty());
+    assert_eq!(events_by_alkane(&request(None, 840_001, 0))?.total, 1);
+
+    let txid = test_block.txdata.last().unwrap().compute_txid();
+    let events = events_by_transaction(&TransactionEventsRequest {
+        txid: txid.as_byte_array().to_vec(),
+        ..Default::default()
+    })?
+    .events;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].outpoint.txid, txid.as_byte_array().to_vec());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_emitted_events_charge_fuel() -> Result<()> {
+    // two topics of 16 bytes and 3 bytes of data
+    assert_eq!(
+        fuel_used(&FUEL_SCHEDULE_V2, vec![30])? - fuel_used(&FREE_EVENTS_SCHEDULE, vec![30])?,
+        FUEL_EMIT_EVENT + 35 * FUEL_PER_STORE_BYTE
+    );
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_free_mint_build;
+use crate::view::call_view;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::Witness;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use protorune::test_helpers::create_block_with_coinbase_tx;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+fn view_u128(id: &AlkaneId, opcode: u128) -> Result<u128> {
+    Ok(u128::from_le_bytes(
+        call_view(id, &vec![opcode], FUEL)?.as_slice().try_into()?,
+    ))
+}
+
+#[wasm_bindgen_test]
+fn test_free_mint() -> Result<()> {
+    clear();
+    let token = AlkaneId { block: 2, tx: 1 };
+    let mint = Cellpack {
+        target: token,
+        inputs: vec![77],
+    };
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_free_mint_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![
+                0,
+                u128::from_le_bytes(*b"FAIR\0\0\0\0\0\0\0\0\0\0\0\0"),
+                u128::from_le_bytes(*b"FAIR\0\0\0\0\0\0\0\0\0\0\0\0"),
+                3500,    /* cap */
+                1000,    /* value per mint */
+                500,     /* premine */
+                0,       /* start height */
+                840_001, /* end height */
+                1,       /* mints per transaction */
+            ],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+    assert_eq!(call_view(&token, &vec![99], FUEL)?, b"FAIR".to_vec());
+    assert_eq!(view_u128(&token, 101)?, 500);
+    assert_eq!(view_u128(&token, 102)?, 3500);
+
+    let mut mint_block = create_block_with_coinbase_tx(840_001);
+    // only the first of these mints fits in the per-transaction limit
+    mint_block
+        .txdata
+        .push(alkane_helpers::create_multiple_cellpack_with_witness(
+            Witness::new(),
+            vec![mint.clone(), mint.clone()],
+            false,
+        ));
+    // the third of these would pass the cap
+    mint_block.txdata.extend(
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            vec![vec![], vec![], vec![]],
+            vec![mint.clone(), mint.clone(), mint.clone()],
+        )
+        .txdata
+        .into_iter()
+        .skip(1),
+    );
+    index_block(&mint_block, 840_001)?;
+    assert_eq!(view_u128(&token, 101)?, 3500);
+    assert_eq!(view_u128(&token, 104)?, 3);
+
+    // the mint window has closed
+    let mut late_block = create_block_with_coinbase_tx(840_002);
+    late_block
+        .txdata
+        .push(alkane_helpers::create_multiple_cellpack_with_witness(
+            Witness::new(),
+            vec![mint.clone(), mint.clone(), mint],
+            false,
+        ));
+    index_block(&late_block, 840_002)?;
+    assert_eq!(view_u128(&token, 104)?, 3);
+    Ok(())
+}
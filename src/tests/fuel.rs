@@ -1,12 +1,21 @@
+use crate::network::genesis;
 use crate::tests::std::alkanes_std_test_build;
+use crate::vm::fuel::{
+    fuel_schedule, minimum_vfsize, transaction_fuel, FuelSchedule, FUEL_EMIT_EVENT, FUEL_EXTCALL,
+    FUEL_PER_STORE_BYTE, FUEL_SCHEDULES, FUEL_SCHEDULE_V0, FUEL_SCHEDULE_V1, FUEL_SCHEDULE_V2,
+    FUEL_SCHEDULE_V3, MINIMUM_FUEL, TOTAL_FUEL,
+};
 use alkanes_support::cellpack::Cellpack;
 use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{EstimateFuelRequest, MessageContextParcel};
 use alkanes_support::trace::{Trace, TraceEvent};
 use anyhow::Result;
 use bitcoin::OutPoint;
 
 use crate::index_block;
 use crate::tests::helpers::{self as alkane_helpers};
+use crate::vm::instance::AlkanesInstance;
+use crate::vm::runtime::AlkanesRuntimeContext;
 use alkane_helpers::clear;
 use alkanes::view;
 #[allow(unused_imports)]
@@ -14,8 +23,12 @@ use metashrew_core::{
     println,
     stdio::{stdout, Write},
 };
+use protobuf::MessageField;
+use std::sync::{Arc, Mutex};
 use wasm_bindgen_test::wasm_bindgen_test;
 
+const FUEL: u64 = 100_000_000;
+
 #[wasm_bindgen_test]
 fn test_infinite_loop() -> Result<()> {
     clear();
@@ -56,3 +69,159 @@ fn test_infinite_loop() -> Result<()> {
 
     Ok(())
 }
+
+#[wasm_bindgen_test]
+fn test_fuel_schedule_v0_matches_launch_costs() -> Result<()> {
+    let schedule = &FUEL_SCHEDULES[0];
+    assert_eq!(schedule, &FUEL_SCHEDULE_V0);
+    assert!(!schedule.deduct_host_fuel);
+    assert!(schedule.crypto.is_none());
+    assert_eq!(schedule.per_store_byte, FUEL_PER_STORE_BYTE);
+    assert_eq!(schedule.extcall, FUEL_EXTCALL);
+    assert_eq!(schedule.request_transaction, 50);
+    assert_eq!(schedule.request_block, 100);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_fuel_schedule_v1_only_adds_crypto() -> Result<()> {
+    assert_eq!(&FUEL_SCHEDULES[1], &FUEL_SCHEDULE_V1);
+    assert_eq!(
+        FUEL_SCHEDULE_V1.activation_height,
+        genesis::CRYPTO_ACTIVATION_HEIGHT
+    );
+    assert!(FUEL_SCHEDULE_V1.crypto.is_some());
+    assert_eq!(
+        FuelSchedule {
+            version: 0,
+            activation_height: 0,
+            crypto: None,
+            ..FUEL_SCHEDULE_V1
+        },
+        FUEL_SCHEDULE_V0
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_fuel_schedule_v2_only_adds_events() -> Result<()> {
+    assert_eq!(
+        fuel_schedule(genesis::EVENTS_ACTIVATION_HEIGHT),
+        &FUEL_SCHEDULE_V2
+    );
+    assert!(FUEL_SCHEDULE_V0.emit_event.is_none());
+    assert!(FUEL_SCHEDULE_V1.emit_event.is_none());
+    assert_eq!(FUEL_SCHEDULE_V2.emit_event, Some(FUEL_EMIT_EVENT));
+    assert_eq!(
+        FuelSchedule {
+            version: 1,
+            activation_height: genesis::CRYPTO_ACTIVATION_HEIGHT,
+            emit_event: None,
+            ..FUEL_SCHEDULE_V2
+        },
+        FUEL_SCHEDULE_V1
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_fuel_schedule_v3_only_deducts_host_fuel() -> Result<()> {
+    assert_eq!(
+        fuel_schedule(genesis::HOST_FUEL_ACTIVATION_HEIGHT),
+        &FUEL_SCHEDULE_V3
+    );
+    assert!(FUEL_SCHEDULES[..3].iter().all(|v| !v.deduct_host_fuel));
+    assert_eq!(
+        FuelSchedule {
+            version: 2,
+            activation_height: genesis::EVENTS_ACTIVATION_HEIGHT,
+            deduct_host_fuel: false,
+            ..FUEL_SCHEDULE_V3
+        },
+        FUEL_SCHEDULE_V2
+    );
+    Ok(())
+}
+
+// fuel the test alkane uses running `inputs` under `schedule`
+fn fuel_used(schedule: &'static FuelSchedule, inputs: Vec<u128>) -> Result<u64> {
+    let mut context = AlkanesRuntimeContext::default();
+    context.myself = AlkaneId { block: 2, tx: 1 };
+    context.inputs = inputs;
+    let mut instance = AlkanesInstance::from_alkane_with_schedule(
+        Arc::new(Mutex::new(context)),
+        Arc::new(alkanes_std_test_build::get_bytes()),
+        FUEL,
+        schedule,
+    )?;
+    instance.execute()?;
+    Ok(FUEL - instance.store.get_fuel()?)
+}
+
+#[wasm_bindgen_test]
+fn test_host_functions_deduct_fuel_under_v3() -> Result<()> {
+    // the same call reads its context and stores two events either way, but
+    // only V3 takes the cost of the reads from the fuel left
+    assert!(fuel_used(&FUEL_SCHEDULE_V3, vec![30])? > fuel_used(&FUEL_SCHEDULE_V2, vec![30])?);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_transaction_fuel_and_minimum_vfsize() -> Result<()> {
+    // a transaction alone in its block gets the whole block's fuel
+    assert_eq!(transaction_fuel(200, 0), TOTAL_FUEL);
+    assert_eq!(transaction_fuel(200, 400), TOTAL_FUEL / 2);
+    assert_eq!(transaction_fuel(1, 1_000_000), MINIMUM_FUEL);
+
+    assert_eq!(minimum_vfsize(MINIMUM_FUEL, 1_000_000), Some(0));
+    assert_eq!(minimum_vfsize(TOTAL_FUEL / 2, 400), Some(400));
+    assert_eq!(minimum_vfsize(TOTAL_FUEL, 400), None);
+    assert_eq!(minimum_vfsize(TOTAL_FUEL + 1, 0), None);
+    for (fuel, rest) in [(1_000_000, 300_000), (7_654_321, 12_345)] {
+        let vsize = minimum_vfsize(fuel, rest).unwrap();
+        assert!(transaction_fuel(vsize, vsize + rest) >= fuel);
+        assert!(transaction_fuel(vsize - 1, vsize - 1 + rest) < fuel);
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_estimate_fuel() -> Result<()> {
+    clear();
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+
+    let estimate = |opcode: u128| {
+        view::estimate_fuel(&EstimateFuelRequest {
+            parcel: MessageField::some(MessageContextParcel {
+                calldata: Cellpack {
+                    target: AlkaneId { block: 2, tx: 1 },
+                    inputs: vec![opcode],
+                }
+                .encipher(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    };
+
+    // a cheap call fits in the minimum allocation
+    let response = estimate(5)?;
+    assert!(response.success);
+    assert!(response.gas_used > 0);
+    assert_eq!(response.fuel, MINIMUM_FUEL);
+    assert_eq!(response.padding, 0);
+
+    // no size of transaction is enough for a call that never returns
+    let response = estimate(20)?;
+    assert!(!response.success);
+    assert!(!response.error.is_empty());
+    assert_eq!(response.required_vsize, 0);
+    Ok(())
+}
@@ -18,11 +18,12 @@ use metashrew_support::index_pointer::KeyValuePointer;
 use protorune::protostone::Protostones;
 use protorune::test_helpers::{create_block_with_coinbase_tx, get_address, ADDRESS1};
 use protorune_support::network::{set_network, NetworkParams};
-use protorune_support::protostone::Protostone;
+use protorune_support::protostone::{Protostone, ProtostoneEdict};
 
 use ordinals::{Etching, Rune, Runestone};
 use std::str::FromStr;
 
+use crate::bytecode::load_bytecode;
 #[cfg(test)]
 use crate::tests::std::alkanes_std_test_build;
 
@@ -287,6 +288,74 @@ pub fn create_multiple_cellpack_with_witness_and_in(
     }
 }
 
+// a transaction spending `previous_output` that calls `cellpack` with only
+// the amounts in `parcel`, moved to it by an edict in a protostone ahead of
+// the message. Everything else, and whatever the call returns, goes to
+// output 0
+pub fn create_cellpack_with_parcel(
+    previous_output: OutPoint,
+    parcel: Vec<(AlkaneId, u128)>,
+    cellpack: Cellpack,
+) -> Transaction {
+    let protocol_id = 1;
+    // output 0, the runestone at 1, then the shadow vouts of the two stones
+    let message_vout = 4;
+    let protostones = vec![
+        Protostone {
+            message: vec![],
+            pointer: Some(0),
+            refund: Some(0),
+            edicts: parcel
+                .into_iter()
+                .map(|(id, amount)| ProtostoneEdict {
+                    id: id.into(),
+                    amount,
+                    output: message_vout,
+                })
+                .collect(),
+            from: None,
+            burn: None,
+            protocol_tag: protocol_id,
+        },
+        Protostone {
+            message: cellpack.encipher(),
+            pointer: Some(0),
+            refund: Some(0),
+            edicts: vec![],
+            from: None,
+            burn: None,
+            protocol_tag: protocol_id,
+        },
+    ];
+    Transaction {
+        version: Version::ONE,
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![
+            TxOut {
+                value: Amount::from_sat(100_000_000),
+                script_pubkey: get_address(&ADDRESS1().as_str()).script_pubkey(),
+            },
+            TxOut {
+                value: Amount::from_sat(0),
+                script_pubkey: (Runestone {
+                    etching: None,
+                    pointer: Some(0),
+                    edicts: Vec::new(),
+                    mint: None,
+                    protocol: protostones.encipher().ok(),
+                })
+                .encipher(),
+            },
+        ],
+    }
+}
+
 pub fn create_cellpack_with_witness(witness: Witness, cellpack: Cellpack) -> Transaction {
     create_multiple_cellpack_with_witness(witness, [cellpack].into(), false)
 }
@@ -307,9 +376,7 @@ pub fn create_multiple_cellpack_with_witness(
 }
 
 pub fn assert_binary_deployed_to_id(token_id: AlkaneId, binary: Vec<u8>) -> Result<()> {
-    let binary_1 = IndexPointer::from_keyword("/alkanes/")
-        .select(&token_id.into())
-        .get()
+    let binary_1 = load_bytecode(&IndexPointer::default(), &token_id)
         .as_ref()
         .clone();
     let binary_2: Vec<u8> = compress(binary)?;
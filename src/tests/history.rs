@@ -0,0 +1,118 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::BytecodeRequest;
+use anyhow::Result;
+use bitcoin::OutPoint;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protobuf::{Message, MessageField};
+use protorune::test_helpers::create_block_with_coinbase_tx;
+use protorune::{balance_sheet::load_sheet, journal, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_views_at_past_height() -> Result<()> {
+    clear();
+    index_block(&create_block_with_coinbase_tx(840_000), 840_000)?;
+    let test_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&test_block, 840_001)?;
+    index_block(&create_block_with_coinbase_tx(840_002), 840_002)?;
+
+    let deployed = AlkaneId { block: 2, tx: 1 };
+    let bytecode_request = BytecodeRequest {
+        id: MessageField::some(deployed.clone().into()),
+        ..Default::default()
+    }
+    .write_to_bytes()?;
+    let outpoint = OutPoint {
+        txid: test_block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+    let balance = || -> Result<u128> {
+        Ok(load_sheet(
+            &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&outpoint)?),
+        )
+        .get(&ProtoruneRuneId { block: 2, tx: 1 }))
+    };
+
+    assert!(view::at_height(840_000, || view::getbytecode(&bytecode_request)).is_err());
+    assert_eq!(view::at_height(840_000, balance)?, 0);
+    assert!(!view::at_height(840_001, || view::getbytecode(&bytecode_request))?.is_empty());
+    assert_eq!(view::at_height(840_001, balance)?, 100);
+
+    // reading the past leaves the tip untouched
+    assert!(!view::getbytecode(&bytecode_request)?.is_empty());
+    assert_eq!(balance()?, 100);
+    Ok(())
+}
+
+#[cfg(not(feature = "archive"))]
+#[wasm_bindgen_test]
+fn test_views_outside_journal_window() -> Result<()> {
+    clear();
+    let start = 840_000u32;
+    let tip = start + journal::JOURNAL_DEPTH as u32 + 1;
+    for height in start..=tip {
+        index_block(&create_block_with_coinbase_tx(height), height)?;
+    }
+    // blocks that fell out of the window no longer hold journal entries
+    assert_eq!(
+        journal::JOURNAL.select_value::<u64>(start as u64).length(),
+        0
+    );
+    assert_eq!(
+        journal::window_start(),
+        Some((tip as u64) - journal::JOURNAL_DEPTH)
+    );
+    assert!(view::at_height(tip - journal::JOURNAL_DEPTH as u32, || Ok(())).is_ok());
+    assert!(view::at_height(start, || Ok(())).is_err());
+    assert!(view::at_height(start - 1, || Ok(())).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "archive")]
+#[wasm_bindgen_test]
+fn test_views_past_journal_depth_when_archived() -> Result<()> {
+    clear();
+    let start = 840_000u32;
+    let tip = start + journal::JOURNAL_DEPTH as u32 + 1;
+    for height in start..=tip {
+        index_block(&create_block_with_coinbase_tx(height), height)?;
+    }
+    assert!(journal::JOURNAL.select_value::<u64>(start as u64).length() > 0);
+    assert_eq!(journal::window_start(), Some((start as u64) - 1));
+    assert!(view::at_height(start, || Ok(())).is_ok());
+    assert!(view::at_height(start - 2, || Ok(())).is_err());
+    // a reorg is still held to the journal depth
+    assert!(journal::rollback(start as u64).is_err());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_views_before_first_journaled_block() -> Result<()> {
+    clear();
+    index_block(&create_block_with_coinbase_tx(840_000), 840_000)?;
+    index_block(&create_block_with_coinbase_tx(840_001), 840_001)?;
+    assert!(view::at_height(839_999, || Ok(())).is_ok());
+    assert!(view::at_height(839_998, || Ok(())).is_err());
+    Ok(())
+}
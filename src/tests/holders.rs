@@ -0,0 +1,132 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view::{at_height, holders, ledger};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{AlkaneHolder, HoldersRequest, LedgerRequest};
+use anyhow::Result;
+use bitcoin::absolute::LockTime;
+use bitcoin::hashes::Hash;
+use bitcoin::transaction::Version;
+use bitcoin::{OutPoint, Transaction};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use protobuf::MessageField;
+use protorune::test_helpers::{
+    create_block_with_txs, create_coinbase_transaction, get_txin_from_outpoint,
+    get_txout_transfer_to_address, ADDRESS1,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn balance(holder: &AlkaneHolder) -> u128 {
+    holder.balance.clone().unwrap_or_default().into()
+}
+
+#[wasm_bindgen_test]
+fn test_holders_follow_transfers() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let request = HoldersRequest {
+        alkane: MessageField::some(alkane.into()),
+        ..Default::default()
+    };
+    let mint_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&mint_block, 840_000)?;
+    let minted = OutPoint {
+        txid: mint_block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+
+    let response = holders(&request)?;
+    assert_eq!(response.total, 1);
+    assert_eq!(u128::from(response.supply.clone().unwrap()), 100);
+    assert_eq!(
+        response.holders[0].outpoint.txid,
+        minted.txid.as_byte_array().to_vec()
+    );
+    assert_eq!(response.holders[0].outpoint.vout, 0);
+    assert!(!response.holders[0].address.is_empty());
+    assert_eq!(balance(&response.holders[0]), 100);
+
+    // the spent outpoint leaves the set and the alkane that kept the tokens joins it
+    let mut spend_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]],
+        vec![Cellpack {
+            target: alkane,
+            inputs: vec![3],
+        }],
+    );
+    spend_block.txdata[1].input[0].previous_output = minted;
+    index_block(&spend_block, 840_001)?;
+
+    let response = holders(&request)?;
+    assert_eq!(response.total, 1);
+    assert_eq!(u128::from(response.supply.clone().unwrap()), 100);
+    let holder: AlkaneId = response.holders[0].alkane.clone().unwrap().into();
+    assert_eq!(holder, alkane);
+    assert_eq!(balance(&response.holders[0]), 100);
+
+    let past = at_height(840_000, || holders(&request))?;
+    assert!(past.holders[0].alkane.is_none());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_holders_drop_outpoints_spent_without_protostones() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let mint_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&mint_block, 840_000)?;
+    let minted = OutPoint {
+        txid: mint_block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+
+    // a plain payment carries no runestone, so the tokens on its input are
+    // stranded on the spent outpoint
+    let payment = Transaction {
+        version: Version::ONE,
+        lock_time: LockTime::ZERO,
+        input: vec![get_txin_from_outpoint(minted)],
+        output: vec![get_txout_transfer_to_address(&ADDRESS1(), 1000)],
+    };
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(840_001), payment.clone()]),
+        840_001,
+    )?;
+
+    let response = holders(&HoldersRequest {
+        alkane: MessageField::some(alkane.into()),
+        ..Default::default()
+    })?;
+    assert_eq!(response.total, 0);
+    assert_eq!(u128::from(response.supply.clone().unwrap()), 0);
+    let entries = ledger(&LedgerRequest {
+        alkane: MessageField::some(alkane.into()),
+        ..Default::default()
+    })?
+    .entries;
+    let debit = entries.last().unwrap();
+    assert!(debit.debit);
+    assert_eq!(debit.txid, payment.compute_txid().as_byte_array().to_vec());
+    assert_eq!(debit.vout, 0);
+    assert_eq!(u128::from(debit.amount.clone().unwrap()), 100);
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view::ledger;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{LedgerEntry, LedgerRequest};
+use anyhow::Result;
+use bitcoin::hashes::Hash;
+use bitcoin::OutPoint;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protobuf::MessageField;
+use protorune::tables::OUTPOINT_SPENDABLE_BY;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn amount(entry: &LedgerEntry) -> u128 {
+    entry.amount.clone().unwrap_or_default().into()
+}
+
+#[wasm_bindgen_test]
+fn test_ledger_records_mint_and_spend() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let mint_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&mint_block, 840_000)?;
+    let minted = OutPoint {
+        txid: mint_block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+
+    // hand the minted tokens back to the alkane, which keeps them
+    let mut spend_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]],
+        vec![Cellpack {
+            target: alkane.clone(),
+            inputs: vec![3],
+        }],
+    );
+    spend_block.txdata[1].input[0].previous_output = minted;
+    index_block(&spend_block, 840_001)?;
+    let spend_txid = spend_block.txdata.last().unwrap().compute_txid();
+
+    let by_alkane = ledger(&LedgerRequest {
+        alkane: MessageField::some(alkane.clone().into()),
+        ..Default::default()
+    })?;
+    assert_eq!(by_alkane.total, 3);
+    let entries = by_alkane.entries;
+    assert!(!entries[0].debit);
+    assert_eq!(amount(&entries[0]), 100);
+    assert_eq!(entries[0].txid, minted.txid.as_byte_array().to_vec());
+    assert_eq!(entries[0].vout, 0);
+    assert_eq!(entries[0].height, 840_000);
+    assert!(entries[1].debit);
+    assert_eq!(entries[1].address, entries[0].address);
+    assert_eq!(entries[1].txid, spend_txid.as_byte_array().to_vec());
+    assert_eq!(entries[1].height, 840_001);
+    assert!(!entries[2].debit);
+    assert_eq!(amount(&entries[2]), 100);
+    let holder: AlkaneId = entries[2].holder.clone().unwrap().into();
+    assert_eq!(holder, alkane);
+
+    let address = OUTPOINT_SPENDABLE_BY
+        .select(&consensus_encode(&minted)?)
+        .get()
+        .as_ref()
+        .clone();
+    assert_eq!(entries[0].address, address);
+    let by_address = ledger(&LedgerRequest {
+        address,
+        offset: 1,
+        ..Default::default()
+    })?;
+    assert_eq!(by_address.total, 2);
+    assert_eq!(by_address.entries.len(), 1);
+    assert!(by_address.entries[0].debit);
+
+    let by_holder = ledger(&LedgerRequest {
+        holder: MessageField::some(alkane.into()),
+        ..Default::default()
+    })?;
+    assert_eq!(by_holder.total, 1);
+    Ok(())
+}
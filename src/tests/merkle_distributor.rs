@@ -0,0 +1,203 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{alkanes_std_merkle_distributor_build, alkanes_std_test_build};
+use crate::view::{self, call_view};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::sha256;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::blockdata::constants::MAX_SCRIPT_ELEMENT_SIZE;
+use bitcoin::script::{self, PushBytes};
+use bitcoin::{opcodes, OutPoint, ScriptBuf, Transaction, Witness};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const DISTRIBUTOR: AlkaneId = AlkaneId { block: 2, tx: 2 };
+
+fn leaf(version: u128, script: &[u8], index: u32, amount: u128) -> Vec<u8> {
+    let mut result = vec![];
+    if version == 1 {
+        result.extend(&(script.len() as u32).to_le_bytes());
+    }
+    result.extend(script);
+    result.extend(&index.to_le_bytes());
+    result.extend(&amount.to_le_bytes());
+    result
+}
+
+// a two leaf tree, the root of it and the proof for each leaf
+fn tree(leaves: [&Vec<u8>; 2]) -> ([u8; 32], [Vec<u8>; 2]) {
+    let hashes = leaves.map(|v| sha256(v));
+    let root = sha256(&[hashes[0], hashes[1]].concat());
+    (root, [hashes[1].to_vec(), hashes[0].to_vec()])
+}
+
+// the claim payload goes in an envelope as is, where deploys gzip theirs
+fn raw_witness(payload: Vec<u8>) -> Witness {
+    let mut builder = script::Builder::new()
+        .push_opcode(opcodes::OP_FALSE)
+        .push_opcode(opcodes::all::OP_IF)
+        .push_slice(*b"BIN")
+        .push_slice([0u8; 0]);
+    for chunk in payload.chunks(MAX_SCRIPT_ELEMENT_SIZE) {
+        builder = builder.push_slice::<&PushBytes>(chunk.try_into().unwrap());
+    }
+    let mut witness = Witness::new();
+    witness.push(builder.push_opcode(opcodes::all::OP_ENDIF).into_script());
+    witness.push([]);
+    witness
+}
+
+// mints 100 of TOKEN and locks them all in a distributor for `root`
+fn deploy(root: [u8; 32], version: Option<u128>) -> Result<()> {
+    let mut inputs = vec![
+        0,
+        2,
+        u128::from_le_bytes(root[..16].try_into()?),
+        u128::from_le_bytes(root[16..].try_into()?),
+    ];
+    inputs.extend(version);
+    let block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_test_build::get_bytes(),
+            alkanes_std_merkle_distributor_build::get_bytes(),
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![4],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs,
+            },
+        ],
+    );
+    index_block(&block, 840_000)?;
+    Ok(())
+}
+
+// a claim paying output 0, which is given `script`
+fn claim(payload: Vec<u8>, script: &[u8], previous_output: OutPoint) -> Transaction {
+    let mut tx = alkane_helpers::create_multiple_cellpack_with_witness_and_in(
+        raw_witness(payload),
+        vec![Cellpack {
+            target: DISTRIBUTOR,
+            inputs: vec![1],
+        }],
+        previous_output,
+        false,
+    );
+    tx.output[0].script_pubkey = ScriptBuf::from_bytes(script.to_vec());
+    tx
+}
+
+fn token_balance(outpoint: &OutPoint) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(outpoint)?),
+    )
+    .get(&ProtoruneRuneId {
+        block: TOKEN.block,
+        tx: TOKEN.tx,
+    }))
+}
+
+fn is_claimed(index: u128) -> Result<bool> {
+    Ok(u128::from_le_bytes(
+        call_view(&DISTRIBUTOR, &vec![2, index], FUEL)?
+            .as_slice()
+            .try_into()?,
+    ) != 0)
+}
+
+fn revert_message(tx: &Transaction) -> Result<Option<String>> {
+    let trace: Trace = view::trace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    Ok(match events.last().cloned() {
+        Some(TraceEvent::RevertContext(response)) => {
+            Some(String::from_utf8_lossy(&response.inner.data).to_string())
+        }
+        _ => None,
+    })
+}
+
+#[wasm_bindgen_test]
+fn test_p2tr_leaf_claims_once() -> Result<()> {
+    clear();
+    let mut p2tr = vec![0x51, 0x20];
+    p2tr.extend([0x7a; 32]);
+    let claimed = leaf(1, &p2tr, 0, 60);
+    let other = leaf(1, &[0x00, 0x14, 0x01, 0x02], 1, 40);
+    let (root, proofs) = tree([&claimed, &other]);
+    deploy(root, Some(1))?;
+    assert!(!is_claimed(0)?);
+
+    let payload = [claimed.clone(), proofs[0].clone()].concat();
+    let first = claim(payload.clone(), &p2tr, OutPoint::null());
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(840_001), first.clone()]),
+        840_001,
+    )?;
+    assert_eq!(revert_message(&first)?, None);
+    assert_eq!(token_balance(&OutPoint::new(first.compute_txid(), 0))?, 60);
+    assert!(is_claimed(0)?);
+    assert!(!is_claimed(1)?);
+
+    // the same leaf again pays nothing
+    let second = claim(payload, &p2tr, OutPoint::new(first.compute_txid(), 1));
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(840_002), second.clone()]),
+        840_002,
+    )?;
+    assert!(revert_message(&second)?
+        .unwrap()
+        .contains("has already been claimed"));
+    assert_eq!(token_balance(&OutPoint::new(second.compute_txid(), 0))?, 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_legacy_leaf_starting_with_one() -> Result<()> {
+    clear();
+    // the first script byte of a legacy leaf used to be taken for a version
+    let mut script = vec![0x01];
+    script.extend([0x33; 19]);
+    let claimed = leaf(0, &script, 0, 25);
+    let other = leaf(0, &[0x44; 20], 1, 75);
+    let (root, proofs) = tree([&claimed, &other]);
+    deploy(root, None)?;
+
+    let tx = claim(
+        [claimed, proofs[0].clone()].concat(),
+        &script,
+        OutPoint::null(),
+    );
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(840_001), tx.clone()]),
+        840_001,
+    )?;
+    assert_eq!(revert_message(&tx)?, None);
+    assert_eq!(token_balance(&OutPoint::new(tx.compute_txid(), 0))?, 25);
+    assert!(is_claimed(0)?);
+    Ok(())
+}
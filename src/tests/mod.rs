@@ -12,22 +12,62 @@ pub mod abi_test;
 #[cfg(test)]
 pub mod alkane;
 #[cfg(test)]
+pub mod amm;
+#[cfg(test)]
 pub mod auth_token;
 #[cfg(test)]
+pub mod bytecode;
+#[cfg(test)]
 pub mod crash;
 #[cfg(test)]
+pub mod crypto;
+#[cfg(test)]
 pub mod edict_then_message;
 #[cfg(test)]
+pub mod events;
+#[cfg(test)]
 pub mod forge;
 #[cfg(test)]
+pub mod free_mint;
+#[cfg(test)]
 pub mod fuel;
 #[cfg(test)]
 pub mod genesis;
 #[cfg(test)]
+pub mod history;
+#[cfg(test)]
+pub mod holders;
+#[cfg(test)]
+pub mod ledger;
+#[cfg(test)]
+pub mod merkle_distributor;
+#[cfg(test)]
+pub mod module_cache;
+#[cfg(test)]
 pub mod networks;
 #[cfg(test)]
+pub mod orbital_collection;
+#[cfg(test)]
+pub mod otc;
+#[cfg(test)]
+pub mod overrides;
+#[cfg(test)]
+pub mod refunder;
+#[cfg(test)]
+pub mod registry;
+#[cfg(test)]
+pub mod reorg;
+#[cfg(test)]
 pub mod serialization;
 #[cfg(test)]
+pub mod simulate_transaction;
+#[cfg(test)]
+pub mod storage_diff;
+#[cfg(test)]
+pub mod validation;
+#[cfg(test)]
 pub mod vec_input_test;
 #[cfg(test)]
+pub mod vesting;
+#[cfg(test)]
 pub mod view;
@@ -0,0 +1,91 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::overrides::simulate_with_overrides;
+use crate::tests::std::{alkanes_std_owned_token_build, alkanes_std_test_build};
+use crate::view::{call_view, StateOverride};
+use crate::vm::cache;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+#[wasm_bindgen_test]
+fn test_module_cache() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+    // the deploy compiled the alkane once
+    assert_eq!(cache::len(), 1);
+
+    for _ in 0..3 {
+        assert_eq!(
+            call_view(&alkane, &vec![5], FUEL)?,
+            vec![0x05, 0x06, 0x07, 0x08]
+        );
+    }
+    assert_eq!(cache::len(), 1);
+
+    // new bytecode at the same location hashes differently and compiles anew
+    let overrides = vec![StateOverride {
+        id: alkane,
+        storage: vec![(b"/totalsupply".to_vec(), 7u128.to_le_bytes().to_vec())],
+        bytecode: Some(alkanes_std_owned_token_build::get_bytes()),
+        ..Default::default()
+    }];
+    assert_eq!(
+        simulate_with_overrides(&alkane, vec![101], &overrides)?,
+        7u128.to_le_bytes().to_vec()
+    );
+    assert_eq!(cache::len(), 2);
+    assert_eq!(
+        call_view(&alkane, &vec![5], FUEL)?,
+        vec![0x05, 0x06, 0x07, 0x08]
+    );
+
+    // a clone shares its factory's code hash, and with it the module
+    index_block(
+        &alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            vec![alkanes_std_test_build::get_bytes(), vec![]],
+            vec![
+                Cellpack {
+                    target: AlkaneId { block: 1, tx: 0 },
+                    inputs: vec![4],
+                },
+                Cellpack {
+                    target: AlkaneId { block: 5, tx: 2 },
+                    inputs: vec![4],
+                },
+            ],
+        ),
+        840_001,
+    )?;
+    assert_eq!(cache::len(), 1);
+    assert_eq!(
+        call_view(&AlkaneId { block: 2, tx: 3 }, &vec![5], FUEL)?,
+        vec![0x05, 0x06, 0x07, 0x08]
+    );
+    assert_eq!(cache::len(), 1);
+
+    // nothing compiled in one block carries into the next
+    index_block(
+        &alkane_helpers::init_with_multiple_cellpacks_with_tx(vec![], vec![]),
+        840_002,
+    )?;
+    assert_eq!(cache::len(), 0);
+    Ok(())
+}
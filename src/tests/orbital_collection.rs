@@ -0,0 +1,241 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{
+    alkanes_std_auth_token_build, alkanes_std_orbital_build, alkanes_std_orbital_collection_build,
+    alkanes_std_owned_token_build,
+};
+use crate::view::call_view;
+use alkane_helpers::clear;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::{cellpack::Cellpack, constants::AUTH_TOKEN_FACTORY_ID};
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::BalanceSheetOperations;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+#[wasm_bindgen_test]
+fn test_orbital_collection() -> Result<()> {
+    clear();
+    let template = AlkaneId { block: 2, tx: 1 };
+    let collection = AlkaneId { block: 2, tx: 2 };
+    // the collection's auth token takes 2:3
+    let items = [AlkaneId { block: 2, tx: 4 }, AlkaneId { block: 2, tx: 5 }];
+    let mint = Cellpack {
+        target: collection,
+        inputs: vec![77],
+    };
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_orbital_build::get_bytes(),
+            alkanes_std_orbital_collection_build::get_bytes(),
+            vec![],
+            vec![],
+            vec![],
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![
+                    0,
+                    u128::from_le_bytes(*b"ORBS\0\0\0\0\0\0\0\0\0\0\0\0"),
+                    u128::from_le_bytes(*b"ORB\0\0\0\0\0\0\0\0\0\0\0\0\0"),
+                    2, /* max supply */
+                    template.block,
+                    template.tx,
+                    0, /* price block */
+                    0, /* price tx */
+                    0, /* price amount */
+                    1, /* auth token units */
+                ],
+            },
+            mint.clone(),
+            mint.clone(),
+            // past the max supply
+            mint,
+        ],
+    );
+    index_block(&test_block, 840_000)?;
+
+    assert_eq!(
+        call_view(&collection, &vec![101], FUEL)?,
+        2u128.to_le_bytes().to_vec()
+    );
+    let template_bytes: Vec<u8> = template.into();
+    assert_eq!(call_view(&collection, &vec![107], FUEL)?, template_bytes);
+    let item_bytes: Vec<u8> = items[1].into();
+    assert_eq!(call_view(&collection, &vec![103, 1], FUEL)?, item_bytes);
+    let all_items: Vec<u8> = items
+        .iter()
+        .flat_map(|v| <AlkaneId as Into<Vec<u8>>>::into(*v))
+        .collect();
+    assert_eq!(call_view(&collection, &vec![104, 0, 0], FUEL)?, all_items);
+
+    // items answer from the collection they were minted by
+    let collection_bytes: Vec<u8> = collection.into();
+    assert_eq!(call_view(&items[0], &vec![103], FUEL)?, collection_bytes);
+    assert_eq!(
+        call_view(&items[1], &vec![102], FUEL)?,
+        1u128.to_le_bytes().to_vec()
+    );
+    assert_eq!(call_view(&items[1], &vec![99], FUEL)?, b"ORBS #1".to_vec());
+    assert_eq!(call_view(&items[0], &vec![100], FUEL)?, b"ORB".to_vec());
+    // the template stays a plain orbital
+    assert_eq!(call_view(&template, &vec![99], FUEL)?, b"NFT".to_vec());
+    Ok(())
+}
+
+// calls `target` with only `parcel` out of what `previous_output` holds
+fn call(
+    previous_output: OutPoint,
+    parcel: Vec<(AlkaneId, u128)>,
+    target: AlkaneId,
+    inputs: Vec<u128>,
+    height: u32,
+) -> Result<Transaction> {
+    let tx = alkane_helpers::create_cellpack_with_parcel(
+        previous_output,
+        parcel,
+        Cellpack { target, inputs },
+    );
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]),
+        height,
+    )?;
+    Ok(tx)
+}
+
+fn balance_at(tx: &Transaction, id: AlkaneId) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint::new(tx.compute_txid(), 0))?),
+    )
+    .get(&id.into()))
+}
+
+#[wasm_bindgen_test]
+fn test_orbital_collection_paid_mint() -> Result<()> {
+    clear();
+    let token = AlkaneId { block: 2, tx: 1 };
+    let token_auth = AlkaneId { block: 2, tx: 2 };
+    let template = AlkaneId { block: 2, tx: 3 };
+    let collection = AlkaneId { block: 2, tx: 4 };
+    let collection_auth = AlkaneId { block: 2, tx: 5 };
+    let item = AlkaneId { block: 2, tx: 6 };
+    let price = 100;
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+            alkanes_std_orbital_build::get_bytes(),
+            alkanes_std_orbital_collection_build::get_bytes(),
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1, 1000],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![
+                    0,
+                    u128::from_le_bytes(*b"ORBS\0\0\0\0\0\0\0\0\0\0\0\0"),
+                    u128::from_le_bytes(*b"ORB\0\0\0\0\0\0\0\0\0\0\0\0\0"),
+                    2, /* max supply */
+                    template.block,
+                    template.tx,
+                    token.block,
+                    token.tx,
+                    price,
+                    1, /* auth token units */
+                ],
+            },
+        ],
+    );
+    index_block(&deploy_block, 840_000)?;
+    let deployed = OutPoint::new(deploy_block.txdata.last().unwrap().compute_txid(), 0);
+
+    // one unit short of the price mints nothing and refunds the payment
+    let tx = call(
+        deployed,
+        vec![(token, price - 1)],
+        collection,
+        vec![77],
+        840_001,
+    )?;
+    assert_eq!(balance_at(&tx, token)?, 1000);
+    assert_eq!(
+        call_view(&collection, &vec![101], FUEL)?,
+        0u128.to_le_bytes().to_vec()
+    );
+
+    // overpaying returns the change, and anything else sent along
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(token, price + 50), (token_auth, 1)],
+        collection,
+        vec![77],
+        840_002,
+    )?;
+    assert_eq!(balance_at(&tx, token)?, 1000 - price);
+    assert_eq!(balance_at(&tx, token_auth)?, 1);
+    assert_eq!(balance_at(&tx, item)?, 1);
+    let item_bytes: Vec<u8> = item.into();
+    assert_eq!(call_view(&collection, &vec![103, 0], FUEL)?, item_bytes);
+
+    // the token's auth token does not control the collection
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(token_auth, 1)],
+        collection,
+        vec![2],
+        840_003,
+    )?;
+    assert_eq!(balance_at(&tx, token)?, 1000 - price);
+
+    // the collection's auth token withdraws the proceeds
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(collection_auth, 1)],
+        collection,
+        vec![2],
+        840_004,
+    )?;
+    assert_eq!(balance_at(&tx, token)?, 1000);
+    assert_eq!(balance_at(&tx, collection_auth)?, 1);
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{
+    alkanes_std_auth_token_build, alkanes_std_otc_build, alkanes_std_owned_token_build,
+};
+use crate::view::call_view;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::AUTH_TOKEN_FACTORY_ID;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::{Amount, OutPoint, ScriptBuf, Transaction, TxOut, Witness};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::BalanceSheetOperations;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const TOKEN_AUTH: AlkaneId = AlkaneId { block: 2, tx: 2 };
+const OTC: AlkaneId = AlkaneId { block: 2, tx: 3 };
+// the auth token of the first listing
+const LISTING_AUTH: AlkaneId = AlkaneId { block: 2, tx: 4 };
+
+const ASK: u64 = 50_000;
+
+const OPEN: u128 = 0;
+const SOLD: u128 = 1;
+const CANCELLED: u128 = 2;
+
+// the seller's payout script, a P2WPKH
+fn seller_script() -> ScriptBuf {
+    let mut script = vec![0x00, 0x14];
+    script.extend([0xab; 20]);
+    ScriptBuf::from_bytes(script)
+}
+
+fn index(tx: &Transaction, height: u32) -> Result<()> {
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]),
+        height,
+    )
+}
+
+// deploys the escrow and lists 100 of TOKEN for each height in `listings`,
+// all asking ASK to the seller's script. The returned outpoint holds the
+// unlisted TOKEN and every auth token
+fn deploy(listings: &[u32]) -> Result<OutPoint> {
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+            alkanes_std_otc_build::get_bytes(),
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1, 1000],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![101],
+            },
+        ],
+    );
+    index_block(&deploy_block, 840_000)?;
+    let mut outpoint = OutPoint::new(deploy_block.txdata.last().unwrap().compute_txid(), 0);
+    let script = seller_script().into_bytes();
+    let words: Vec<u128> = script
+        .chunks(16)
+        .map(|v| {
+            let mut word = [0u8; 16];
+            word[..v.len()].copy_from_slice(v);
+            u128::from_le_bytes(word)
+        })
+        .collect();
+    for height in listings {
+        let mut inputs = vec![1, ASK as u128, script.len() as u128, words.len() as u128];
+        inputs.extend(&words);
+        let tx = alkane_helpers::create_cellpack_with_parcel(
+            outpoint,
+            vec![(TOKEN, 100)],
+            Cellpack {
+                target: OTC,
+                inputs,
+            },
+        );
+        index(&tx, *height)?;
+        outpoint = OutPoint::new(tx.compute_txid(), 0);
+    }
+    Ok(outpoint)
+}
+
+// a transaction buying each of `listings` in turn, paying `sats` to `script`
+// on output 0, where whatever is bought also goes
+fn buy(listings: &[u128], script: ScriptBuf, sats: u64, height: u32) -> Result<Transaction> {
+    let mut tx = alkane_helpers::create_multiple_cellpack_with_witness_and_in(
+        Witness::new(),
+        listings
+            .iter()
+            .map(|listing| Cellpack {
+                target: OTC,
+                inputs: vec![2, *listing],
+            })
+            .collect(),
+        OutPoint::null(),
+        false,
+    );
+    tx.output[0] = TxOut {
+        value: Amount::from_sat(sats),
+        script_pubkey: script,
+    };
+    index(&tx, height)?;
+    Ok(tx)
+}
+
+fn cancel(
+    previous_output: OutPoint,
+    auth: AlkaneId,
+    listing: u128,
+    height: u32,
+) -> Result<Transaction> {
+    let tx = alkane_helpers::create_cellpack_with_parcel(
+        previous_output,
+        vec![(auth, 1)],
+        Cellpack {
+            target: OTC,
+            inputs: vec![3, listing],
+        },
+    );
+    index(&tx, height)?;
+    Ok(tx)
+}
+
+fn balance_at(tx: &Transaction, id: AlkaneId) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint::new(tx.compute_txid(), 0))?),
+    )
+    .get(&id.into()))
+}
+
+// the status field of GetListing, after the listed alkane, amount and ask
+fn status(listing: u128) -> Result<u128> {
+    let data = call_view(&OTC, &vec![100, listing], FUEL)?;
+    Ok(u128::from_le_bytes(data[64..80].try_into()?))
+}
+
+#[wasm_bindgen_test]
+fn test_otc_buy_with_paying_output() -> Result<()> {
+    clear();
+    deploy(&[840_001])?;
+    assert_eq!(status(0)?, OPEN);
+
+    let tx = buy(&[0], seller_script(), ASK, 840_002)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 100);
+    assert_eq!(status(0)?, SOLD);
+
+    // a sold listing cannot be bought again
+    let tx = buy(&[0], seller_script(), ASK + 1, 840_003)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_otc_buy_without_payment() -> Result<()> {
+    clear();
+    deploy(&[840_001])?;
+
+    // one sat short of the ask
+    let tx = buy(&[0], seller_script(), ASK - 1, 840_002)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    assert_eq!(status(0)?, OPEN);
+
+    // the full ask, but to a script other than the seller's
+    let mut other_script = seller_script().into_bytes();
+    other_script[2] = 0xcd;
+    let tx = buy(&[0], ScriptBuf::from_bytes(other_script), ASK, 840_003)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    assert_eq!(status(0)?, OPEN);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_otc_payment_output_settles_one_listing() -> Result<()> {
+    clear();
+    deploy(&[840_001, 840_002])?;
+
+    // both listings ask the same amount to the same script, so a single
+    // output would satisfy either, but it may only pay for one of them
+    let tx = buy(&[0, 1], seller_script(), ASK, 840_003)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 100);
+    assert_eq!(status(0)?, SOLD);
+    assert_eq!(status(1)?, OPEN);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_otc_cancel() -> Result<()> {
+    clear();
+    let seller = deploy(&[840_001])?;
+
+    // the token's own auth token does not control the listing
+    let tx = cancel(seller, TOKEN_AUTH, 0, 840_002)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 900);
+    assert_eq!(status(0)?, OPEN);
+
+    let tx = cancel(OutPoint::new(tx.compute_txid(), 0), LISTING_AUTH, 0, 840_003)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 1000);
+    assert_eq!(balance_at(&tx, LISTING_AUTH)?, 1);
+    assert_eq!(status(0)?, CANCELLED);
+
+    // nor can a cancelled listing be bought
+    let tx = buy(&[0], seller_script(), ASK, 840_004)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{alkanes_std_owned_token_build, alkanes_std_test_build};
+use crate::utils::balance_pointer;
+use crate::view::{apply_overrides, call_view, simulate_request, StateOverride};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::parcel::AlkaneTransfer;
+use alkanes_support::proto;
+use anyhow::Result;
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+// a simulate request for `inputs` on `alkane` with `overrides`, in the form
+// the simulate export parses from a client
+pub fn simulate_with_overrides(
+    alkane: &AlkaneId,
+    inputs: Vec<u128>,
+    overrides: &[StateOverride],
+) -> Result<Vec<u8>> {
+    let mut request = proto::alkanes::MessageContextParcel::new();
+    request.calldata = Cellpack {
+        target: alkane.clone(),
+        inputs,
+    }
+    .encipher();
+    for state in overrides {
+        let mut v = proto::alkanes::AlkaneOverride::new();
+        v.id = Some(state.id.into()).into();
+        for (key, value) in &state.storage {
+            let mut pair = proto::alkanes::KeyValuePair::new();
+            pair.key = key.clone();
+            pair.value = value.clone();
+            v.storage.push(pair);
+        }
+        v.bytecode = state.bytecode.clone().unwrap_or_default();
+        v.balances = state.balances.iter().cloned().map(Into::into).collect();
+        request.overrides.push(v);
+    }
+    let (response, _gas_used) = simulate_request(request)?;
+    Ok(response.data)
+}
+
+#[wasm_bindgen_test]
+fn test_simulate_with_overrides() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+
+    // the test alkane has no opcode 101
+    assert!(call_view(&alkane, &vec![101], FUEL).is_err());
+
+    // swapped for the owned token, 101 reads the overridden total supply
+    let overrides = vec![StateOverride {
+        id: alkane,
+        storage: vec![(b"/totalsupply".to_vec(), 42u128.to_le_bytes().to_vec())],
+        bytecode: Some(alkanes_std_owned_token_build::get_bytes()),
+        balances: vec![],
+    }];
+    let data = simulate_with_overrides(&alkane, vec![101], &overrides)?;
+    assert_eq!(data, 42u128.to_le_bytes().to_vec());
+
+    // nothing sticks once the view returns
+    assert!(call_view(&alkane, &vec![101], FUEL).is_err());
+    assert_eq!(
+        IndexPointer::from_keyword("/alkanes/")
+            .select(&alkane.into())
+            .keyword("/storage/")
+            .select(&b"/totalsupply".to_vec())
+            .get()
+            .len(),
+        0
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_override_balances_and_bytecode_validation() -> Result<()> {
+    clear();
+    let who = AlkaneId { block: 2, tx: 1 };
+    let what = AlkaneId { block: 2, tx: 2 };
+    let mut atomic = AtomicPointer::default();
+    apply_overrides(
+        &mut atomic,
+        840_000,
+        &[StateOverride {
+            id: who,
+            balances: vec![AlkaneTransfer {
+                id: what,
+                value: 1000,
+            }],
+            ..Default::default()
+        }],
+    )?;
+    assert_eq!(
+        balance_pointer(&mut atomic, &who, &what).get_value::<u128>(),
+        1000
+    );
+    atomic.rollback();
+    assert_eq!(
+        balance_pointer(&mut AtomicPointer::default(), &who, &what).get_value::<u128>(),
+        0
+    );
+
+    // replacement bytecode is held to the same rules as a deploy
+    assert!(apply_overrides(
+        &mut AtomicPointer::default(),
+        840_000,
+        &[StateOverride {
+            id: who,
+            bytecode: Some(vec![0x00, 0x61, 0x73, 0x6d]),
+            ..Default::default()
+        }],
+    )
+    .is_err());
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{
+    alkanes_std_auth_token_build, alkanes_std_refunder_build, alkanes_std_test_build,
+};
+use crate::utils::balance_pointer;
+use crate::view::{self, call_view};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::AUTH_TOKEN_FACTORY_ID;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::parcel::AlkaneTransferParcel;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction, Witness};
+use metashrew_core::index_pointer::AtomicPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+use std::io::Cursor;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+const REFUNDER: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const AUTH_TOKEN: AlkaneId = AlkaneId { block: 2, tx: 2 };
+const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 3 };
+
+// the refunder with its auth token on the first outpoint returned, and 100 of
+// TOKEN alone on the second
+fn deploy() -> Result<(OutPoint, OutPoint)> {
+    let refunder_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_refunder_build::get_bytes(),
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1],
+            },
+        ],
+    );
+    index_block(&refunder_block, 840_000)?;
+    let token_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![alkanes_std_test_build::get_bytes()],
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&token_block, 840_001)?;
+    let last = |block: &bitcoin::Block| OutPoint {
+        txid: block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+    Ok((last(&refunder_block), last(&token_block)))
+}
+
+// calls the refunder with whatever `previous_output` holds and indexes it
+fn call(inputs: Vec<u128>, previous_output: OutPoint, height: u32) -> Result<Transaction> {
+    let tx = alkane_helpers::create_multiple_cellpack_with_witness_and_in(
+        Witness::new(),
+        vec![Cellpack {
+            target: REFUNDER,
+            inputs,
+        }],
+        previous_output,
+        false,
+    );
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]),
+        height,
+    )?;
+    Ok(tx)
+}
+
+fn balance_at(tx: &Transaction, id: AlkaneId) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint::new(tx.compute_txid(), 0))?),
+    )
+    .get(&ProtoruneRuneId {
+        block: id.block,
+        tx: id.tx,
+    }))
+}
+
+fn held_by_refunder(id: AlkaneId) -> u128 {
+    balance_pointer(&mut AtomicPointer::default(), &REFUNDER, &id).get_value::<u128>()
+}
+
+fn reverted(tx: &Transaction) -> Result<bool> {
+    let trace: Trace = view::trace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    Ok(matches!(events.last(), Some(TraceEvent::RevertContext(_))))
+}
+
+#[wasm_bindgen_test]
+fn test_refund_forwards_to_pointer() -> Result<()> {
+    clear();
+    let (_, tokens) = deploy()?;
+    let tx = call(vec![50], tokens, 840_002)?;
+    assert!(!reverted(&tx)?);
+    assert_eq!(balance_at(&tx, TOKEN)?, 100);
+    assert_eq!(held_by_refunder(TOKEN), 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_escrow_and_recover() -> Result<()> {
+    clear();
+    let (auth, tokens) = deploy()?;
+
+    let escrow = call(vec![51], tokens, 840_002)?;
+    assert!(!reverted(&escrow)?);
+    assert_eq!(balance_at(&escrow, TOKEN)?, 0);
+    assert_eq!(held_by_refunder(TOKEN), 100);
+    let escrowed =
+        AlkaneTransferParcel::parse(&mut Cursor::new(call_view(&REFUNDER, &vec![1000], FUEL)?))?;
+    assert_eq!(escrowed.0.len(), 1);
+    assert_eq!(escrowed.0[0].id, TOKEN);
+    assert_eq!(escrowed.0[0].value, 100);
+
+    // without the auth token nothing is paid out
+    let denied = call(
+        vec![52, TOKEN.block, TOKEN.tx, 0],
+        OutPoint::new(escrow.compute_txid(), 0),
+        840_003,
+    )?;
+    assert!(reverted(&denied)?);
+    assert_eq!(balance_at(&denied, TOKEN)?, 0);
+    assert_eq!(held_by_refunder(TOKEN), 100);
+
+    let partial = call(vec![52, TOKEN.block, TOKEN.tx, 30], auth, 840_004)?;
+    assert!(!reverted(&partial)?);
+    assert_eq!(balance_at(&partial, TOKEN)?, 30);
+    assert_eq!(balance_at(&partial, AUTH_TOKEN)?, 1);
+    assert_eq!(held_by_refunder(TOKEN), 70);
+
+    // an amount of 0 pays out the rest
+    let rest = call(
+        vec![52, TOKEN.block, TOKEN.tx, 0],
+        OutPoint::new(partial.compute_txid(), 0),
+        840_005,
+    )?;
+    assert!(!reverted(&rest)?);
+    assert_eq!(balance_at(&rest, TOKEN)?, 100);
+    assert_eq!(balance_at(&rest, AUTH_TOKEN)?, 1);
+    assert_eq!(held_by_refunder(TOKEN), 0);
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::index_block;
+use crate::network::genesis_alkane_bytes;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::sha256;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::Result;
+use bitcoin::hashes::Hash;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use protobuf::MessageField;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn ids(response: &proto::alkanes::AlkanesResponse) -> Vec<AlkaneId> {
+    response
+        .alkanes
+        .iter()
+        .map(|v| v.id.clone().unwrap().into())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn test_alkanes_registry() -> Result<()> {
+    clear();
+    let template = AlkaneId { block: 2, tx: 1 };
+    let clone_cellpack = Cellpack {
+        target: AlkaneId { block: 5, tx: 1 },
+        inputs: vec![4],
+    };
+    let first_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![4],
+            },
+            clone_cellpack.clone(),
+        ],
+    );
+    index_block(&first_block, 840_000)?;
+    let second_block =
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(vec![vec![]], vec![clone_cellpack]);
+    index_block(&second_block, 840_001)?;
+
+    let all = view::alkanes(&proto::alkanes::AlkanesRequest::new())?;
+    assert_eq!(all.total, 4);
+    assert_eq!(
+        ids(&all),
+        vec![
+            AlkaneId::new(2, 0),
+            template,
+            AlkaneId::new(2, 2),
+            AlkaneId::new(2, 3)
+        ]
+    );
+    // the genesis alkane is recorded when genesis runs, ahead of the block
+    let genesis = &all.alkanes[0];
+    assert_eq!(
+        genesis.kind.enum_value_or_default(),
+        proto::alkanes::AlkaneCreationKind::CREATE
+    );
+    assert_eq!(genesis.height, 840_000);
+    assert_eq!(genesis.code_hash, sha256(&genesis_alkane_bytes()).to_vec());
+    let created = &all.alkanes[1];
+    assert_eq!(
+        created.kind.enum_value_or_default(),
+        proto::alkanes::AlkaneCreationKind::CREATE
+    );
+    assert_eq!(created.height, 840_000);
+    assert_eq!(
+        created.txid,
+        first_block.txdata[1]
+            .compute_txid()
+            .as_byte_array()
+            .to_vec()
+    );
+    assert_eq!(
+        created.code_hash,
+        sha256(&alkanes_std_test_build::get_bytes()).to_vec()
+    );
+    let cloned = &all.alkanes[3];
+    assert_eq!(
+        cloned.kind.enum_value_or_default(),
+        proto::alkanes::AlkaneCreationKind::FACTORY
+    );
+    let cloned_from: AlkaneId = cloned.template.clone().unwrap().into();
+    assert_eq!(cloned_from, template);
+    assert_eq!(cloned.height, 840_001);
+    assert_eq!(cloned.code_hash, created.code_hash);
+
+    let mut request = proto::alkanes::AlkanesRequest::new();
+    request.template = MessageField::some(template.into());
+    let clones = view::alkanes(&request)?;
+    assert_eq!(clones.total, 2);
+    assert_eq!(ids(&clones), vec![AlkaneId::new(2, 2), AlkaneId::new(2, 3)]);
+
+    let mut request = proto::alkanes::AlkanesRequest::new();
+    request.to_height = 840_000;
+    request.offset = 2;
+    request.limit = 1;
+    let page = view::alkanes(&request)?;
+    assert_eq!(page.total, 3);
+    assert_eq!(ids(&page), vec![AlkaneId::new(2, 2)]);
+
+    let mut request = proto::alkanes::AlkanesRequest::new();
+    request.from_height = 840_001;
+    assert_eq!(ids(&view::alkanes(&request)?), vec![AlkaneId::new(2, 3)]);
+
+    // a range past every record, and an offset past the range, are empty
+    let mut request = proto::alkanes::AlkanesRequest::new();
+    request.from_height = 840_002;
+    let page = view::alkanes(&request)?;
+    assert_eq!(page.total, 0);
+    assert!(page.alkanes.is_empty());
+    let mut request = proto::alkanes::AlkanesRequest::new();
+    request.offset = 10;
+    let page = view::alkanes(&request)?;
+    assert_eq!(page.total, 4);
+    assert!(page.alkanes.is_empty());
+    Ok(())
+}
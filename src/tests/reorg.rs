@@ -0,0 +1,178 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{
+    self as alkane_helpers, assert_binary_deployed_to_id, assert_token_id_has_no_deployment,
+};
+use crate::tests::std::alkanes_std_test_build;
+use crate::vm::utils::sequence_pointer;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::blockdata::transaction::Version;
+use bitcoin::{OutPoint, Transaction};
+use metashrew_core::index_pointer::AtomicPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{
+    create_block_with_coinbase_tx, create_block_with_txs, create_coinbase_transaction,
+    create_protostone_transaction, create_rune_etching_transaction, get_rune_balance_by_outpoint,
+    get_txin_from_outpoint, get_txout_transfer_to_address, RunesTestingConfig, ADDRESS1,
+};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables, tables::RuneTable};
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_reorg_rolls_back_alkanes_state() -> Result<()> {
+    clear();
+    let genesis_block = create_block_with_coinbase_tx(840_000);
+    index_block(&genesis_block, 840_000)?;
+
+    let test_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&test_block, 840_001)?;
+
+    let deployed = AlkaneId { block: 2, tx: 1 };
+    let tx = test_block.txdata.last().unwrap();
+    let outpoint = OutPoint {
+        txid: tx.compute_txid(),
+        vout: 0,
+    };
+    let sheet = || {
+        load_sheet(
+            &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&outpoint).unwrap()),
+        )
+    };
+    let trace_outpoint = OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    };
+    assert_binary_deployed_to_id(deployed.clone(), alkanes_std_test_build::get_bytes())?;
+    assert_eq!(sheet().get(&ProtoruneRuneId { block: 2, tx: 1 }), 100);
+    assert_eq!(
+        sequence_pointer(&AtomicPointer::default()).get_value::<u128>(),
+        2
+    );
+    assert!(view::trace(&trace_outpoint)?.len() > 0);
+
+    // a competing block at the same height replaces the one we indexed
+    let replacement_block = create_block_with_coinbase_tx(840_001);
+    index_block(&replacement_block, 840_001)?;
+
+    assert_token_id_has_no_deployment(deployed)?;
+    assert_eq!(sheet().get(&ProtoruneRuneId { block: 2, tx: 1 }), 0);
+    assert_eq!(
+        sequence_pointer(&AtomicPointer::default()).get_value::<u128>(),
+        1
+    );
+    assert_eq!(view::trace(&trace_outpoint)?.len(), 0);
+    assert_eq!(
+        crate::tables::TRACES_BY_HEIGHT
+            .select_value::<u64>(840_001)
+            .get_list()
+            .len(),
+        0
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_reorg_restores_spent_inputs() -> Result<()> {
+    clear();
+    index_block(&create_block_with_coinbase_tx(840_000), 840_000)?;
+
+    // 1000 of a rune etched at 840_001:1
+    let etching = create_rune_etching_transaction(&RunesTestingConfig::default());
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(840_001), etching.clone()]),
+        840_001,
+    )?;
+    let rune_outpoint = OutPoint::new(etching.compute_txid(), 0);
+    let rune = ProtoruneRuneId {
+        block: 840_001,
+        tx: 1,
+    };
+
+    // 100 of the alkane at 2:1
+    let test_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&test_block, 840_002)?;
+    let alkane_outpoint = OutPoint::new(test_block.txdata.last().unwrap().compute_txid(), 0);
+    let alkane = ProtoruneRuneId { block: 2, tx: 1 };
+    let alkane_balance = |outpoint: &OutPoint| {
+        load_sheet(
+            &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(outpoint).unwrap()),
+        )
+        .get(&alkane)
+    };
+
+    // the orphaned block spends the rune with a plain transfer and moves the
+    // alkane on with a protostone
+    let rune_spend = Transaction {
+        version: Version::ONE,
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: vec![get_txin_from_outpoint(rune_outpoint)],
+        output: vec![get_txout_transfer_to_address(&ADDRESS1(), 100_000_000)],
+    };
+    let alkane_spend = create_protostone_transaction(
+        alkane_outpoint,
+        None,
+        false,
+        0,
+        0,
+        AlkaneMessageContext::protocol_tag(),
+        vec![],
+    );
+    let moved = OutPoint::new(alkane_spend.compute_txid(), 0);
+    index_block(
+        &create_block_with_txs(vec![
+            create_coinbase_transaction(840_003),
+            rune_spend,
+            alkane_spend,
+        ]),
+        840_003,
+    )?;
+    assert_eq!(
+        get_rune_balance_by_outpoint(rune_outpoint, vec![rune]),
+        vec![0]
+    );
+    assert_eq!(alkane_balance(&alkane_outpoint), 0);
+    assert_eq!(alkane_balance(&moved), 100);
+
+    index_block(&create_block_with_coinbase_tx(840_003), 840_003)?;
+    assert_eq!(
+        get_rune_balance_by_outpoint(rune_outpoint, vec![rune]),
+        vec![1000]
+    );
+    assert_eq!(alkane_balance(&alkane_outpoint), 100);
+    assert_eq!(alkane_balance(&moved), 0);
+    // only the replacement block's coinbase is listed at the height
+    assert_eq!(
+        tables::RUNES
+            .HEIGHT_TO_TRANSACTION_IDS
+            .select_value::<u64>(840_003)
+            .length(),
+        1
+    );
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view::{holders, simulate_transaction};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{
+    AlkaneTransfer, HoldersRequest, Outpoint, PrevoutBalances, SimulateTransactionRequest,
+};
+use anyhow::Result;
+use bitcoin::absolute::LockTime;
+use bitcoin::hashes::Hash;
+use bitcoin::transaction::Version;
+use bitcoin::{OutPoint, Transaction};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::utils::consensus_encode;
+use protobuf::MessageField;
+use protorune::test_helpers::{get_txin_from_outpoint, get_txout_transfer_to_address, ADDRESS1};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_simulate_transaction() -> Result<()> {
+    clear();
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let supply = || -> Result<u128> {
+        Ok(holders(&HoldersRequest {
+            alkane: MessageField::some(alkane.into()),
+            ..Default::default()
+        })?
+        .supply
+        .clone()
+        .unwrap_or_default()
+        .into())
+    };
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+    assert_eq!(supply()?, 100);
+
+    let transaction = |opcode: u128| {
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            vec![vec![]],
+            vec![Cellpack {
+                target: alkane,
+                inputs: vec![opcode],
+            }],
+        )
+        .txdata[1]
+            .clone()
+    };
+
+    // a mint shows up on the output without being indexed
+    let mint = transaction(4);
+    let response = simulate_transaction(&SimulateTransactionRequest {
+        transaction: consensus_encode(&mint)?,
+        ..Default::default()
+    })?;
+    assert!(response.errors.is_empty());
+    assert_eq!(response.traces.len(), 1);
+    let minted = response
+        .outputs
+        .iter()
+        .flat_map(|v| v.balances.iter())
+        .map(|v| u128::from(v.value.clone().unwrap_or_default()))
+        .sum::<u128>();
+    assert_eq!(minted, 100);
+    assert_eq!(supply()?, 100);
+
+    // opcode 3 reverts unless it is sent exactly one alkane
+    let spend = transaction(3);
+    let response = simulate_transaction(&SimulateTransactionRequest {
+        transaction: consensus_encode(&spend)?,
+        ..Default::default()
+    })?;
+    assert_eq!(response.errors.len(), 1);
+
+    let prevout = spend.input[0].previous_output;
+    let response = simulate_transaction(&SimulateTransactionRequest {
+        transaction: consensus_encode(&spend)?,
+        prevouts: vec![PrevoutBalances {
+            outpoint: MessageField::some(Outpoint {
+                txid: prevout.txid.as_byte_array().to_vec(),
+                vout: prevout.vout,
+                ..Default::default()
+            }),
+            balances: vec![AlkaneTransfer {
+                id: MessageField::some(alkane.into()),
+                value: MessageField::some(50u128.into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    })?;
+    assert!(response.errors.is_empty());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_simulate_transaction_without_runestone() -> Result<()> {
+    clear();
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }],
+    );
+    index_block(&deploy_block, 840_000)?;
+    let payment = |outpoint: OutPoint| Transaction {
+        version: Version::ONE,
+        lock_time: LockTime::ZERO,
+        input: vec![get_txin_from_outpoint(outpoint)],
+        output: vec![get_txout_transfer_to_address(&ADDRESS1(), 1000)],
+    };
+
+    // a plain payment of an outpoint holding nothing simulates cleanly
+    let response = simulate_transaction(&SimulateTransactionRequest {
+        transaction: consensus_encode(&payment(OutPoint::null()))?,
+        ..Default::default()
+    })?;
+    assert!(response.errors.is_empty());
+    assert!(response.traces.is_empty());
+    assert_eq!(response.outputs.len(), 1);
+    assert!(response.outputs[0].balances.is_empty());
+
+    // the minted tokens stay behind on the spent outpoint, as indexed
+    let minted = OutPoint {
+        txid: deploy_block.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+    let response = simulate_transaction(&SimulateTransactionRequest {
+        transaction: consensus_encode(&payment(minted))?,
+        ..Default::default()
+    })?;
+    assert!(response.outputs[0].balances.is_empty());
+    assert_eq!(response.errors.len(), 1);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{alkanes_std_auth_token_build, alkanes_std_owned_token_build};
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::AUTH_TOKEN_FACTORY_ID;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{StorageSlotDiff, Trace, TraceEvent};
+use anyhow::{anyhow, Result};
+use bitcoin::OutPoint;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_trace_records_storage_diff() -> Result<()> {
+    clear();
+    let block_height = 840_000;
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+        ]
+        .into(),
+        [
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1, 1000],
+            },
+        ]
+        .into(),
+    );
+    index_block(&test_block, block_height)?;
+
+    let outpoint = OutPoint {
+        txid: test_block.txdata.last().unwrap().compute_txid(),
+        vout: 3,
+    };
+    let trace: Trace = view::trace(&outpoint)?.try_into()?;
+    let diffs = trace
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|v| match v {
+            TraceEvent::ReturnContext(response) => Some(response.storage_diff.clone()),
+            _ => None,
+        })
+        .collect::<Vec<Vec<StorageSlotDiff>>>();
+
+    // the auth token deployed by the owned token exits first, then the owned
+    // token itself
+    assert_eq!(diffs.len(), 2);
+    assert!(!diffs[0].is_empty());
+    let owned_token = &diffs[1];
+    assert!(owned_token.windows(2).all(|v| v[0].key < v[1].key));
+    let total_supply = owned_token
+        .iter()
+        .find(|v| v.key == b"/totalsupply".to_vec())
+        .ok_or(anyhow!("no diff for /totalsupply"))?;
+    assert_eq!(total_supply.old_value.len(), 0);
+    assert_eq!(total_supply.new_value, 1000u128.to_le_bytes().to_vec());
+    Ok(())
+}
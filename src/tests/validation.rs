@@ -0,0 +1,118 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::view;
+use crate::vm::fuel::{FUEL_SCHEDULE_V0, FUEL_SCHEDULE_V1, FUEL_SCHEDULE_V2};
+use crate::vm::validation::validate_binary;
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::OutPoint;
+use metashrew_core::index_pointer::IndexPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// a module with nothing in it
+const EMPTY_MODULE: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+// a module importing env.nope, which the runtime does not provide
+const UNSUPPORTED_IMPORT: [u8; 28] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x02, 0x0c,
+    0x01, 0x03, 0x65, 0x6e, 0x76, 0x04, 0x6e, 0x6f, 0x70, 0x65, 0x00, 0x00,
+];
+
+// a module importing env.__height as taking nothing, where the runtime's
+// takes the pointer to write the height to
+const MISMATCHED_IMPORT: [u8; 32] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x02, 0x10,
+    0x01, 0x03, 0x65, 0x6e, 0x76, 0x08, 0x5f, 0x5f, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x00, 0x00,
+];
+
+// a module exporting memory and an __execute that clears it with memory.fill,
+// from the bulk memory proposal wasmi runs with
+const BULK_MEMORY: [u8; 66] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f, 0x03,
+    0x02, 0x01, 0x00, 0x05, 0x04, 0x01, 0x00, 0x80, 0x04, 0x07, 0x16, 0x02, 0x09, 0x5f, 0x5f, 0x65,
+    0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x00, 0x00, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02,
+    0x00, 0x0a, 0x0f, 0x01, 0x0d, 0x00, 0x41, 0x00, 0x41, 0x00, 0x41, 0x00, 0xfc, 0x0b, 0x00, 0x41,
+    0x00, 0x0b,
+];
+
+#[wasm_bindgen_test]
+fn test_validate_binary() -> Result<()> {
+    validate_binary(&alkanes_std_test_build::get_bytes(), &FUEL_SCHEDULE_V2)?;
+    assert!(validate_binary(&EMPTY_MODULE, &FUEL_SCHEDULE_V2).is_err());
+    assert!(validate_binary(&UNSUPPORTED_IMPORT, &FUEL_SCHEDULE_V2)
+        .unwrap_err()
+        .to_string()
+        .contains("env.nope"));
+    assert!(validate_binary(&MISMATCHED_IMPORT, &FUEL_SCHEDULE_V2)
+        .unwrap_err()
+        .to_string()
+        .contains("host function env.__height as"));
+    assert!(validate_binary(&[0x01, 0x02, 0x03], &FUEL_SCHEDULE_V2).is_err());
+    validate_binary(&BULK_MEMORY, &FUEL_SCHEDULE_V2)?;
+    // the test alkane emits events, which V1 never links
+    assert!(
+        validate_binary(&alkanes_std_test_build::get_bytes(), &FUEL_SCHEDULE_V1)
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported host function env.__emit_event")
+    );
+    // the test alkane hashes through the crypto imports, which V0 never links
+    assert!(
+        validate_binary(&alkanes_std_test_build::get_bytes(), &FUEL_SCHEDULE_V0)
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported host function env.__")
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_deploy_reverts_without_sequence() -> Result<()> {
+    clear();
+    let deploy = Cellpack {
+        target: AlkaneId { block: 1, tx: 0 },
+        inputs: vec![4],
+    };
+    let rejected_block =
+        alkane_helpers::init_with_multiple_cellpacks(EMPTY_MODULE.to_vec(), vec![deploy.clone()]);
+    index_block(&rejected_block, 840_000)?;
+
+    let trace: Trace = view::trace(&OutPoint {
+        txid: rejected_block.txdata.last().unwrap().compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    match events.last().cloned() {
+        Some(TraceEvent::RevertContext(response)) => {
+            assert!(String::from_utf8_lossy(&response.inner.data).contains("__execute"));
+        }
+        _ => panic!("expected the rejected deploy to revert"),
+    }
+
+    // the next deploy takes the sequence number the rejected one would have used
+    let alkane = AlkaneId { block: 2, tx: 1 };
+    let binary = |id: &AlkaneId| {
+        IndexPointer::from_keyword("/alkanes/")
+            .select(&(*id).into())
+            .get()
+    };
+    assert!(binary(&alkane).as_ref().is_empty());
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks(
+        alkanes_std_test_build::get_bytes(),
+        vec![deploy],
+    );
+    index_block(&deploy_block, 840_001)?;
+    assert!(!binary(&alkane).as_ref().is_empty());
+    Ok(())
+}
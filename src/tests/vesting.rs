@@ -0,0 +1,235 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{
+    alkanes_std_auth_token_build, alkanes_std_owned_token_build, alkanes_std_vesting_build,
+};
+use crate::view::{plain_parcel_from_cellpack, simulate_parcel};
+use alkane_helpers::clear;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::AUTH_TOKEN_FACTORY_ID;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use protorune::test_helpers::{create_block_with_txs, create_coinbase_transaction};
+use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
+use protorune_support::balance_sheet::BalanceSheetOperations;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FUEL: u64 = 100_000_000;
+
+const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const VESTING: AlkaneId = AlkaneId { block: 2, tx: 3 };
+const ADMIN: AlkaneId = AlkaneId { block: 2, tx: 4 };
+const BENEFICIARY: AlkaneId = AlkaneId { block: 2, tx: 5 };
+
+const START: u128 = 840_010;
+const CLIFF: u128 = 840_020;
+const END: u128 = 840_110;
+
+// 1000 of TOKEN vesting from START to END with a cliff at CLIFF. The returned
+// outpoint holds the admin and beneficiary tokens along with the token's own
+// auth token
+fn deploy(revocable: u128) -> Result<OutPoint> {
+    let deploy_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+            alkanes_std_vesting_build::get_bytes(),
+        ],
+        vec![
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            // the token at 2:1, its auth token at 2:2
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1, 1000],
+            },
+            // deployed at 2:3 through a view, so it is left uninitialized
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![100],
+            },
+        ],
+    );
+    index_block(&deploy_block, 840_000)?;
+    let tx = call(
+        OutPoint::new(deploy_block.txdata.last().unwrap().compute_txid(), 0),
+        vec![(TOKEN, 1000)],
+        vec![0, START, CLIFF, END, revocable],
+        840_001,
+    )?;
+    Ok(OutPoint::new(tx.compute_txid(), 0))
+}
+
+// calls the vesting contract with only `parcel` out of what `previous_output`
+// holds
+fn call(
+    previous_output: OutPoint,
+    parcel: Vec<(AlkaneId, u128)>,
+    inputs: Vec<u128>,
+    height: u32,
+) -> Result<Transaction> {
+    let tx = alkane_helpers::create_cellpack_with_parcel(
+        previous_output,
+        parcel,
+        Cellpack {
+            target: VESTING,
+            inputs,
+        },
+    );
+    index_block(
+        &create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]),
+        height,
+    )?;
+    Ok(tx)
+}
+
+fn balance_at(tx: &Transaction, id: AlkaneId) -> Result<u128> {
+    Ok(load_sheet(
+        &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
+            .OUTPOINT_TO_RUNES
+            .select(&consensus_encode(&OutPoint::new(tx.compute_txid(), 0))?),
+    )
+    .get(&id.into()))
+}
+
+// a u128 view of the vesting contract as it would answer at `height`
+fn view_at(opcode: u128, height: u64) -> Result<u128> {
+    let mut parcel = plain_parcel_from_cellpack(Cellpack {
+        target: VESTING,
+        inputs: vec![opcode],
+    });
+    parcel.height = height;
+    let (response, _) = simulate_parcel(&parcel, FUEL)?;
+    Ok(u128::from_le_bytes(response.data[0..16].try_into()?))
+}
+
+// vested, claimed, remaining and claimable as of `height`
+fn views_at(height: u64) -> Result<(u128, u128, u128, u128)> {
+    Ok((
+        view_at(100, height)?,
+        view_at(101, height)?,
+        view_at(102, height)?,
+        view_at(103, height)?,
+    ))
+}
+
+#[wasm_bindgen_test]
+fn test_vesting_claims_follow_the_schedule() -> Result<()> {
+    clear();
+    let granted = deploy(0)?;
+    assert_eq!(views_at(840_019)?, (0, 0, 1000, 0));
+
+    // nothing is released before the cliff
+    let tx = call(granted, vec![(BENEFICIARY, 1)], vec![1], 840_015)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    assert_eq!(balance_at(&tx, BENEFICIARY)?, 1);
+
+    // halfway from start to end, half has vested
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(BENEFICIARY, 1)],
+        vec![1],
+        840_060,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 500);
+    assert_eq!(balance_at(&tx, BENEFICIARY)?, 1);
+    assert_eq!(views_at(840_060)?, (500, 500, 500, 0));
+    assert_eq!(views_at(840_070)?, (600, 500, 500, 100));
+
+    // the admin token is not the beneficiary's
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(ADMIN, 1)],
+        vec![1],
+        840_070,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 500);
+    assert_eq!(balance_at(&tx, ADMIN)?, 1);
+
+    // past the end, the rest is released
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(BENEFICIARY, 1)],
+        vec![1],
+        840_200,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 1000);
+    assert_eq!(views_at(840_200)?, (1000, 1000, 0, 0));
+
+    // and there is nothing left to claim
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(BENEFICIARY, 1)],
+        vec![1],
+        840_201,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 1000);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_vesting_revoke() -> Result<()> {
+    clear();
+    let granted = deploy(1)?;
+
+    // the beneficiary cannot revoke
+    let tx = call(granted, vec![(BENEFICIARY, 1)], vec![2], 840_030)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    assert_eq!(views_at(840_030)?, (200, 0, 1000, 200));
+
+    // the admin takes back what has not vested yet
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(ADMIN, 1)],
+        vec![2],
+        840_060,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 500);
+    assert_eq!(balance_at(&tx, ADMIN)?, 1);
+    // vesting stopped at the revocation
+    assert_eq!(views_at(840_200)?, (500, 0, 500, 500));
+
+    // a grant is only revoked once
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(ADMIN, 1)],
+        vec![2],
+        840_070,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 500);
+
+    // what vested before the revocation can still be claimed
+    let tx = call(
+        OutPoint::new(tx.compute_txid(), 0),
+        vec![(BENEFICIARY, 1)],
+        vec![1],
+        840_200,
+    )?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 1000);
+    assert_eq!(views_at(840_200)?, (500, 500, 0, 0));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_vesting_irrevocable_grant() -> Result<()> {
+    clear();
+    let granted = deploy(0)?;
+    let tx = call(granted, vec![(ADMIN, 1)], vec![2], 840_060)?;
+    assert_eq!(balance_at(&tx, TOKEN)?, 0);
+    assert_eq!(balance_at(&tx, ADMIN)?, 1);
+    assert_eq!(views_at(840_200)?, (1000, 0, 1000, 1000));
+    Ok(())
+}
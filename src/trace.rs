@@ -6,6 +6,7 @@ use bitcoin::OutPoint;
 use metashrew_support::index_pointer::KeyValuePointer;
 use metashrew_support::utils::consensus_encode;
 use protobuf::Message;
+use protorune::journal;
 use std::sync::Arc;
 #[allow(unused_imports)]
 use {
@@ -15,9 +16,11 @@ use {
 
 pub fn save_trace(outpoint: &OutPoint, height: u64, trace: Trace) -> Result<()> {
     let buffer: Vec<u8> = consensus_encode::<OutPoint>(outpoint)?;
+    journal::record(&TRACES.select(&buffer));
     TRACES.select(&buffer).set(Arc::<Vec<u8>>::new(
         <Trace as Into<proto::alkanes::AlkanesTrace>>::into(trace).write_to_bytes()?,
     ));
+    journal::record_list(&TRACES_BY_HEIGHT.select_value(height));
     TRACES_BY_HEIGHT
         .select_value(height)
         .append(Arc::new(buffer));
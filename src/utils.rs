@@ -1,6 +1,8 @@
+use crate::message::AlkaneMessageContext;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransferParcel;
 use alkanes_support::storage::StorageMap;
+use alkanes_support::trace::StorageSlotDiff;
 use alkanes_support::utils::overflow_error;
 use anyhow::{anyhow, Result};
 use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
@@ -10,6 +12,10 @@ use metashrew_core::{
     stdio::{stdout, Write},
 };
 use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::journal;
+use protorune::ledger::{self, HolderKey, LedgerEntry, LedgerHolder, LedgerOrigin};
+use protorune::message::{MessageContext, MessageContextParcel};
+use protorune::tables::RuneTable;
 use protorune_support::rune_transfer::RuneTransfer;
 use std::sync::Arc;
 
@@ -20,21 +26,54 @@ pub fn from_protobuf(v: alkanes_support::proto::alkanes::AlkaneId) -> AlkaneId {
     }
 }
 
+// index of the first entry in a list kept in height order whose height is at
+// or past `height`
+pub fn lower_bound(
+    list: &IndexPointer,
+    height: u64,
+    height_of: impl Fn(&Vec<u8>) -> Result<u64>,
+) -> Result<u32> {
+    let (mut lo, mut hi) = (0u32, list.length());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if height_of(list.select_index(mid).get().as_ref())? < height {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+fn balance_key(who: &AlkaneId, what: &AlkaneId) -> IndexPointer {
+    let who_bytes: Vec<u8> = (*who).into();
+    let what_bytes: Vec<u8> = (*what).into();
+    IndexPointer::default()
+        .keyword("/alkanes/")
+        .select(&what_bytes)
+        .keyword("/balances/")
+        .select(&who_bytes)
+}
+
+// journals a balance about to be written. Reads go through balance_pointer
+// without this, so balances only enter the undo journal when they change
+pub fn record_balance(who: &AlkaneId, what: &AlkaneId) {
+    journal::record(&balance_key(who, what));
+}
+
 pub fn balance_pointer(
     atomic: &mut AtomicPointer,
     who: &AlkaneId,
     what: &AlkaneId,
 ) -> AtomicPointer {
-    let who_bytes: Vec<u8> = who.clone().into();
     let what_bytes: Vec<u8> = what.clone().into();
-    let ptr = atomic
-        .derive(&IndexPointer::default())
-        .keyword("/alkanes/")
-        .select(&what_bytes)
-        .keyword("/balances/")
-        .select(&who_bytes);
+    let ptr = atomic.derive(&balance_key(who, what));
     if ptr.get().len() != 0 {
-        alkane_inventory_pointer(who).append(Arc::new(what_bytes));
+        // reads append here too, so the inventory is journaled on every path
+        // rather than only alongside record_balance
+        let inventory = alkane_inventory_pointer(who);
+        journal::record_list(&inventory);
+        inventory.append(Arc::new(what_bytes));
     }
     ptr
 }
@@ -52,19 +91,81 @@ pub fn u128_from_bytes(v: Vec<u8>) -> u128 {
     let bytes: [u8; 16] = untyped.try_into().unwrap();
     u128::from_le_bytes(bytes)
 }
-pub fn credit_balances(atomic: &mut AtomicPointer, to: &AlkaneId, runes: &Vec<RuneTransfer>) {
+
+// attributes balance changes made while handling a protostone to its shadow
+// vout, the same outpoint its trace is saved under
+pub fn ledger_origin(parcel: &MessageContextParcel) -> LedgerOrigin {
+    LedgerOrigin {
+        txid: parcel.transaction.compute_txid(),
+        vout: parcel.vout,
+        height: parcel.height,
+    }
+}
+
+// records a change to a balance held by an alkane, given the balance it was
+// left at
+fn append_ledger(
+    atomic: &mut AtomicPointer,
+    holder: &AlkaneId,
+    what: &AlkaneId,
+    origin: &LedgerOrigin,
+    amount: u128,
+    debit: bool,
+    balance: u128,
+) -> Result<()> {
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    ledger::append_entry(
+        atomic,
+        &table,
+        &LedgerEntry::new(
+            (*what).into(),
+            LedgerHolder::Id((*holder).into()),
+            origin,
+            amount,
+            debit,
+        ),
+    );
+    ledger::set_holder(
+        atomic,
+        &table,
+        &(*what).into(),
+        &HolderKey::Id((*holder).into()),
+        balance != 0,
+    )
+}
+
+pub fn credit_balances(
+    atomic: &mut AtomicPointer,
+    to: &AlkaneId,
+    runes: &Vec<RuneTransfer>,
+    origin: &LedgerOrigin,
+) -> Result<()> {
     for rune in runes.clone() {
+        record_balance(to, &rune.id.into());
         let mut ptr = balance_pointer(atomic, to, &rune.id.clone().into());
-        ptr.set_value::<u128>(rune.value + ptr.get_value::<u128>());
+        let balance = rune.value + ptr.get_value::<u128>();
+        ptr.set_value::<u128>(balance);
+        append_ledger(
+            atomic,
+            to,
+            &rune.id.into(),
+            origin,
+            rune.value,
+            false,
+            balance,
+        )?;
     }
+    Ok(())
 }
 
 pub fn debit_balances(
     atomic: &mut AtomicPointer,
     to: &AlkaneId,
     runes: &AlkaneTransferParcel,
+    origin: &LedgerOrigin,
 ) -> Result<()> {
     for rune in runes.0.clone() {
+        record_balance(to, &rune.id);
         let mut pointer = balance_pointer(atomic, to, &rune.id.clone().into());
         let pointer_value = pointer.get_value::<u128>();
         let v = {
@@ -78,6 +179,7 @@ pub fn debit_balances(
             }
         };
         pointer.set_value::<u128>(v);
+        append_ledger(atomic, to, &rune.id, origin, pointer_value - v, true, v)?;
     }
     Ok(())
 }
@@ -87,8 +189,10 @@ pub fn transfer_from(
     atomic: &mut AtomicPointer,
     from: &AlkaneId,
     to: &AlkaneId,
+    origin: &LedgerOrigin,
 ) -> Result<()> {
     for transfer in &parcel.0 {
+        record_balance(from, &transfer.id);
         let mut from_pointer =
             balance_pointer(atomic, &from.clone().into(), &transfer.id.clone().into());
         let mut balance = from_pointer.get_value::<u128>();
@@ -99,18 +203,68 @@ pub fn transfer_from(
                 return Err(anyhow!("balance underflow during transfer_from"));
             }
         }
+        let debited = std::cmp::min(from_pointer.get_value::<u128>(), transfer.value);
         from_pointer.set_value::<u128>(balance - transfer.value);
+        append_ledger(
+            atomic,
+            from,
+            &transfer.id,
+            origin,
+            debited,
+            true,
+            balance - transfer.value,
+        )?;
+        record_balance(to, &transfer.id);
         let mut to_pointer =
             balance_pointer(atomic, &to.clone().into(), &transfer.id.clone().into());
-        to_pointer.set_value::<u128>(to_pointer.get_value::<u128>() + transfer.value);
+        let to_balance = to_pointer.get_value::<u128>() + transfer.value;
+        to_pointer.set_value::<u128>(to_balance);
+        append_ledger(
+            atomic,
+            to,
+            &transfer.id,
+            origin,
+            transfer.value,
+            false,
+            to_balance,
+        )?;
     }
     Ok(())
 }
-pub fn pipe_storagemap_to<T: KeyValuePointer>(map: &StorageMap, pointer: &mut T) {
-    map.0.iter().for_each(|(k, v)| {
-        pointer
-            .keyword("/storage/")
-            .select(k)
-            .set(Arc::new(v.clone()));
-    });
+// writes every slot in the map and returns what changed, ordered by key
+pub fn pipe_storagemap_to<T: KeyValuePointer>(
+    map: &StorageMap,
+    pointer: &mut T,
+) -> Vec<StorageSlotDiff> {
+    let mut diff = map
+        .0
+        .iter()
+        .map(|(k, v)| {
+            let mut slot = pointer.keyword("/storage/").select(k);
+            journal::record(&slot);
+            let old_value = slot.get().as_ref().clone();
+            slot.set(Arc::new(v.clone()));
+            StorageSlotDiff {
+                key: k.clone(),
+                old_value,
+                new_value: v.clone(),
+            }
+        })
+        .collect::<Vec<StorageSlotDiff>>();
+    diff.sort_by(|a, b| a.key.cmp(&b.key));
+    diff
+}
+
+// folds the slots a frame flushed before its subcalls into the diff taken
+// when it exits, so each slot reports its value from before the frame ran
+pub fn merge_storage_diff(
+    flushed: &[StorageSlotDiff],
+    mut diff: Vec<StorageSlotDiff>,
+) -> Vec<StorageSlotDiff> {
+    for slot in diff.iter_mut() {
+        if let Some(earliest) = flushed.iter().find(|v| v.key == slot.key) {
+            slot.old_value = earliest.old_value.clone();
+        }
+    }
+    diff
 }
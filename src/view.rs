@@ -1,18 +1,28 @@
+use crate::bytecode::{self, code_pointer, code_reference, load_bytecode};
 use crate::message::AlkaneMessageContext;
 use crate::network::set_view_mode;
-use crate::tables::{TRACES, TRACES_BY_HEIGHT};
+use crate::registry;
+use crate::tables::{ALKANES_BY_TEMPLATE, ALKANES_REGISTRY, TRACES, TRACES_BY_HEIGHT};
 use crate::utils::{
-    alkane_inventory_pointer, balance_pointer, credit_balances, debit_balances, pipe_storagemap_to,
+    alkane_inventory_pointer, balance_pointer, credit_balances, debit_balances, ledger_origin,
+    pipe_storagemap_to,
+};
+use crate::vm::fuel::{
+    fuel_schedule, minimum_vfsize, transaction_fuel, FuelTank, VirtualFuelBytes, TOTAL_FUEL,
 };
 use crate::vm::instance::AlkanesInstance;
 use crate::vm::runtime::AlkanesRuntimeContext;
 use crate::vm::utils::{prepare_context, run_after_special, run_special_cellpacks};
+use crate::vm::validation::validate_binary;
 use alkanes_support::cellpack::Cellpack;
+use alkanes_support::crypto::sha256;
+use alkanes_support::gz::compress;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransfer;
 use alkanes_support::proto;
 use alkanes_support::proto::alkanes::{AlkaneInventoryRequest, AlkaneInventoryResponse};
 use alkanes_support::response::ExtendedCallResponse;
+use alkanes_support::trace::{Trace, TraceEvent};
 use anyhow::{anyhow, Result};
 use bitcoin::blockdata::transaction::Version;
 use bitcoin::consensus::encode::serialize;
@@ -24,13 +34,18 @@ use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 #[allow(unused_imports)]
 use metashrew_core::{println, stdio::stdout};
 use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use ordinals::{Artifact, Runestone};
 use protobuf::{Message, MessageField};
-use protorune::balance_sheet::MintableDebit;
+use protorune::balance_sheet::{clear_balances, load_sheet, MintableDebit};
+use protorune::journal;
+use protorune::ledger::{self, HolderKey, LedgerHolder};
 use protorune::message::{MessageContext, MessageContextParcel};
-use protorune::tables::RUNES;
+use protorune::tables::{RuneTable, OUTPOINT_SPENDABLE_BY, RUNES};
 use protorune::view;
+use protorune::Protorune;
 use protorune_support::balance_sheet::ProtoruneRuneId;
 use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations};
+use protorune_support::protostone::Protostone;
 use protorune_support::rune_transfer::RuneTransfer;
 use protorune_support::utils::{consensus_decode, decode_varint_list};
 use std::collections::HashMap;
@@ -102,6 +117,96 @@ pub fn plain_parcel_from_cellpack(cellpack: Cellpack) -> MessageContextParcel {
     result
 }
 
+// answers a view as of the end of block `height`. Exports pass through the
+// height their input is prefixed with; 0, or anything at or past the tip,
+// reads the tip. Only the last JOURNAL_DEPTH blocks can be read unless the
+// indexer is built with the `archive` feature, and older heights are an
+// error rather than a read of the tip.
+pub fn at_height<T>(height: u32, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    if height == 0 {
+        f()
+    } else {
+        journal::at_height(height as u64, f)
+    }
+}
+
+// state laid over an alkane for the length of a simulation, in the manner of
+// eth_call state overrides
+#[derive(Clone, Debug, Default)]
+pub struct StateOverride {
+    pub id: AlkaneId,
+    pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+    // uncompressed wasm, validated as it would be at deploy time
+    pub bytecode: Option<Vec<u8>>,
+    pub balances: Vec<AlkaneTransfer>,
+}
+
+pub fn overrides_from_protobuf(v: Vec<proto::alkanes::AlkaneOverride>) -> Vec<StateOverride> {
+    v.into_iter()
+        .map(|v| StateOverride {
+            id: v.id.into_option().unwrap_or_default().into(),
+            storage: v
+                .storage
+                .into_iter()
+                .map(|pair| (pair.key, pair.value))
+                .collect(),
+            bytecode: if v.bytecode.is_empty() {
+                None
+            } else {
+                Some(v.bytecode)
+            },
+            balances: v
+                .balances
+                .into_iter()
+                .map(|transfer| AlkaneTransfer {
+                    id: transfer.id.into_option().unwrap_or_default().into(),
+                    value: transfer.value.into_option().unwrap_or_default().into(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+// writes overrides through the parcel's atomic pointer. Nothing is committed,
+// so they are dropped along with the rest of the simulation's writes.
+// Bytecode is validated as a deploy at `height` would be
+pub fn apply_overrides(
+    atomic: &mut AtomicPointer,
+    height: u64,
+    overrides: &[StateOverride],
+) -> Result<()> {
+    for state in overrides {
+        let id_bytes: Vec<u8> = state.id.into();
+        let mut alkane = atomic.derive(&IndexPointer::from_keyword("/alkanes/").select(&id_bytes));
+        for (key, value) in &state.storage {
+            alkane
+                .keyword("/storage/")
+                .select(key)
+                .set(Arc::new(value.clone()));
+        }
+        if let Some(bytecode) = &state.bytecode {
+            validate_binary(bytecode, fuel_schedule(height))?;
+            let hash = sha256(bytecode);
+            atomic
+                .derive(&code_pointer(&hash))
+                .set(Arc::new(compress(bytecode.clone())?));
+            alkane.set(Arc::new(code_reference(&hash)));
+        }
+        for balance in &state.balances {
+            let what: Vec<u8> = balance.id.into();
+            atomic
+                .derive(
+                    &IndexPointer::from_keyword("/alkanes/")
+                        .select(&what)
+                        .keyword("/balances/")
+                        .select(&id_bytes),
+                )
+                .set_value::<u128>(balance.value);
+        }
+    }
+    Ok(())
+}
+
 pub fn call_view(id: &AlkaneId, inputs: &Vec<u128>, fuel: u64) -> Result<Vec<u8>> {
     let (response, _gas_used) = simulate_parcel(
         &plain_parcel_from_cellpack(Cellpack {
@@ -360,6 +465,133 @@ pub fn alkane_inventory(req: &AlkaneInventoryRequest) -> Result<AlkaneInventoryR
     Ok(result)
 }
 
+// default and largest number of entries a paginated view returns at once
+pub const PAGE_SIZE: u64 = 100;
+
+pub(crate) fn page_limit(limit: u64) -> u64 {
+    match limit {
+        0 => PAGE_SIZE,
+        v => std::cmp::min(v, PAGE_SIZE),
+    }
+}
+
+pub fn ledger(req: &proto::alkanes::LedgerRequest) -> Result<proto::alkanes::LedgerResponse> {
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let list = if let Some(alkane) = req.alkane.as_ref() {
+        let id: AlkaneId = alkane.clone().into();
+        table.LEDGER_BY_RUNE_ID.select(&id.into())
+    } else if let Some(holder) = req.holder.as_ref() {
+        let id: AlkaneId = holder.clone().into();
+        table.LEDGER_BY_HOLDER.select(&id.into())
+    } else if !req.address.is_empty() {
+        table.LEDGER_BY_HOLDER.select(&req.address)
+    } else {
+        return Err(anyhow!("ledger request names no alkane, holder or address"));
+    };
+    let (entries, total) = ledger::page(&list, req.offset, page_limit(req.limit))?;
+    let mut result = proto::alkanes::LedgerResponse::new();
+    result.total = total;
+    result.entries = entries
+        .into_iter()
+        .map(|entry| {
+            let mut v = proto::alkanes::LedgerEntry::new();
+            v.alkane = MessageField::some(AlkaneId::from(entry.rune).into());
+            match entry.holder {
+                LedgerHolder::Address(address) => v.address = address,
+                LedgerHolder::Id(id) => v.holder = MessageField::some(AlkaneId::from(id).into()),
+            }
+            v.txid = entry.txid.as_byte_array().to_vec();
+            v.vout = entry.vout;
+            v.amount = MessageField::some(entry.amount.into());
+            v.debit = entry.debit;
+            v.height = entry.height;
+            v
+        })
+        .collect();
+    Ok(result)
+}
+
+// alkanes in creation order, or only the clones of a template
+pub fn alkanes(req: &proto::alkanes::AlkanesRequest) -> Result<proto::alkanes::AlkanesResponse> {
+    let page = |list: &IndexPointer| {
+        registry::page(
+            list,
+            req.from_height,
+            req.to_height,
+            req.offset,
+            page_limit(req.limit),
+        )
+    };
+    let (records, total) = match req.template.clone().into_option() {
+        Some(template) => {
+            let template: AlkaneId = template.into();
+            page(&ALKANES_BY_TEMPLATE.select(&template.into()))?
+        }
+        None => page(&ALKANES_REGISTRY)?,
+    };
+    let mut result = proto::alkanes::AlkanesResponse::new();
+    result.alkanes = records;
+    result.total = total;
+    Ok(result)
+}
+
+// the outpoints and alkanes currently holding an alkane, with their balances,
+// alongside its circulating supply
+pub fn holders(req: &proto::alkanes::HoldersRequest) -> Result<proto::alkanes::HoldersResponse> {
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let alkane: AlkaneId = req
+        .alkane
+        .clone()
+        .into_option()
+        .ok_or_else(|| anyhow!("holders request names no alkane"))?
+        .into();
+    let rune: ProtoruneRuneId = alkane.into();
+    let alkane_bytes: Vec<u8> = alkane.into();
+    let list = table.HOLDERS.select(&alkane_bytes);
+    let total = list.length() as u64;
+    let end = std::cmp::min(total, req.offset.saturating_add(page_limit(req.limit)));
+    let mut result = proto::alkanes::HoldersResponse::new();
+    for i in req.offset..end {
+        let mut holder = proto::alkanes::AlkaneHolder::new();
+        let balance = match HolderKey::parse(list.select_index(i as u32).get().as_ref())? {
+            HolderKey::Outpoint(outpoint) => {
+                let outpoint_bytes = consensus_encode(&outpoint)?;
+                holder.outpoint = MessageField::some(proto::alkanes::Outpoint {
+                    txid: outpoint.txid.as_byte_array().to_vec(),
+                    vout: outpoint.vout,
+                    ..Default::default()
+                });
+                holder.address = OUTPOINT_SPENDABLE_BY
+                    .select(&outpoint_bytes)
+                    .get()
+                    .as_ref()
+                    .clone();
+                load_sheet(&table.OUTPOINT_TO_RUNES.select(&outpoint_bytes)).get(&rune)
+            }
+            HolderKey::Id(id) => {
+                let who: AlkaneId = id.into();
+                holder.alkane = MessageField::some(who.into());
+                IndexPointer::from_keyword("/alkanes/")
+                    .select(&alkane_bytes)
+                    .keyword("/balances/")
+                    .select(&who.into())
+                    .get_value::<u128>()
+            }
+        };
+        holder.balance = MessageField::some(balance.into());
+        result.holders.push(holder);
+    }
+    result.total = total;
+    result.supply = MessageField::some(
+        table
+            .SUPPLY
+            .select(&alkane_bytes)
+            .get_value::<u128>()
+            .into(),
+    );
+    Ok(result)
+}
+
 pub fn traceblock(height: u32) -> Result<Vec<u8>> {
     let mut block_events: Vec<proto::alkanes::AlkanesBlockEvent> = vec![];
     for outpoint in TRACES_BY_HEIGHT.select_value(height as u64).get_list() {
@@ -406,6 +638,55 @@ pub fn simulate_safe(
     simulate_parcel(parcel, fuel)
 }
 
+// runs a simulate request with its overrides laid over state first
+pub fn simulate_request(
+    mut request: proto::alkanes::MessageContextParcel,
+) -> Result<(ExtendedCallResponse, u64)> {
+    let overrides = overrides_from_protobuf(std::mem::take(&mut request.overrides));
+    let mut parcel = parcel_from_protobuf(request);
+    apply_overrides(&mut parcel.atomic, parcel.height, &overrides)?;
+    simulate_safe(&parcel, u64::MAX)
+}
+
+// sizes the fuel a parcel's transaction would be allocated if it opened a
+// block of `block_size` virtual fuel bytes. Deploys leave the first input's
+// witness out of their size, so padding for those has to go elsewhere
+pub fn estimate_fuel(
+    req: &proto::alkanes::EstimateFuelRequest,
+) -> Result<proto::alkanes::EstimateFuelResponse> {
+    let mut request = req.parcel.clone().unwrap_or_default();
+    let overrides = overrides_from_protobuf(std::mem::take(&mut request.overrides));
+    let mut parcel = parcel_from_protobuf(request);
+    apply_overrides(&mut parcel.atomic, parcel.height, &overrides)?;
+    let mut result = proto::alkanes::EstimateFuelResponse::new();
+    result.vsize = parcel.transaction.vfsize();
+    let block_size = std::cmp::max(req.block_size, result.vsize);
+    result.fuel = transaction_fuel(result.vsize, block_size);
+    // no transaction is ever allocated more than TOTAL_FUEL, so a call that
+    // needs more is reported as failing rather than run to completion
+    match simulate_safe(&parcel, TOTAL_FUEL) {
+        Ok((_response, gas_used)) => {
+            result.gas_used = gas_used;
+            if gas_used <= result.fuel {
+                result.success = true;
+            } else {
+                result.error = format!(
+                    "needs {} fuel but a transaction of {} vbytes is allocated {}",
+                    gas_used, result.vsize, result.fuel
+                );
+            }
+            if let Some(vsize) = minimum_vfsize(gas_used, block_size - result.vsize) {
+                result.required_vsize = std::cmp::max(vsize, result.vsize);
+                result.padding = result.required_vsize - result.vsize;
+            }
+        }
+        Err(e) => {
+            result.error = e.to_string();
+        }
+    }
+    Ok(result)
+}
+
 pub fn meta_safe(parcel: &MessageContextParcel) -> Result<Vec<u8>> {
     set_view_mode();
     let list = decode_varint_list(&mut Cursor::new(parcel.calldata.clone()))?;
@@ -431,7 +712,7 @@ pub fn simulate_parcel(
     )));
     let mut atomic = parcel.atomic.derive(&IndexPointer::default());
     let (caller, myself, binary) = run_special_cellpacks(context.clone(), &cellpack)?;
-    credit_balances(&mut atomic, &myself, &parcel.runes);
+    credit_balances(&mut atomic, &myself, &parcel.runes, &ledger_origin(parcel))?;
     prepare_context(context.clone(), &caller, &myself, false);
     let (response, gas_used) = run_after_special(context.clone(), binary, fuel)?;
     pipe_storagemap_to(
@@ -445,7 +726,12 @@ pub fn simulate_parcel(
         response.alkanes.clone().into(),
     );
     combined.debit_mintable(&sheet, &mut atomic)?;
-    debit_balances(&mut atomic, &myself, &response.alkanes)?;
+    debit_balances(
+        &mut atomic,
+        &myself,
+        &response.alkanes,
+        &ledger_origin(parcel),
+    )?;
     Ok((response, gas_used))
 }
 
@@ -468,15 +754,170 @@ pub fn multi_simulate_safe(
     multi_simulate(parcels, fuel)
 }
 
+fn to_transfers<P: KeyValuePointer + Clone>(
+    sheet: &BalanceSheet<P>,
+) -> Vec<proto::alkanes::AlkaneTransfer> {
+    let mut balances = sheet
+        .balances()
+        .iter()
+        .filter(|(_k, v)| **v != 0)
+        .map(|(k, v)| (*k, *v))
+        .collect::<Vec<(ProtoruneRuneId, u128)>>();
+    balances.sort();
+    balances
+        .into_iter()
+        .map(|(id, value)| proto::alkanes::AlkaneTransfer {
+            id: MessageField::some(AlkaneId::from(id).into()),
+            value: MessageField::some(value.into()),
+            ..Default::default()
+        })
+        .collect()
+}
+
+// runs a raw transaction through the same runestone and protostone indexing a
+// block would, against an atomic pointer that is never committed. Traces and
+// the clearing of spent inputs bypass that pointer, so like every view this
+// has to run in view mode where nothing it writes is kept
+pub fn simulate_transaction(
+    req: &proto::alkanes::SimulateTransactionRequest,
+) -> Result<proto::alkanes::SimulateTransactionResponse> {
+    set_view_mode();
+    let tx = consensus_decode::<Transaction>(&mut Cursor::new(req.transaction.clone()))?;
+    let txid = tx.compute_txid();
+    let height = match req.height {
+        0 => journal::tip().map(|v| v + 1).unwrap_or_default(),
+        v => v,
+    };
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let mut atomic = AtomicPointer::default();
+    for prevout in &req.prevouts {
+        let outpoint = prevout
+            .outpoint
+            .clone()
+            .into_option()
+            .ok_or_else(|| anyhow!("prevout balances name no outpoint"))?;
+        let outpoint = OutPoint {
+            txid: bitcoin::Txid::from_slice(&outpoint.txid)?,
+            vout: outpoint.vout,
+        };
+        let sheet = <BalanceSheet<AtomicPointer> as From<Vec<RuneTransfer>>>::from(
+            prevout
+                .balances
+                .iter()
+                .map(|v| RuneTransfer {
+                    id: v.id.clone().unwrap_or_default().into(),
+                    value: v.value.clone().unwrap_or_default().into(),
+                })
+                .collect::<Vec<RuneTransfer>>(),
+        );
+        let mut ptr = atomic.derive(
+            &table
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&outpoint)?),
+        );
+        clear_balances(&ptr);
+        sheet.save(&mut ptr, false);
+    }
+    let mut block = default_block();
+    block.txdata.push(tx.clone());
+    FuelTank::initialize(&block);
+
+    let mut result = proto::alkanes::SimulateTransactionResponse::new();
+    let runestone = match Runestone::decipher(&tx) {
+        Some(Artifact::Runestone(runestone)) => Some(runestone),
+        _ => None,
+    };
+    match runestone.as_ref() {
+        Some(runestone) => {
+            if let Err(e) = Protorune::index_runestone::<AlkaneMessageContext>(
+                &mut atomic,
+                &tx,
+                runestone,
+                height,
+                0,
+                &block,
+                Protorune::get_runestone_output_index(&tx)?,
+            ) {
+                atomic.rollback();
+                result.errors.push(e.to_string());
+            }
+        }
+        // without a runestone no protostone moves the inputs' protorunes, so
+        // the indexer leaves them on the spent outpoints and debits them from
+        // their holders. Outputs come back empty, as they would be indexed
+        None => {
+            for input in &tx.input {
+                let sheet = load_sheet(
+                    &atomic.derive(
+                        &table
+                            .OUTPOINT_TO_RUNES
+                            .select(&consensus_encode(&input.previous_output)?),
+                    ),
+                );
+                if !to_transfers(&sheet).is_empty() {
+                    result.errors.push(format!(
+                        "protorunes on {}:{} reach no output without a protostone",
+                        input.previous_output.txid, input.previous_output.vout
+                    ));
+                }
+            }
+        }
+    }
+    for vout in 0..tx.output.len() as u32 {
+        let sheet = load_sheet(
+            &atomic.derive(
+                &table
+                    .OUTPOINT_TO_RUNES
+                    .select(&consensus_encode(&OutPoint { txid, vout })?),
+            ),
+        );
+        result.outputs.push(proto::alkanes::OutputBalances {
+            vout,
+            balances: to_transfers(&sheet),
+            ..Default::default()
+        });
+    }
+    let protostones = match runestone.as_ref() {
+        Some(runestone) => Protostone::from_runestone(runestone)?,
+        None => vec![],
+    };
+    for i in 0..protostones.len() as u32 {
+        let outpoint = OutPoint {
+            txid,
+            vout: tx.output.len() as u32 + 1 + i,
+        };
+        let bytes = trace(&outpoint)?;
+        if bytes.is_empty() {
+            continue;
+        }
+        let events: Trace = bytes.clone().try_into()?;
+        if let Some(TraceEvent::RevertContext(response)) = events.0.lock().unwrap().last().cloned()
+        {
+            result.errors.push(
+                String::from_utf8_lossy(response.inner.data.get(4..).unwrap_or_default())
+                    .to_string(),
+            );
+        }
+        result.traces.push(proto::alkanes::Trace {
+            outpoint: MessageField::some(proto::alkanes::Outpoint {
+                txid: txid.as_byte_array().to_vec(),
+                vout: outpoint.vout,
+                ..Default::default()
+            }),
+            trace: MessageField::some(proto::alkanes::AlkanesTrace::parse_from_bytes(&bytes)?),
+            ..Default::default()
+        });
+    }
+    Ok(result)
+}
+
 pub fn getbytecode(input: &Vec<u8>) -> Result<Vec<u8>> {
     let request = alkanes_support::proto::alkanes::BytecodeRequest::parse_from_bytes(input)?;
     let alkane_id = request.id.unwrap();
     let alkane_id = crate::utils::from_protobuf(alkane_id);
 
-    // Get the bytecode from the storage
-    let bytecode = metashrew_core::index_pointer::IndexPointer::from_keyword("/alkanes/")
-        .select(&alkane_id.into())
-        .get();
+    // Get the bytecode from the storage, through its code hash
+    let bytecode = load_bytecode(&IndexPointer::default(), &alkane_id);
 
     // Return the uncompressed bytecode
     if bytecode.len() > 0 {
@@ -486,6 +927,24 @@ pub fn getbytecode(input: &Vec<u8>) -> Result<Vec<u8>> {
     }
 }
 
+pub fn code_hash(
+    req: &proto::alkanes::CodeHashRequest,
+) -> Result<proto::alkanes::CodeHashResponse> {
+    let hash = match req.id.clone().into_option() {
+        Some(id) => bytecode::code_hash(&IndexPointer::default(), &id.into())?
+            .ok_or_else(|| anyhow!("No bytecode found for the given AlkaneId"))?
+            .to_vec(),
+        None => req.code_hash.clone(),
+    };
+    let mut result = proto::alkanes::CodeHashResponse::new();
+    result.alkanes = bytecode::alkanes_with_code(&hash)?
+        .into_iter()
+        .map(|v| v.into())
+        .collect();
+    result.code_hash = hash;
+    Ok(result)
+}
+
 pub fn getblock(input: &Vec<u8>) -> Result<Vec<u8>> {
     use crate::etl;
     use alkanes_support::proto::alkanes::{BlockRequest, BlockResponse};
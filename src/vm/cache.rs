@@ -0,0 +1,120 @@
+use alkanes_support::gz::decompress;
+use anyhow::Result;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex};
+use wasmi::{Config, Engine, Module};
+
+// Compiled modules are kept for the length of a block, so a contract called
+// thousands of times is inflated and compiled once. Entries are keyed by the
+// code hash the bytecode is stored under, so clones of a factory share one
+// module. Anything that rewrites an alkane's bytecode, including a reorg or a
+// view's state override, points it at another hash and misses.
+
+pub const MODULE_CACHE_CAPACITY: usize = 128;
+
+// bytecode on its way to instantiation
+#[derive(Clone, Debug)]
+pub enum Bytecode {
+    // gzipped, as stored under /alkanes-code/<hash>. Only inflated on a miss
+    Stored {
+        hash: [u8; 32],
+        compressed: Arc<Vec<u8>>,
+    },
+    // raw wasm that is compiled every time
+    Wasm(Arc<Vec<u8>>),
+}
+
+impl From<Arc<Vec<u8>>> for Bytecode {
+    fn from(v: Arc<Vec<u8>>) -> Bytecode {
+        Bytecode::Wasm(v)
+    }
+}
+
+impl Bytecode {
+    pub fn stored(hash: [u8; 32], compressed: Arc<Vec<u8>>) -> Bytecode {
+        Bytecode::Stored { hash, compressed }
+    }
+}
+
+struct ModuleCache {
+    // modules only run in stores of the engine that compiled them, so the
+    // engine is replaced along with the entries at each block
+    engine: Engine,
+    modules: HashMap<[u8; 32], Module>,
+    // least recently used first
+    order: VecDeque<[u8; 32]>,
+}
+
+fn new_engine() -> Engine {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    Engine::new(&config)
+}
+
+impl ModuleCache {
+    fn new() -> Self {
+        ModuleCache {
+            engine: new_engine(),
+            modules: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+    fn touch(&mut self, key: &[u8; 32]) {
+        if let Some(position) = self.order.iter().position(|v| v == key) {
+            self.order.remove(position);
+        }
+        self.order.push_back(*key);
+    }
+    fn insert(&mut self, key: [u8; 32], module: Module) {
+        while self.modules.len() >= MODULE_CACHE_CAPACITY {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.modules.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        self.modules.insert(key, module);
+        self.touch(&key);
+    }
+}
+
+static MODULE_CACHE: LazyLock<Mutex<ModuleCache>> =
+    LazyLock::new(|| Mutex::new(ModuleCache::new()));
+
+// the engine modules are currently compiled with, for parsing a binary the
+// same way a call would without caching the result
+pub fn engine() -> Engine {
+    MODULE_CACHE.lock().unwrap().engine.clone()
+}
+
+// the engine to build a store on and the module to instantiate in it
+pub fn load(bytecode: &Bytecode) -> Result<(Engine, Module)> {
+    let mut cache = MODULE_CACHE.lock().unwrap();
+    let engine = cache.engine.clone();
+    let (hash, compressed) = match bytecode {
+        Bytecode::Wasm(binary) => {
+            let module = Module::new(&engine, &mut &binary[..])?;
+            return Ok((engine, module));
+        }
+        Bytecode::Stored { hash, compressed } => (*hash, compressed),
+    };
+    let key = hash;
+    if let Some(module) = cache.modules.get(&key).cloned() {
+        cache.touch(&key);
+        return Ok((engine, module));
+    }
+    let module = Module::new(&engine, &mut &decompress(compressed.as_ref().clone())?[..])?;
+    cache.insert(key, module.clone());
+    Ok((engine, module))
+}
+
+// starts a block with an empty cache and a fresh engine, so compiled code
+// never outlives the block it was compiled in
+pub fn reset() {
+    *MODULE_CACHE.lock().unwrap() = ModuleCache::new();
+}
+
+pub fn len() -> usize {
+    MODULE_CACHE.lock().unwrap().modules.len()
+}
@@ -1 +1,3 @@
 pub(super) const MEMORY_LIMIT: usize = 43554432;
+// every instance's memory is grown to this many pages before it runs
+pub(super) const INITIAL_MEMORY_PAGES: u32 = 512;
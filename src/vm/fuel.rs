@@ -1,5 +1,6 @@
 use crate::{
     message::AlkaneMessageContext,
+    network::genesis,
     vm::{AlkanesInstance, AlkanesState},
 };
 use alkanes_support::utils::overflow_error;
@@ -238,6 +239,35 @@ impl FuelTank {
     }
 }
 
+// the allocation fuel_transaction gives a transaction of `txsize` virtual
+// fuel bytes when it is the first in a block of `block_size`
+pub fn transaction_fuel(txsize: u64, block_size: u64) -> u64 {
+    let size = std::cmp::max(block_size, txsize);
+    if size == 0 {
+        return MINIMUM_FUEL;
+    }
+    let metered = (TOTAL_FUEL as u128) * (txsize as u128) / (size as u128);
+    std::cmp::max(MINIMUM_FUEL, metered as u64)
+}
+
+// the smallest transaction size whose allocation covers `fuel` when `rest`
+// virtual fuel bytes of other alkanes transactions share the block. Padding
+// grows the block along with the transaction, so past TOTAL_FUEL nothing helps
+pub fn minimum_vfsize(fuel: u64, rest: u64) -> Option<u64> {
+    if fuel <= MINIMUM_FUEL {
+        return Some(0);
+    }
+    if fuel >= TOTAL_FUEL {
+        return if fuel == TOTAL_FUEL && rest == 0 {
+            Some(1)
+        } else {
+            None
+        };
+    }
+    let (fuel, rest, total) = (fuel as u128, rest as u128, TOTAL_FUEL as u128);
+    Some(std::cmp::max(1, (fuel * rest).div_ceil(total - fuel)) as u64)
+}
+
 pub const MINIMUM_FUEL: u64 = 350_000;
 pub const FUEL_PER_VBYTE: u64 = 150;
 pub const FUEL_PER_REQUEST_BYTE: u64 = 1;
@@ -251,6 +281,132 @@ pub const FUEL_BALANCE: u64 = 10;
 pub const FUEL_EXTCALL_DEPLOY: u64 = 10_000;
 pub const FUEL_LOAD_BLOCK: u64 = 1000; // Fixed cost for loading a block
 pub const FUEL_LOAD_TRANSACTION: u64 = 500; // Fixed cost for loading a transaction
+pub const FUEL_EMIT_EVENT: u64 = 100; // Fixed cost per event, on top of the per-byte store cost
+
+// Crypto precompiles: a fixed cost per call, plus FUEL_PER_HASH_BYTE for each
+// byte hashed. Still a small fraction of the same work done in wasm
+pub const FUEL_SHA256: u64 = 100;
+pub const FUEL_KECCAK256: u64 = 100;
+pub const FUEL_RIPEMD160: u64 = 150;
+pub const FUEL_HASH160: u64 = 250;
+pub const FUEL_PER_HASH_BYTE: u64 = 2;
+pub const FUEL_SCHNORR_VERIFY: u64 = 5_000;
+pub const FUEL_ECDSA_VERIFY: u64 = 5_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CryptoCosts {
+    pub sha256: u64,
+    pub keccak256: u64,
+    pub ripemd160: u64,
+    pub hash160: u64,
+    pub per_hash_byte: u64,
+    pub schnorr_verify: u64,
+    pub ecdsa_verify: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuelSchedule {
+    pub version: u32,
+    pub activation_height: u64,
+    pub per_request_byte: u64,
+    pub per_load_byte: u64,
+    pub per_store_byte: u64,
+    pub sequence: u64,
+    pub fuel: u64,
+    pub height: u64,
+    pub balance: u64,
+    pub extcall: u64,
+    pub extcall_deploy: u64,
+    pub request_transaction: u64,
+    pub load_transaction: u64,
+    pub request_block: u64,
+    pub load_block: u64,
+    pub log: u64,
+    // None leaves the crypto host functions unlinked, so a binary importing
+    // them fails to instantiate as it did before they existed
+    pub crypto: Option<CryptoCosts>,
+    // the fixed cost of __emit_event, which is likewise unlinked while None
+    pub emit_event: Option<u64>,
+    // false keeps the launch behaviour of host functions only checking that
+    // their cost is left, so only wasmi's per-instruction fuel is ever spent
+    pub deduct_host_fuel: bool,
+}
+
+// the schedule alkanes launched with, kept bit-for-bit identical so that
+// historical blocks replay with the fuel they were originally metered at
+pub const FUEL_SCHEDULE_V0: FuelSchedule = FuelSchedule {
+    version: 0,
+    activation_height: 0,
+    per_request_byte: FUEL_PER_REQUEST_BYTE,
+    per_load_byte: FUEL_PER_LOAD_BYTE,
+    per_store_byte: FUEL_PER_STORE_BYTE,
+    sequence: FUEL_SEQUENCE,
+    fuel: FUEL_FUEL,
+    height: FUEL_HEIGHT,
+    balance: FUEL_BALANCE,
+    extcall: FUEL_EXTCALL,
+    extcall_deploy: FUEL_EXTCALL_DEPLOY,
+    request_transaction: 50,
+    load_transaction: FUEL_LOAD_TRANSACTION,
+    request_block: 100,
+    load_block: FUEL_LOAD_BLOCK,
+    log: 0,
+    crypto: None,
+    emit_event: None,
+    deduct_host_fuel: false,
+};
+
+// launch costs plus the crypto precompiles, from the network's activation
+// height on
+pub const FUEL_SCHEDULE_V1: FuelSchedule = FuelSchedule {
+    version: 1,
+    activation_height: genesis::CRYPTO_ACTIVATION_HEIGHT,
+    crypto: Some(CryptoCosts {
+        sha256: FUEL_SHA256,
+        keccak256: FUEL_KECCAK256,
+        ripemd160: FUEL_RIPEMD160,
+        hash160: FUEL_HASH160,
+        per_hash_byte: FUEL_PER_HASH_BYTE,
+        schnorr_verify: FUEL_SCHNORR_VERIFY,
+        ecdsa_verify: FUEL_ECDSA_VERIFY,
+    }),
+    ..FUEL_SCHEDULE_V0
+};
+
+// V1 plus event emission, from the network's activation height on
+pub const FUEL_SCHEDULE_V2: FuelSchedule = FuelSchedule {
+    version: 2,
+    activation_height: genesis::EVENTS_ACTIVATION_HEIGHT,
+    emit_event: Some(FUEL_EMIT_EVENT),
+    ..FUEL_SCHEDULE_V1
+};
+
+// V2 with every host function's cost deducted from the caller's fuel rather
+// than only checked, from the network's activation height on
+pub const FUEL_SCHEDULE_V3: FuelSchedule = FuelSchedule {
+    version: 3,
+    activation_height: genesis::HOST_FUEL_ACTIVATION_HEIGHT,
+    deduct_host_fuel: true,
+    ..FUEL_SCHEDULE_V2
+};
+
+// Schedules in activation order. A fuel change ships by appending a schedule
+// with the next version and the network's upgrade height as its
+// activation_height, never by editing one that is already active.
+pub const FUEL_SCHEDULES: &[FuelSchedule] = &[
+    FUEL_SCHEDULE_V0,
+    FUEL_SCHEDULE_V1,
+    FUEL_SCHEDULE_V2,
+    FUEL_SCHEDULE_V3,
+];
+
+pub fn fuel_schedule(height: u64) -> &'static FuelSchedule {
+    FUEL_SCHEDULES
+        .iter()
+        .rev()
+        .find(|v| v.activation_height <= height)
+        .unwrap_or(&FUEL_SCHEDULES[0])
+}
 
 pub trait Fuelable {
     fn consume_fuel(&mut self, n: u64) -> Result<()>;
@@ -258,23 +414,40 @@ pub trait Fuelable {
 
 impl<'a> Fuelable for Caller<'_, AlkanesState> {
     fn consume_fuel(&mut self, n: u64) -> Result<()> {
-        overflow_error((self.get_fuel().unwrap() as u64).checked_sub(n))?;
+        let remaining = overflow_error((self.get_fuel().unwrap() as u64).checked_sub(n))?;
+        if self.data().fuel_schedule.deduct_host_fuel {
+            self.set_fuel(remaining)?;
+        }
         Ok(())
     }
 }
 
 impl Fuelable for AlkanesInstance {
     fn consume_fuel(&mut self, n: u64) -> Result<()> {
-        overflow_error((self.store.get_fuel().unwrap() as u64).checked_sub(n))?;
+        let remaining = overflow_error((self.store.get_fuel().unwrap() as u64).checked_sub(n))?;
+        if self.store.data().fuel_schedule.deduct_host_fuel {
+            self.store.set_fuel(remaining)?;
+        }
         Ok(())
     }
 }
 
+// charges `n` the way the active schedule meters host functions: checked
+// only before V3, so historical blocks replay with the fuel they were metered
+// at, and deducted from then on
 pub fn consume_fuel<'a>(caller: &mut Caller<'_, AlkanesState>, n: u64) -> Result<()> {
     caller.consume_fuel(n)
 }
 
-pub fn compute_extcall_fuel(savecount: u64) -> Result<u64> {
-    let save_fuel = overflow_error(FUEL_PER_STORE_BYTE.checked_mul(savecount))?;
-    overflow_error::<u64>(FUEL_EXTCALL.checked_add(save_fuel))
+// deducts `n` from the caller's fuel, failing if less is left. Host functions
+// added by a schedule after V0 are charged through this
+pub fn charge_fuel(caller: &mut Caller<'_, AlkanesState>, n: u64) -> Result<()> {
+    let remaining = caller.get_fuel()?;
+    caller.set_fuel(overflow_error(remaining.checked_sub(n))?)?;
+    Ok(())
+}
+
+pub fn compute_extcall_fuel(schedule: &FuelSchedule, savecount: u64) -> Result<u64> {
+    let save_fuel = overflow_error(schedule.per_store_byte.checked_mul(savecount))?;
+    overflow_error::<u64>(schedule.extcall.checked_add(save_fuel))
 }
@@ -2,10 +2,15 @@ use super::{
     get_memory, read_arraybuffer, send_to_arraybuffer, sequence_pointer, AlkanesState, Extcall,
     Saveable, SaveableExtendedCallResponse,
 };
-use crate::utils::{balance_pointer, pipe_storagemap_to, transfer_from};
+use crate::events::save_event;
+use crate::utils::{
+    balance_pointer, ledger_origin, merge_storage_diff, pipe_storagemap_to, transfer_from,
+};
 use crate::vm::{run_after_special, run_special_cellpacks};
 use alkanes_support::{
     cellpack::Cellpack,
+    crypto,
+    event::{decode_topics, AlkaneEvent},
     id::AlkaneId,
     parcel::AlkaneTransferParcel,
     response::CallResponse,
@@ -14,6 +19,7 @@ use alkanes_support::{
     utils::overflow_error,
 };
 use anyhow::{anyhow, Result};
+use bitcoin::OutPoint;
 use metashrew_core::index_pointer::IndexPointer;
 #[allow(unused_imports)]
 use metashrew_core::{
@@ -22,11 +28,7 @@ use metashrew_core::{
 };
 use metashrew_support::index_pointer::KeyValuePointer;
 
-use crate::vm::fuel::{
-    consume_fuel, Fuelable, FUEL_BALANCE, FUEL_EXTCALL, FUEL_EXTCALL_DEPLOY, FUEL_FUEL,
-    FUEL_HEIGHT, FUEL_LOAD_BLOCK, FUEL_LOAD_TRANSACTION, FUEL_PER_LOAD_BYTE, FUEL_PER_REQUEST_BYTE,
-    FUEL_PER_STORE_BYTE, FUEL_SEQUENCE,
-};
+use crate::vm::fuel::{charge_fuel, consume_fuel, CryptoCosts, FuelSchedule, Fuelable};
 use protorune_support::utils::consensus_encode;
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
@@ -56,6 +58,21 @@ impl AlkanesHostFunctionsImpl {
             .atomic
             .commit();
     }
+    fn schedule(caller: &Caller<'_, AlkanesState>) -> &'static FuelSchedule {
+        caller.data().fuel_schedule
+    }
+    // the crypto imports are only linked under a schedule that prices them
+    fn crypto_costs(caller: &Caller<'_, AlkanesState>) -> Result<CryptoCosts> {
+        Self::schedule(caller)
+            .crypto
+            .ok_or_else(|| anyhow!("crypto host functions are not active at this height"))
+    }
+    // __emit_event is likewise only linked under a schedule that prices it
+    fn emit_event_cost(caller: &Caller<'_, AlkanesState>) -> Result<u64> {
+        Self::schedule(caller)
+            .emit_event
+            .ok_or_else(|| anyhow!("events are not active at this height"))
+    }
     pub(super) fn _abort<'a>(caller: Caller<'_, AlkanesState>) {
         AlkanesHostFunctionsImpl::abort(caller, 0, 0, 0, 0);
     }
@@ -90,8 +107,9 @@ impl AlkanesHostFunctionsImpl {
             ((result as u64) + (key.len() as u64), result)
         };
 
-        let fuel_cost =
-            overflow_error((bytes_processed as u64).checked_mul(FUEL_PER_REQUEST_BYTE))?;
+        let fuel_cost = overflow_error(
+            (bytes_processed as u64).checked_mul(Self::schedule(caller).per_request_byte),
+        )?;
         #[cfg(feature = "debug-log")]
         {
             println!(
@@ -131,7 +149,9 @@ impl AlkanesHostFunctionsImpl {
             (key.len() + value.len(), value)
         };
 
-        let fuel_cost = overflow_error((bytes_processed as u64).checked_mul(FUEL_PER_LOAD_BYTE))?;
+        let fuel_cost = overflow_error(
+            (bytes_processed as u64).checked_mul(Self::schedule(caller).per_load_byte),
+        )?;
         #[cfg(feature = "debug-log")]
         {
             println!(
@@ -157,7 +177,8 @@ impl AlkanesHostFunctionsImpl {
             .len()
             .try_into()?;
 
-        let fuel_cost = overflow_error((result as u64).checked_mul(FUEL_PER_REQUEST_BYTE))?;
+        let fuel_cost =
+            overflow_error((result as u64).checked_mul(Self::schedule(caller).per_request_byte))?;
         #[cfg(feature = "debug-log")]
         {
             println!(
@@ -176,7 +197,9 @@ impl AlkanesHostFunctionsImpl {
 
         let result: Vec<u8> = caller.data_mut().context.lock().unwrap().serialize();
 
-        let fuel_cost = overflow_error((result.len() as u64).checked_mul(FUEL_PER_LOAD_BYTE))?;
+        let fuel_cost = overflow_error(
+            (result.len() as u64).checked_mul(Self::schedule(caller).per_load_byte),
+        )?;
         #[cfg(feature = "debug-log")]
         {
             println!(
@@ -205,7 +228,7 @@ impl AlkanesHostFunctionsImpl {
 
         // Use a small fixed cost for requesting transaction size
         // This is just getting the size, not loading the full transaction
-        let request_fuel = std::cmp::min(50, FUEL_LOAD_TRANSACTION / 10);
+        let request_fuel = Self::schedule(caller).request_transaction;
         consume_fuel(caller, request_fuel)?;
 
         #[cfg(feature = "debug-log")]
@@ -257,7 +280,9 @@ impl AlkanesHostFunctionsImpl {
 
         let returndata: Vec<u8> = caller.data_mut().context.lock().unwrap().returndata.clone();
 
-        let fuel_cost = overflow_error((returndata.len() as u64).checked_mul(FUEL_PER_LOAD_BYTE))?;
+        let fuel_cost = overflow_error(
+            (returndata.len() as u64).checked_mul(Self::schedule(caller).per_load_byte),
+        )?;
         #[cfg(feature = "debug-log")]
         {
             println!(
@@ -285,14 +310,15 @@ impl AlkanesHostFunctionsImpl {
         )?;
 
         // Use fixed fuel cost instead of scaling with transaction size
-        consume_fuel(caller, FUEL_LOAD_TRANSACTION)?;
+        let fuel_cost = Self::schedule(caller).load_transaction;
+        consume_fuel(caller, fuel_cost)?;
 
         #[cfg(feature = "debug-log")]
         {
             println!(
                 "Loading transaction: size={} bytes, fuel cost={} (fixed)",
                 transaction.len(),
-                FUEL_LOAD_TRANSACTION
+                Self::schedule(caller).load_transaction
             );
         }
 
@@ -308,7 +334,7 @@ impl AlkanesHostFunctionsImpl {
 
         // Use a small fixed cost for requesting block size
         // This is just getting the size, not loading the full block
-        let request_fuel = std::cmp::min(100, FUEL_LOAD_BLOCK / 10);
+        let request_fuel = Self::schedule(caller).request_block;
         consume_fuel(caller, request_fuel)?;
 
         #[cfg(feature = "debug-log")]
@@ -329,14 +355,15 @@ impl AlkanesHostFunctionsImpl {
             consensus_encode(&caller.data_mut().context.lock().unwrap().message.block)?;
 
         // Use fixed fuel cost instead of scaling with block size
-        consume_fuel(caller, FUEL_LOAD_BLOCK)?;
+        let fuel_cost = Self::schedule(caller).load_block;
+        consume_fuel(caller, fuel_cost)?;
 
         #[cfg(feature = "debug-log")]
         {
             println!(
                 "Loading block: size={} bytes, fuel cost={} (fixed)",
                 block.len(),
-                FUEL_LOAD_BLOCK
+                Self::schedule(caller).load_block
             );
         }
 
@@ -353,10 +380,11 @@ impl AlkanesHostFunctionsImpl {
 
         #[cfg(feature = "debug-log")]
         {
-            println!("sequence: fuel_cost={}", FUEL_SEQUENCE);
+            println!("sequence: fuel_cost={}", Self::schedule(caller).sequence);
         }
 
-        consume_fuel(caller, FUEL_SEQUENCE)?;
+        let fuel_cost = Self::schedule(caller).sequence;
+        consume_fuel(caller, fuel_cost)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &buffer)?;
         Ok(())
@@ -369,11 +397,13 @@ impl AlkanesHostFunctionsImpl {
         {
             println!(
                 "fuel: remaining_fuel={}, fuel_cost={}",
-                remaining_fuel, FUEL_FUEL
+                remaining_fuel,
+                Self::schedule(caller).fuel
             );
         }
 
-        consume_fuel(caller, FUEL_FUEL)?;
+        let fuel_cost = Self::schedule(caller).fuel;
+        consume_fuel(caller, fuel_cost)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &buffer)?;
         Ok(())
@@ -386,11 +416,13 @@ impl AlkanesHostFunctionsImpl {
         {
             println!(
                 "height: block_height={}, fuel_cost={}",
-                height_value, FUEL_HEIGHT
+                height_value,
+                Self::schedule(caller).height
             );
         }
 
-        consume_fuel(caller, FUEL_HEIGHT)?;
+        let fuel_cost = Self::schedule(caller).height;
+        consume_fuel(caller, fuel_cost)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &height)?;
         Ok(())
@@ -427,11 +459,12 @@ impl AlkanesHostFunctionsImpl {
                 what.block,
                 what.tx,
                 balance.len(),
-                FUEL_BALANCE
+                Self::schedule(caller).balance
             );
         }
 
-        consume_fuel(caller, FUEL_BALANCE)?;
+        let fuel_cost = Self::schedule(caller).balance;
+        consume_fuel(caller, fuel_cost)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &balance)?;
         Ok(())
@@ -470,10 +503,11 @@ impl AlkanesHostFunctionsImpl {
             {
                 println!(
                     "extcall: deployment detected, additional fuel_cost={}",
-                    FUEL_EXTCALL_DEPLOY
+                    Self::schedule(caller).extcall_deploy
                 );
             }
-            caller.consume_fuel(FUEL_EXTCALL_DEPLOY)?;
+            let fuel_cost = Self::schedule(caller).extcall_deploy;
+            caller.consume_fuel(fuel_cost)?;
         }
 
         // Prepare subcontext data
@@ -490,12 +524,14 @@ impl AlkanesHostFunctionsImpl {
             // Re-acquire lock for state updates
             let transfer_error = {
                 let mut context_guard = caller.data_mut().context.lock().unwrap();
-                pipe_storagemap_to(
+                let origin = ledger_origin(&context_guard.message);
+                let flushed = pipe_storagemap_to(
                     &storage_map,
                     &mut context_guard.message.atomic.derive(
                         &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
                     ),
                 );
+                context_guard.storage_diff.extend(flushed);
 
                 let _transfer_error = transfer_from(
                     &incoming_alkanes,
@@ -505,6 +541,7 @@ impl AlkanesHostFunctionsImpl {
                         .derive(&IndexPointer::default()),
                     &myself,
                     &submyself,
+                    &origin,
                 )
                 .is_err();
                 if _transfer_error {
@@ -530,11 +567,16 @@ impl AlkanesHostFunctionsImpl {
             subbed.returndata = vec![];
             subbed.incoming_alkanes = incoming_alkanes.clone();
             subbed.inputs = cellpack.inputs.clone();
+            subbed.storage_diff = vec![];
             (subbed, binary)
         };
 
-        let base_fuel = FUEL_EXTCALL;
-        let storage_fuel = overflow_error(FUEL_PER_STORE_BYTE.checked_mul(storage_map_len))?;
+        let base_fuel = Self::schedule(caller).extcall;
+        let storage_fuel = overflow_error(
+            Self::schedule(caller)
+                .per_store_byte
+                .checked_mul(storage_map_len),
+        )?;
         let total_fuel = overflow_error(base_fuel.checked_add(storage_fuel))?;
 
         #[cfg(feature = "debug-log")]
@@ -547,6 +589,13 @@ impl AlkanesHostFunctionsImpl {
         }
 
         consume_fuel(caller, total_fuel)?;
+        // once host costs are deducted the callee can't be handed fuel they
+        // already took, and the fuel it leaves is all the caller gets back
+        let start_fuel = if Self::schedule(caller).deduct_host_fuel {
+            std::cmp::min(start_fuel, caller.get_fuel()?)
+        } else {
+            start_fuel
+        };
 
         let mut trace_context: TraceContext = subcontext.flat().into();
         trace_context.fuel = start_fuel;
@@ -554,11 +603,8 @@ impl AlkanesHostFunctionsImpl {
         subcontext.trace.clock(event);
 
         // Run the call in a new context
-        let result = match run_after_special(
-            Arc::new(Mutex::new(subcontext.clone())),
-            binary_rc,
-            start_fuel,
-        ) {
+        let subcontext_rc = Arc::new(Mutex::new(subcontext.clone()));
+        let result = match run_after_special(subcontext_rc.clone(), binary_rc, start_fuel) {
             Ok((response, gas_used)) => {
                 caller.set_fuel(overflow_error(start_fuel.checked_sub(gas_used))?)?;
                 let mut return_context: TraceResponse = response.clone().into();
@@ -566,12 +612,18 @@ impl AlkanesHostFunctionsImpl {
 
                 // Update trace and context state
                 let mut context_guard = caller.data_mut().context.lock().unwrap();
+                let mut saveable: SaveableExtendedCallResponse = response.clone().into();
+                saveable.associate(&subcontext);
+                let origin = ledger_origin(&context_guard.message);
+                let diff = saveable.save(&mut context_guard.message.atomic, &origin)?;
+                // a staticcall's writes are rolled back below, so nothing changed
+                if !T::isstatic() {
+                    return_context.storage_diff =
+                        merge_storage_diff(&subcontext_rc.lock().unwrap().storage_diff, diff);
+                }
                 context_guard
                     .trace
                     .clock(TraceEvent::ReturnContext(return_context));
-                let mut saveable: SaveableExtendedCallResponse = response.clone().into();
-                saveable.associate(&subcontext);
-                saveable.save(&mut context_guard.message.atomic)?;
                 let serialized = CallResponse::from(response.into()).serialize();
                 context_guard.returndata = serialized.clone();
                 T::handle_atomic(&mut context_guard.message.atomic);
@@ -608,7 +660,153 @@ impl AlkanesHostFunctionsImpl {
             let data = mem.data(&caller);
             read_arraybuffer(data, v)?
         };
+        let fuel_cost = Self::schedule(caller).log;
+        consume_fuel(caller, fuel_cost)?;
         print!("{}", String::from_utf8(message)?);
         Ok(())
     }
+    // charges the schedule's `base` plus per_hash_byte for every byte of the
+    // input, then writes its digest to `output`
+    fn digest<const N: usize>(
+        caller: &mut Caller<'_, AlkanesState>,
+        data: i32,
+        output: i32,
+        base: u64,
+        hash: fn(&[u8]) -> [u8; N],
+    ) -> Result<()> {
+        let data = {
+            let mem = get_memory(caller)?;
+            read_arraybuffer(mem.data(&caller), data)?
+        };
+        let fuel_cost = overflow_error(
+            Self::crypto_costs(caller)?
+                .per_hash_byte
+                .checked_mul(data.len() as u64)
+                .and_then(|v| v.checked_add(base)),
+        )?;
+        charge_fuel(caller, fuel_cost)?;
+        send_to_arraybuffer(caller, output.try_into()?, &hash(&data).to_vec())?;
+        Ok(())
+    }
+    pub(super) fn sha256(
+        caller: &mut Caller<'_, AlkanesState>,
+        data: i32,
+        output: i32,
+    ) -> Result<()> {
+        let base = Self::crypto_costs(caller)?.sha256;
+        Self::digest(caller, data, output, base, crypto::sha256)
+    }
+    pub(super) fn keccak256(
+        caller: &mut Caller<'_, AlkanesState>,
+        data: i32,
+        output: i32,
+    ) -> Result<()> {
+        let base = Self::crypto_costs(caller)?.keccak256;
+        Self::digest(caller, data, output, base, crypto::keccak256)
+    }
+    pub(super) fn ripemd160(
+        caller: &mut Caller<'_, AlkanesState>,
+        data: i32,
+        output: i32,
+    ) -> Result<()> {
+        let base = Self::crypto_costs(caller)?.ripemd160;
+        Self::digest(caller, data, output, base, crypto::ripemd160)
+    }
+    pub(super) fn hash160(
+        caller: &mut Caller<'_, AlkanesState>,
+        data: i32,
+        output: i32,
+    ) -> Result<()> {
+        let base = Self::crypto_costs(caller)?.hash160;
+        Self::digest(caller, data, output, base, crypto::hash160)
+    }
+    // returns 1 if the signature verifies and 0 otherwise. Malformed keys and
+    // signatures are a failed verification, not an abort
+    fn verify(
+        caller: &mut Caller<'_, AlkanesState>,
+        pubkey: i32,
+        message: i32,
+        signature: i32,
+        fuel_cost: u64,
+        verify: fn(&[u8], &[u8], &[u8]) -> bool,
+    ) -> Result<i32> {
+        let (pubkey, message, signature) = {
+            let mem = get_memory(caller)?;
+            let data = mem.data(&caller);
+            (
+                read_arraybuffer(data, pubkey)?,
+                read_arraybuffer(data, message)?,
+                read_arraybuffer(data, signature)?,
+            )
+        };
+        charge_fuel(caller, fuel_cost)?;
+        Ok(verify(&pubkey, &message, &signature) as i32)
+    }
+    pub(super) fn schnorr_verify(
+        caller: &mut Caller<'_, AlkanesState>,
+        pubkey: i32,
+        message: i32,
+        signature: i32,
+    ) -> Result<i32> {
+        let fuel_cost = Self::crypto_costs(caller)?.schnorr_verify;
+        Self::verify(
+            caller,
+            pubkey,
+            message,
+            signature,
+            fuel_cost,
+            crypto::schnorr_verify,
+        )
+    }
+    pub(super) fn ecdsa_verify(
+        caller: &mut Caller<'_, AlkanesState>,
+        pubkey: i32,
+        digest: i32,
+        signature: i32,
+    ) -> Result<i32> {
+        let fuel_cost = Self::crypto_costs(caller)?.ecdsa_verify;
+        Self::verify(
+            caller,
+            pubkey,
+            digest,
+            signature,
+            fuel_cost,
+            crypto::ecdsa_verify,
+        )
+    }
+    pub(super) fn emit_event<'a>(
+        caller: &mut Caller<'_, AlkanesState>,
+        topics: i32,
+        data: i32,
+    ) -> Result<()> {
+        let mem = get_memory(caller)?;
+        let (topics, data) = {
+            let mem_data = mem.data(&caller);
+            (
+                read_arraybuffer(mem_data, topics)?,
+                read_arraybuffer(mem_data, data)?,
+            )
+        };
+        let emit_event = Self::emit_event_cost(caller)?;
+        let fuel_cost = overflow_error(
+            Self::schedule(caller)
+                .per_store_byte
+                .checked_mul((topics.len() + data.len()) as u64)
+                .and_then(|v| v.checked_add(emit_event)),
+        )?;
+        charge_fuel(caller, fuel_cost)?;
+        let topics = decode_topics(&topics)?;
+        let mut context_guard = caller.data_mut().context.lock().unwrap();
+        let event = AlkaneEvent {
+            alkane: context_guard.myself.clone(),
+            topics,
+            data,
+            height: context_guard.message.height,
+            outpoint: OutPoint {
+                txid: context_guard.message.transaction.compute_txid(),
+                vout: context_guard.message.vout,
+            },
+        };
+        save_event(&mut context_guard.message.atomic, event)
+    }
 }
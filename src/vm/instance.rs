@@ -1,6 +1,9 @@
 use super::{
-    extcall::*, read_arraybuffer, AlkanesExportsImpl, AlkanesHostFunctionsImpl,
-    AlkanesRuntimeContext, AlkanesState, MEMORY_LIMIT,
+    cache::{self, Bytecode},
+    extcall::*,
+    fuel::{fuel_schedule, FuelSchedule},
+    read_arraybuffer, AlkanesExportsImpl, AlkanesHostFunctionsImpl, AlkanesRuntimeContext,
+    AlkanesState, INITIAL_MEMORY_PAGES, MEMORY_LIMIT,
 };
 use alkanes_support::response::ExtendedCallResponse;
 use anyhow::{anyhow, Result};
@@ -70,81 +73,263 @@ impl AlkanesInstance {
     }
     pub fn from_alkane(
         context: Arc<Mutex<AlkanesRuntimeContext>>,
-        binary: Arc<Vec<u8>>,
+        binary: impl Into<Bytecode>,
         start_fuel: u64,
     ) -> Result<Self> {
-        /*
-        let binary = context
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&context.myself.clone().into())
-            .get();
-            */
-        let mut config = Config::default();
-        config.consume_fuel(true);
-        let engine = Engine::new(&config);
+        let fuel_schedule = fuel_schedule(context.lock().unwrap().message.height);
+        Self::from_alkane_with_schedule(context, binary, start_fuel, fuel_schedule)
+    }
+    // instantiates under `fuel_schedule` rather than the one active at the
+    // message's height
+    pub fn from_alkane_with_schedule(
+        context: Arc<Mutex<AlkanesRuntimeContext>>,
+        binary: impl Into<Bytecode>,
+        start_fuel: u64,
+        fuel_schedule: &'static FuelSchedule,
+    ) -> Result<Self> {
+        let (engine, module) = cache::load(&binary.into())?;
+        let mut store = Self::new_store(&engine, context, start_fuel, fuel_schedule)?;
+        let linker = Self::host_linker(&mut store)?;
+        let mut alkanes_instance = AlkanesInstance {
+            instance: linker
+                .instantiate(&mut store, &module)?
+                .ensure_no_start(&mut store)?,
+            store,
+        };
+
+        let memory = alkanes_instance.get_memory()?;
+
+        let current_pages = memory.size(&alkanes_instance.store);
+
+        if current_pages < INITIAL_MEMORY_PAGES {
+            memory
+                .grow(
+                    &mut alkanes_instance.store,
+                    INITIAL_MEMORY_PAGES - current_pages,
+                )
+                .expect("Failed to grow memory");
+        }
+
+        Ok(alkanes_instance)
+    }
+    pub(super) fn new_store(
+        engine: &Engine,
+        context: Arc<Mutex<AlkanesRuntimeContext>>,
+        start_fuel: u64,
+        fuel_schedule: &'static FuelSchedule,
+    ) -> Result<Store<AlkanesState>> {
         let mut store = Store::<AlkanesState>::new(
-            &engine,
+            engine,
             AlkanesState {
                 had_failure: false,
                 limiter: StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build(),
-                context: context.clone(),
+                context,
+                fuel_schedule,
             },
         );
         store.limiter(|state| &mut state.limiter);
         Store::<AlkanesState>::set_fuel(&mut store, start_fuel)?; // TODO: implement gas limits
-        let module = Module::new(&engine, &mut &binary[..])?;
-        let mut linker: Linker<AlkanesState> = Linker::<AlkanesState>::new(&engine);
-        linker.func_wrap("env", "abort", AlkanesHostFunctionsImpl::abort)?;
-        linker.func_wrap(
+        Ok(store)
+    }
+    // every host function the store's fuel schedule links, defined in `store`
+    // under "env". Deploy validation checks imports against this same linker
+    pub(super) fn host_linker(store: &mut Store<AlkanesState>) -> Result<Linker<AlkanesState>> {
+        let fuel_schedule = store.data().fuel_schedule;
+        let mut linker: Linker<AlkanesState> = Linker::<AlkanesState>::new(store.engine());
+        linker.define(
+            "env",
+            "abort",
+            Func::wrap(&mut *store, AlkanesHostFunctionsImpl::abort),
+        )?;
+        linker.define(
             "env",
             "__load_storage",
-            |mut caller: Caller<'_, AlkanesState>, k: i32, v: i32| {
-                match AlkanesHostFunctionsImpl::load_storage(&mut caller, k, v) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        AlkanesHostFunctionsImpl::_abort(caller);
-                        -1
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, k: i32, v: i32| {
+                    match AlkanesHostFunctionsImpl::load_storage(&mut caller, k, v) {
+                        Ok(v) => v,
+                        Err(_e) => {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                            -1
+                        }
                     }
-                }
-            },
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__request_storage",
-            |mut caller: Caller<'_, AlkanesState>, k: i32| {
-                match AlkanesHostFunctionsImpl::request_storage(&mut caller, k) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        AlkanesHostFunctionsImpl::_abort(caller);
-                        -1
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, k: i32| {
+                    match AlkanesHostFunctionsImpl::request_storage(&mut caller, k) {
+                        Ok(v) => v,
+                        Err(_e) => {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                            -1
+                        }
                     }
-                }
-            },
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__log",
-            |mut caller: Caller<'_, AlkanesState>, v: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::log(&mut caller, v) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, v: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::log(&mut caller, v) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        // linked only once the schedule prices it, like the crypto imports
+        // below
+        if fuel_schedule.emit_event.is_some() {
+            linker.define(
+                "env",
+                "__emit_event",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>, topics: i32, data: i32| {
+                        if let Err(_e) =
+                            AlkanesHostFunctionsImpl::emit_event(&mut caller, topics, data)
+                        {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                        }
+                    },
+                ),
+            )?;
+        }
+        // linked only once the schedule prices them. Before that a binary
+        // importing them fails to instantiate, as it always has
+        if fuel_schedule.crypto.is_some() {
+            linker.define(
+                "env",
+                "__sha256",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>, data: i32, output: i32| {
+                        if let Err(_e) = AlkanesHostFunctionsImpl::sha256(&mut caller, data, output)
+                        {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                        }
+                    },
+                ),
+            )?;
+            linker.define(
+                "env",
+                "__keccak256",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>, data: i32, output: i32| {
+                        if let Err(_e) =
+                            AlkanesHostFunctionsImpl::keccak256(&mut caller, data, output)
+                        {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                        }
+                    },
+                ),
+            )?;
+            linker.define(
+                "env",
+                "__ripemd160",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>, data: i32, output: i32| {
+                        if let Err(_e) =
+                            AlkanesHostFunctionsImpl::ripemd160(&mut caller, data, output)
+                        {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                        }
+                    },
+                ),
+            )?;
+            linker.define(
+                "env",
+                "__hash160",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>, data: i32, output: i32| {
+                        if let Err(_e) =
+                            AlkanesHostFunctionsImpl::hash160(&mut caller, data, output)
+                        {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                        }
+                    },
+                ),
+            )?;
+            linker.define(
+                "env",
+                "__schnorr_verify",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>,
+                     pubkey: i32,
+                     message: i32,
+                     signature: i32|
+                     -> i32 {
+                        match AlkanesHostFunctionsImpl::schnorr_verify(
+                            &mut caller,
+                            pubkey,
+                            message,
+                            signature,
+                        ) {
+                            Ok(v) => v,
+                            Err(_e) => {
+                                AlkanesHostFunctionsImpl::_abort(caller);
+                                -1
+                            }
+                        }
+                    },
+                ),
+            )?;
+            linker.define(
+                "env",
+                "__ecdsa_verify",
+                Func::wrap(
+                    &mut *store,
+                    |mut caller: Caller<'_, AlkanesState>,
+                     pubkey: i32,
+                     message: i32,
+                     signature: i32|
+                     -> i32 {
+                        match AlkanesHostFunctionsImpl::ecdsa_verify(
+                            &mut caller,
+                            pubkey,
+                            message,
+                            signature,
+                        ) {
+                            Ok(v) => v,
+                            Err(_e) => {
+                                AlkanesHostFunctionsImpl::_abort(caller);
+                                -1
+                            }
+                        }
+                    },
+                ),
+            )?;
+        }
+        linker.define(
             "env",
             "__balance",
-            |mut caller: Caller<'_, AlkanesState>, who: i32, what: i32, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::balance(&mut caller, who, what, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, who: i32, what: i32, output: i32| {
+                    if let Err(_e) =
+                        AlkanesHostFunctionsImpl::balance(&mut caller, who, what, output)
+                    {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__request_context",
-            |mut caller: Caller<'_, AlkanesState>| -> i32 {
+            Func::wrap(&mut *store, |mut caller: Caller<'_, AlkanesState>| -> i32 {
                 match AlkanesHostFunctionsImpl::request_context(&mut caller) {
                     Ok(v) => v,
                     Err(_e) => {
@@ -152,62 +337,77 @@ impl AlkanesInstance {
                         -1
                     }
                 }
-            },
+            }),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__load_context",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                match AlkanesHostFunctionsImpl::load_context(&mut caller, output) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        AlkanesHostFunctionsImpl::_abort(caller);
-                        -1
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    match AlkanesHostFunctionsImpl::load_context(&mut caller, output) {
+                        Ok(v) => v,
+                        Err(_e) => {
+                            AlkanesHostFunctionsImpl::_abort(caller);
+                            -1
+                        }
                     }
-                }
-            },
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__sequence",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::sequence(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::sequence(&mut caller, output) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__fuel",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::fuel(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::fuel(&mut caller, output) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__height",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::height(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::height(&mut caller, output) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
 
-        linker.func_wrap(
+        linker.define(
             "env",
             "__returndatacopy",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::returndatacopy(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::returndatacopy(&mut caller, output) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__request_transaction",
-            |mut caller: Caller<'_, AlkanesState>| -> i32 {
+            Func::wrap(&mut *store, |mut caller: Caller<'_, AlkanesState>| -> i32 {
                 match AlkanesHostFunctionsImpl::request_transaction(&mut caller) {
                     Ok(v) => v,
                     Err(_e) => {
@@ -215,23 +415,27 @@ impl AlkanesInstance {
                         -1
                     }
                 }
-            },
+            }),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__load_transaction",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::load_transaction(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::load_transaction(&mut caller, output)
+                    {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
         /* removed below to prevent redundancy / requirement for archived chaindata */
         /*
-        linker.func_wrap(
+        linker.define(
             "env",
             "__request_output",
-            |mut caller: Caller<'_, AlkanesState>, outpoint: i32| -> i32 {
+            Func::wrap(&mut *store, |mut caller: Caller<'_, AlkanesState>, outpoint: i32| -> i32 {
                 match AlkanesHostFunctionsImpl::request_output(&mut caller, outpoint) {
                   Err(_e) => {
                     AlkanesHostFunctionsImpl::_abort(caller);
@@ -239,121 +443,116 @@ impl AlkanesInstance {
                   }
                   Ok(v) => v
                 }
-            },
+            }),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__load_output",
-            |mut caller: Caller<'_, AlkanesState>, outpoint: i32, output: i32| {
+            Func::wrap(&mut *store, |mut caller: Caller<'_, AlkanesState>, outpoint: i32, output: i32| {
                 if let Err(_e) = AlkanesHostFunctionsImpl::load_output(&mut caller, outpoint, output) {
                     AlkanesHostFunctionsImpl::_abort(caller);
                 }
-            },
+            }),
         )?;
         */
-        linker.func_wrap(
+        linker.define(
             "env",
             "__request_block",
-            |mut caller: Caller<'_, AlkanesState>| match AlkanesHostFunctionsImpl::request_block(
-                &mut caller,
-            ) {
-                Ok(v) => v,
-                Err(_e) => {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                    -1
+            Func::wrap(&mut *store, |mut caller: Caller<'_, AlkanesState>| {
+                match AlkanesHostFunctionsImpl::request_block(&mut caller) {
+                    Ok(v) => v,
+                    Err(_e) => {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                        -1
+                    }
                 }
-            },
+            }),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__load_block",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = AlkanesHostFunctionsImpl::load_block(&mut caller, output) {
-                    AlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>, output: i32| {
+                    if let Err(_e) = AlkanesHostFunctionsImpl::load_block(&mut caller, output) {
+                        AlkanesHostFunctionsImpl::_abort(caller);
+                    }
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__call",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                handle_extcall(
-                    AlkanesHostFunctionsImpl::extcall::<Call>(
-                        &mut caller,
-                        cellpack_ptr,
-                        incoming_alkanes_ptr,
-                        checkpoint_ptr,
-                        start_fuel,
-                    ),
-                    caller,
-                )
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>,
+                 cellpack_ptr: i32,
+                 incoming_alkanes_ptr: i32,
+                 checkpoint_ptr: i32,
+                 start_fuel: u64|
+                 -> i32 {
+                    handle_extcall(
+                        AlkanesHostFunctionsImpl::extcall::<Call>(
+                            &mut caller,
+                            cellpack_ptr,
+                            incoming_alkanes_ptr,
+                            checkpoint_ptr,
+                            start_fuel,
+                        ),
+                        caller,
+                    )
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__delegatecall",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                handle_extcall(
-                    AlkanesHostFunctionsImpl::extcall::<Delegatecall>(
-                        &mut caller,
-                        cellpack_ptr,
-                        incoming_alkanes_ptr,
-                        checkpoint_ptr,
-                        start_fuel,
-                    ),
-                    caller,
-                )
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>,
+                 cellpack_ptr: i32,
+                 incoming_alkanes_ptr: i32,
+                 checkpoint_ptr: i32,
+                 start_fuel: u64|
+                 -> i32 {
+                    handle_extcall(
+                        AlkanesHostFunctionsImpl::extcall::<Delegatecall>(
+                            &mut caller,
+                            cellpack_ptr,
+                            incoming_alkanes_ptr,
+                            checkpoint_ptr,
+                            start_fuel,
+                        ),
+                        caller,
+                    )
+                },
+            ),
         )?;
-        linker.func_wrap(
+        linker.define(
             "env",
             "__staticcall",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                handle_extcall(
-                    AlkanesHostFunctionsImpl::extcall::<Staticcall>(
-                        &mut caller,
-                        cellpack_ptr,
-                        incoming_alkanes_ptr,
-                        checkpoint_ptr,
-                        start_fuel,
-                    ),
-                    caller,
-                )
-            },
+            Func::wrap(
+                &mut *store,
+                |mut caller: Caller<'_, AlkanesState>,
+                 cellpack_ptr: i32,
+                 incoming_alkanes_ptr: i32,
+                 checkpoint_ptr: i32,
+                 start_fuel: u64|
+                 -> i32 {
+                    handle_extcall(
+                        AlkanesHostFunctionsImpl::extcall::<Staticcall>(
+                            &mut caller,
+                            cellpack_ptr,
+                            incoming_alkanes_ptr,
+                            checkpoint_ptr,
+                            start_fuel,
+                        ),
+                        caller,
+                    )
+                },
+            ),
         )?;
-        let mut alkanes_instance = AlkanesInstance {
-            instance: linker
-                .instantiate(&mut store, &module)?
-                .ensure_no_start(&mut store)?,
-            store,
-        };
-
-        let memory = alkanes_instance.get_memory()?;
-
-        let current_pages = memory.size(&alkanes_instance.store);
-
-        if current_pages < 512 {
-            memory
-                .grow(&mut alkanes_instance.store, 512 - current_pages)
-                .expect("Failed to grow memory");
-        }
-
-        Ok(alkanes_instance)
+        Ok(linker)
     }
     pub fn reset(&mut self) {
         self.store.data_mut().had_failure = false;
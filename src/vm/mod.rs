@@ -1,3 +1,4 @@
+pub mod cache;
 pub mod constants;
 pub mod exports;
 pub mod extcall;
@@ -7,6 +8,7 @@ pub mod instance;
 pub mod runtime;
 pub mod state;
 pub mod utils;
+pub mod validation;
 
 use self::constants::*;
 use self::exports::*;
@@ -1,7 +1,11 @@
 use std::fmt;
 
 use alkanes_support::{
-    cellpack::Cellpack, context::Context, id::AlkaneId, parcel::AlkaneTransferParcel, trace::Trace,
+    cellpack::Cellpack,
+    context::Context,
+    id::AlkaneId,
+    parcel::AlkaneTransferParcel,
+    trace::{StorageSlotDiff, Trace},
 };
 #[allow(unused_imports)]
 use {
@@ -20,6 +24,8 @@ pub struct AlkanesRuntimeContext {
     pub inputs: Vec<u128>,
     pub message: Box<MessageContextParcel>,
     pub trace: Trace,
+    // slots this frame flushed to storage ahead of its subcalls
+    pub storage_diff: Vec<StorageSlotDiff>,
 }
 
 impl fmt::Debug for AlkanesRuntimeContext {
@@ -49,6 +55,7 @@ impl AlkanesRuntimeContext {
             caller: AlkaneId::default(),
             trace: Trace::default(),
             inputs: cloned.inputs,
+            storage_diff: vec![],
         }
     }
     pub fn flatten(&self) -> Vec<u128> {
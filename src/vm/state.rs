@@ -1,3 +1,4 @@
+use super::fuel::FuelSchedule;
 use super::AlkanesRuntimeContext;
 use std::sync::{Arc, Mutex};
 use wasmi::*;
@@ -6,4 +7,5 @@ pub struct AlkanesState {
     pub(super) had_failure: bool,
     pub(super) context: Arc<Mutex<AlkanesRuntimeContext>>,
     pub(super) limiter: StoreLimits,
+    pub(super) fuel_schedule: &'static FuelSchedule,
 }
@@ -1,9 +1,13 @@
+use super::cache::Bytecode;
+use super::validation::{validate_binary, validates_deploys};
 use super::{AlkanesInstance, AlkanesRuntimeContext, AlkanesState};
+use crate::bytecode::{load_bytecode, load_code, store_bytecode};
+use crate::registry::{save_alkane, AlkaneRecord, CreationKind};
 use crate::utils::{pipe_storagemap_to, transfer_from};
-use crate::vm::fuel::compute_extcall_fuel;
-use alkanes_support::trace::TraceEvent;
+use crate::vm::fuel::{compute_extcall_fuel, fuel_schedule};
+use alkanes_support::trace::{StorageSlotDiff, TraceEvent};
 use alkanes_support::{
-    cellpack::Cellpack, gz::decompress, id::AlkaneId, parcel::AlkaneTransferParcel,
+    cellpack::Cellpack, crypto::sha256, gz::decompress, id::AlkaneId, parcel::AlkaneTransferParcel,
     response::ExtendedCallResponse, storage::StorageMap, utils::overflow_error,
     witness::find_witness_payload,
 };
@@ -15,6 +19,8 @@ use metashrew_core::{
     stdio::{stdout, Write},
 };
 use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::journal;
+use protorune::ledger::LedgerOrigin;
 
 use std::sync::{Arc, Mutex};
 use wasmi::*;
@@ -51,41 +57,45 @@ pub fn sequence_pointer(ptr: &AtomicPointer) -> AtomicPointer {
 pub fn run_special_cellpacks(
     context: Arc<Mutex<AlkanesRuntimeContext>>,
     cellpack: &Cellpack,
-) -> Result<(AlkaneId, AlkaneId, Arc<Vec<u8>>)> {
+) -> Result<(AlkaneId, AlkaneId, Bytecode)> {
     let mut payload = cellpack.clone();
-    let mut binary = Arc::<Vec<u8>>::new(vec![]);
+    let mut binary = Bytecode::Wasm(Arc::new(vec![]));
     let mut next_sequence_pointer = sequence_pointer(&mut context.lock().unwrap().message.atomic);
     let next_sequence = next_sequence_pointer.get_value::<u128>();
     let original_target = cellpack.target.clone();
+    let mut created: Option<(CreationKind, [u8; 32])> = None;
     if cellpack.target.is_created(next_sequence) {
-        let wasm_payload = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into())
-            .get();
-        binary = Arc::new(decompress(wasm_payload.as_ref().clone())?);
+        // nothing deployed there compiles as an empty module and fails
+        if let Some((hash, wasm_payload)) =
+            load_code(&context.lock().unwrap().message.atomic, &payload.target)?
+        {
+            binary = Bytecode::stored(hash, wasm_payload);
+        }
     } else if cellpack.target.is_create() {
         let wasm_payload = Arc::new(
             find_witness_payload(&context.lock().unwrap().message.transaction.clone(), 0)
                 .ok_or("finding witness payload failed for creation of alkane")
                 .map_err(|_| anyhow!("used CREATE cellpack but no binary found in witness"))?,
         );
+        let wasm = decompress(wasm_payload.as_ref().clone())?;
+        let height = context.lock().unwrap().message.height;
+        if validates_deploys(height) {
+            validate_binary(&wasm, fuel_schedule(height))?;
+        }
         payload.target = AlkaneId {
             block: 2,
             tx: next_sequence,
         };
-        let mut pointer = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into());
-        pointer.set(wasm_payload.clone());
-        binary = Arc::new(decompress(wasm_payload.as_ref().clone())?);
+        let hash = sha256(&wasm);
+        binary = Bytecode::stored(hash, wasm_payload.clone());
+        store_bytecode(
+            &mut context.lock().unwrap().message.atomic,
+            &payload.target,
+            wasm_payload,
+            hash,
+        )?;
+        created = Some((CreationKind::Create, hash));
+        journal::record(&next_sequence_pointer);
         next_sequence_pointer.set_value(next_sequence + 1);
     } else if let Some(number) = cellpack.target.reserved() {
         let wasm_payload = Arc::new(
@@ -95,49 +105,51 @@ pub fn run_special_cellpacks(
                     anyhow!("used CREATERESERVED cellpack but no binary found in witness")
                 })?,
         );
+        let wasm = decompress(wasm_payload.as_ref().clone())?;
+        let height = context.lock().unwrap().message.height;
+        if validates_deploys(height) {
+            validate_binary(&wasm, fuel_schedule(height))?;
+        }
         payload.target = AlkaneId {
             block: 4,
             tx: number,
         };
-        let mut ptr = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into());
-        if ptr.get().as_ref().len() == 0 {
-            ptr.set(wasm_payload.clone());
+        let hash = sha256(&wasm);
+        binary = Bytecode::stored(hash, wasm_payload.clone());
+        let mut context_guard = context.lock().unwrap();
+        let atomic = &mut context_guard.message.atomic;
+        if load_bytecode(atomic, &payload.target).is_empty() {
+            store_bytecode(atomic, &payload.target, wasm_payload, hash)?;
+            created = Some((CreationKind::Reserved, hash));
         } else {
             return Err(anyhow!(format!(
                 "used CREATERESERVED cellpack but {} already holds a binary",
                 number
             )));
         }
-        binary = Arc::new(decompress(wasm_payload.clone().as_ref().clone())?);
     } else if let Some(factory) = cellpack.target.factory() {
         payload.target = AlkaneId::new(2, next_sequence);
+        journal::record(&next_sequence_pointer);
         next_sequence_pointer.set_value(next_sequence + 1);
-        let context_binary: Vec<u8> = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&factory.clone().into())
-            .get()
-            .as_ref()
-            .clone();
-        let rc = Arc::new(context_binary);
-        context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into())
-            .set(rc.clone());
-        binary = Arc::new(decompress(rc.as_ref().clone())?);
+        // a clone points at the factory's code rather than copying it
+        let mut context_guard = context.lock().unwrap();
+        let atomic = &mut context_guard.message.atomic;
+        let (hash, rc) = load_code(atomic, &factory)?
+            .ok_or_else(|| anyhow!("factory {:?} holds no binary", factory))?;
+        store_bytecode(atomic, &payload.target, rc.clone(), hash)?;
+        binary = Bytecode::stored(hash, rc);
+        created = Some((CreationKind::Factory(factory), hash));
+    }
+    if let Some((kind, hash)) = created {
+        let mut context_guard = context.lock().unwrap();
+        let record = AlkaneRecord {
+            id: payload.target,
+            height: context_guard.message.height,
+            txid: context_guard.message.transaction.compute_txid(),
+            kind,
+            code_hash: hash,
+        };
+        save_alkane(&mut context_guard.message.atomic, record)?;
     }
     if &original_target != &payload.target {
         context
@@ -195,8 +207,12 @@ pub trait Saveable {
     fn to(&self) -> AlkaneId;
     fn storage_map(&self) -> StorageMap;
     fn alkanes(&self) -> AlkaneTransferParcel;
-    fn save(&self, atomic: &mut AtomicPointer) -> Result<()> {
-        pipe_storagemap_to(
+    fn save(
+        &self,
+        atomic: &mut AtomicPointer,
+        origin: &LedgerOrigin,
+    ) -> Result<Vec<StorageSlotDiff>> {
+        let diff = pipe_storagemap_to(
             &self.storage_map(),
             &mut atomic
                 .derive(&IndexPointer::from_keyword("/alkanes/").select(&self.from().into())),
@@ -206,14 +222,15 @@ pub trait Saveable {
             &mut atomic.derive(&IndexPointer::default()),
             &self.from().into(),
             &self.to().into(),
+            origin,
         )?;
-        Ok(())
+        Ok(diff)
     }
 }
 
 pub fn run_after_special(
     context: Arc<Mutex<AlkanesRuntimeContext>>,
-    binary: Arc<Vec<u8>>,
+    binary: Bytecode,
     start_fuel: u64,
 ) -> Result<(ExtendedCallResponse, u64)> {
     #[cfg(feature = "debug-log")]
@@ -225,10 +242,11 @@ pub fn run_after_special(
         );
     }
 
-    let mut instance = AlkanesInstance::from_alkane(context.clone(), binary.clone(), start_fuel)?;
+    let mut instance = AlkanesInstance::from_alkane(context.clone(), binary, start_fuel)?;
     let response = instance.execute()?;
 
     let remaining_fuel = instance.store.get_fuel().unwrap();
+    let fuel_schedule = instance.store.data().fuel_schedule;
     let storage_len = response.storage.serialize().len() as u64;
 
     #[cfg(feature = "debug-log")]
@@ -241,7 +259,7 @@ pub fn run_after_special(
         println!("  - Storage size: {} bytes", storage_len);
     }
 
-    let computed_storage_fuel = compute_extcall_fuel(storage_len).unwrap_or(0);
+    let computed_storage_fuel = compute_extcall_fuel(fuel_schedule, storage_len).unwrap_or(0);
 
     #[cfg(feature = "debug-log")]
     {
@@ -251,7 +269,7 @@ pub fn run_after_special(
 
     let fuel_used = overflow_error(start_fuel.checked_sub(remaining_fuel).and_then(
         |v: u64| -> Option<u64> {
-            let computed_fuel = compute_extcall_fuel(storage_len).ok()?;
+            let computed_fuel = compute_extcall_fuel(fuel_schedule, storage_len).ok()?;
             let opt = v.checked_add(computed_fuel);
             #[cfg(feature = "debug-log")]
             {
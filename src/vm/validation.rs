@@ -0,0 +1,102 @@
+use super::cache;
+use super::fuel::FuelSchedule;
+use super::{AlkanesInstance, AlkanesRuntimeContext, INITIAL_MEMORY_PAGES, MEMORY_LIMIT};
+use crate::network::genesis;
+use anyhow::{anyhow, Result};
+use std::sync::{Arc, Mutex};
+use wasmi::{ExternType, Module};
+
+const WASM_PAGE_SIZE: usize = 0x10000;
+
+// validation changes which deploys succeed, so blocks below the network's
+// activation height store whatever they were given, as they always have
+pub fn validates_deploys(height: u64) -> bool {
+    height >= genesis::DEPLOY_VALIDATION_HEIGHT
+}
+
+// checks a decompressed binary can be instantiated before it is stored, so a
+// deploy that could never run reverts instead of taking a sequence number.
+// The binary is parsed by the engine that will run it, so anything wasmi
+// accepts at call time is accepted here too. Imports are checked by name and
+// signature against the linker `schedule` instantiates with, the schedule of
+// the deploy's height
+pub fn validate_binary(binary: &[u8], schedule: &'static FuelSchedule) -> Result<()> {
+    let engine = cache::engine();
+    let module = Module::new(&engine, &mut &binary[..])
+        .map_err(|e| anyhow!("deployed binary is not a valid module: {}", e))?;
+    let mut store = AlkanesInstance::new_store(
+        &engine,
+        Arc::new(Mutex::new(AlkanesRuntimeContext::default())),
+        0,
+        schedule,
+    )?;
+    let linker = AlkanesInstance::host_linker(&mut store)?;
+    for import in module.imports() {
+        match import.ty() {
+            ExternType::Func(ty) => {
+                let linked = match import.module() {
+                    "env" => linker
+                        .get(&store, "env", import.name())
+                        .and_then(|v| v.into_func()),
+                    _ => None,
+                };
+                match linked {
+                    Some(func) if &func.ty(&store) == ty => {}
+                    Some(func) => {
+                        return Err(anyhow!(
+                            "deployed binary imports host function {}.{} as {:?}, but it is {:?}",
+                            import.module(),
+                            import.name(),
+                            ty,
+                            func.ty(&store)
+                        ))
+                    }
+                    None => {
+                        return Err(anyhow!(
+                            "deployed binary imports unsupported host function {}.{}",
+                            import.module(),
+                            import.name()
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "deployed binary imports {}.{}, only host functions may be imported",
+                    import.module(),
+                    import.name()
+                ))
+            }
+        }
+    }
+    if !module
+        .exports()
+        .any(|v| v.name() == "__execute" && matches!(v.ty(), ExternType::Func(_)))
+    {
+        return Err(anyhow!("deployed binary does not export __execute"));
+    }
+    let memory = module
+        .exports()
+        .find_map(|v| match v.ty() {
+            ExternType::Memory(memory) if v.name() == "memory" => Some(*memory),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow!("deployed binary does not export memory"))?;
+    if (memory.minimum() as usize) * WASM_PAGE_SIZE > MEMORY_LIMIT {
+        return Err(anyhow!(
+            "deployed binary asks for {} pages of memory, more than the limit of {} bytes",
+            memory.minimum(),
+            MEMORY_LIMIT
+        ));
+    }
+    if let Some(maximum) = memory.maximum() {
+        if maximum < INITIAL_MEMORY_PAGES {
+            return Err(anyhow!(
+                "deployed binary caps memory at {} pages, below the {} pages every instance is given",
+                maximum,
+                INITIAL_MEMORY_PAGES
+            ));
+        }
+    }
+    Ok(())
+}